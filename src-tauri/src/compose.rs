@@ -0,0 +1,402 @@
+// Pattern Composition Module
+// Merges patterns into one canvas and splits a pattern into a grid of sections
+
+use crate::{CanvasConfig, Color, Layer, LayerMetadata, NdpFile, OverlayImage};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+
+/// Largest canvas side a merge may produce (same limit as the canvas layout dialog)
+pub(crate) const MAX_CANVAS_SIDE: u32 = 1000;
+
+/// Where the second pattern is placed relative to the first
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
+pub struct MergeOptions {
+    /// Column of the second pattern's left edge (may be negative)
+    #[serde(default)]
+    pub offset_x: i32,
+    /// Row of the second pattern's top edge (may be negative)
+    #[serde(default)]
+    pub offset_y: i32,
+}
+
+/// How a pattern is divided into sections
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct SplitOptions {
+    pub columns: u32,
+    pub rows: u32,
+    /// Stitches repeated on each side of a seam so sections can be aligned
+    #[serde(default)]
+    pub overlap: u32,
+}
+
+/// One section of a split pattern and where it came from
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PatternSection {
+    pub row: u32,
+    pub column: u32,
+    pub x: u32, // Left edge in the source pattern, including overlap
+    pub y: u32, // Top edge in the source pattern, including overlap
+    pub project: NdpFile,
+}
+
+/// Key used to recognise the same thread (or blend of threads) in two palettes
+fn thread_key(color: &Color) -> Option<(String, String, String)> {
+    let code = color.thread_code.as_deref()?.trim();
+    if code.is_empty() {
+        return None;
+    }
+    let brand = color.thread_brand.as_deref().unwrap_or("").trim();
+    let blend = color.blend_thread_code.as_deref().unwrap_or("").trim();
+    Some((brand.to_lowercase(), code.to_lowercase(), blend.to_lowercase()))
+}
+
+/// `id`, or the first "id-n" not already in `taken`
+fn unique_id(taken: &HashSet<String>, id: &str) -> String {
+    if !taken.contains(id) {
+        return id.to_string();
+    }
+    (2..)
+        .map(|n| format!("{}-{}", id, n))
+        .find(|candidate| !taken.contains(candidate))
+        .unwrap()
+}
+
+/// Physical size scaled to a new stitch count
+pub(crate) fn scale_physical(physical: Option<f64>, from: u32, to: u32) -> Option<f64> {
+    physical.filter(|_| from > 0).map(|p| p * to as f64 / from as f64)
+}
+
+/// Add `other`'s colors to `palette`, returning a map from `other`'s color ids to merged ids
+/// Threads with the same brand and code are shared; colors without a code are shared
+/// only when an uncoded color with the same RGB already exists.
+fn merge_palettes(palette: &mut Vec<Color>, other: &[Color]) -> HashMap<String, String> {
+    let mut by_thread: HashMap<(String, String, String), String> = palette
+        .iter()
+        .filter_map(|c| thread_key(c).map(|key| (key, c.id.clone())))
+        .collect();
+    let mut by_rgb: HashMap<[u8; 3], String> = palette
+        .iter()
+        .filter(|c| thread_key(c).is_none())
+        .map(|c| (c.rgb, c.id.clone()))
+        .collect();
+    let mut ids: HashSet<String> = palette.iter().map(|c| c.id.clone()).collect();
+    let mut symbols: HashSet<String> = palette.iter().filter_map(|c| c.symbol.clone()).collect();
+
+    let mut remap = HashMap::new();
+    for color in other {
+        let key = thread_key(color);
+        let existing = match &key {
+            Some(key) => by_thread.get(key),
+            None => by_rgb.get(&color.rgb),
+        };
+        if let Some(id) = existing {
+            remap.insert(color.id.clone(), id.clone());
+            continue;
+        }
+
+        let mut added = color.clone();
+        added.id = unique_id(&ids, &color.id);
+        // Symbols must identify one color; a clashing symbol is left for the user to pick
+        if added.symbol.as_ref().is_some_and(|s| symbols.contains(s)) {
+            added.symbol = None;
+        }
+
+        ids.insert(added.id.clone());
+        if let Some(symbol) = &added.symbol {
+            symbols.insert(symbol.clone());
+        }
+        match key {
+            Some(key) => by_thread.insert(key, added.id.clone()),
+            None => by_rgb.insert(added.rgb, added.id.clone()),
+        };
+        remap.insert(color.id.clone(), added.id.clone());
+        palette.push(added);
+    }
+
+    remap
+}
+
+/// Move every stitch and overlay in a project by a whole number of cells
+/// Callers guarantee no stitch ends up at a negative position.
+pub(crate) fn shift_project(project: &mut NdpFile, dx: i64, dy: i64) {
+    if dx == 0 && dy == 0 {
+        return;
+    }
+    for layer in &mut project.layers {
+        for stitch in &mut layer.stitches {
+            stitch.x = (stitch.x as i64 + dx) as u32;
+            stitch.y = (stitch.y as i64 + dy) as u32;
+        }
+    }
+    for overlay in project.overlays.iter_mut().flatten() {
+        overlay.x = (overlay.x as i64 + dx) as i32;
+        overlay.y = (overlay.y as i64 + dy) as i32;
+    }
+}
+
+/// Combine two patterns on one canvas, `other` placed at the given offset
+/// The canvas grows to cover both; `other`'s layers are added above `base`'s.
+pub fn merge_projects(base: &NdpFile, other: &NdpFile, options: MergeOptions) -> Result<NdpFile, String> {
+    if base.canvas.mesh_count != other.canvas.mesh_count {
+        return Err(format!(
+            "Cannot merge patterns with different mesh counts ({} and {})",
+            base.canvas.mesh_count, other.canvas.mesh_count
+        ));
+    }
+
+    let (ox, oy) = (options.offset_x as i64, options.offset_y as i64);
+    let left = ox.min(0);
+    let top = oy.min(0);
+    let right = (base.canvas.width as i64).max(ox + other.canvas.width as i64);
+    let bottom = (base.canvas.height as i64).max(oy + other.canvas.height as i64);
+    let (width, height) = (right - left, bottom - top);
+    if width > MAX_CANVAS_SIDE as i64 || height > MAX_CANVAS_SIDE as i64 {
+        return Err(format!(
+            "Merged canvas would be {}x{} (maximum {}x{})",
+            width, height, MAX_CANVAS_SIDE, MAX_CANVAS_SIDE
+        ));
+    }
+
+    let mut merged = base.clone();
+    shift_project(&mut merged, -left, -top);
+
+    let mut incoming = other.clone();
+    shift_project(&mut incoming, ox - left, oy - top);
+
+    let color_map = merge_palettes(&mut merged.color_palette, &incoming.color_palette);
+    let remap_color = |id: &mut String| {
+        if let Some(mapped) = color_map.get(id.as_str()) {
+            *id = mapped.clone();
+        }
+    };
+
+    let mut layer_ids: HashSet<String> = merged.layers.iter().map(|l| l.id.clone()).collect();
+    for mut layer in incoming.layers {
+        layer.id = unique_id(&layer_ids, &layer.id);
+        layer_ids.insert(layer.id.clone());
+        for stitch in &mut layer.stitches {
+            remap_color(&mut stitch.color_id);
+        }
+        if let Some(LayerMetadata::Text { color_id, .. }) = layer.metadata.as_mut() {
+            remap_color(color_id);
+        }
+        merged.layers.push(layer);
+    }
+
+    if let Some(overlays) = incoming.overlays {
+        let target = merged.overlays.get_or_insert_with(Vec::new);
+        let mut overlay_ids: HashSet<String> = target.iter().map(|o| o.id.clone()).collect();
+        for mut overlay in overlays {
+            overlay.id = unique_id(&overlay_ids, &overlay.id);
+            overlay_ids.insert(overlay.id.clone());
+            target.push(overlay);
+        }
+    }
+
+    if let Some(assets) = incoming.assets {
+        let target = merged.assets.get_or_insert_with(Vec::new);
+        for asset in assets {
+            if !target.iter().any(|a| a.id == asset.id) {
+                target.push(asset);
+            }
+        }
+    }
+
+    merged.canvas = CanvasConfig {
+        width: width as u32,
+        height: height as u32,
+        mesh_count: base.canvas.mesh_count,
+        physical_width: scale_physical(base.canvas.physical_width, base.canvas.width, width as u32),
+        physical_height: scale_physical(base.canvas.physical_height, base.canvas.height, height as u32),
+    };
+    merged.thumbnail = None;
+    merged.thumbnail_fingerprint = None;
+
+    Ok(merged)
+}
+
+/// Start and end (exclusive) of section `index` of `count` along a side of `length` stitches
+fn section_span(length: u32, count: u32, index: u32, overlap: u32) -> (u32, u32) {
+    let start = (length as u64 * index as u64 / count as u64) as u32;
+    let end = (length as u64 * (index + 1) as u64 / count as u64) as u32;
+    (start.saturating_sub(overlap), (end + overlap).min(length))
+}
+
+/// Whether an overlay covers any cell of the section
+fn overlay_intersects(overlay: &OverlayImage, x: u32, y: u32, width: u32, height: u32) -> bool {
+    let (left, top) = (overlay.x as i64, overlay.y as i64);
+    let (right, bottom) = (left + overlay.width as i64, top + overlay.height as i64);
+    left < (x + width) as i64 && right > x as i64 && top < (y + height) as i64 && bottom > y as i64
+}
+
+/// Divide a pattern into a grid of sections
+/// Every section keeps the full palette so color ids and symbols match across sections.
+pub fn split_project(project: &NdpFile, options: SplitOptions) -> Result<Vec<PatternSection>, String> {
+    let (width, height) = (project.canvas.width, project.canvas.height);
+    if options.columns == 0 || options.rows == 0 {
+        return Err("A pattern must be split into at least one row and column".to_string());
+    }
+    if options.columns > width || options.rows > height {
+        return Err(format!(
+            "Cannot split a {}x{} pattern into {} columns and {} rows",
+            width, height, options.columns, options.rows
+        ));
+    }
+
+    let mut sections = Vec::with_capacity((options.columns * options.rows) as usize);
+    for row in 0..options.rows {
+        let (y, y_end) = section_span(height, options.rows, row, options.overlap);
+        for column in 0..options.columns {
+            let (x, x_end) = section_span(width, options.columns, column, options.overlap);
+            let (section_width, section_height) = (x_end - x, y_end - y);
+
+            let layers = project
+                .layers
+                .iter()
+                .map(|layer| Layer {
+                    stitches: layer
+                        .stitches
+                        .iter()
+                        .filter(|s| s.x >= x && s.x < x_end && s.y >= y && s.y < y_end)
+                        .cloned()
+                        .collect(),
+                    ..layer.clone()
+                })
+                .collect();
+
+            let overlays = project.overlays.as_ref().map(|overlays| {
+                overlays
+                    .iter()
+                    .filter(|o| overlay_intersects(o, x, y, section_width, section_height))
+                    .cloned()
+                    .collect::<Vec<_>>()
+            });
+
+            let mut section = NdpFile {
+                layers,
+                overlays,
+                canvas: CanvasConfig {
+                    width: section_width,
+                    height: section_height,
+                    mesh_count: project.canvas.mesh_count,
+                    physical_width: scale_physical(project.canvas.physical_width, width, section_width),
+                    physical_height: scale_physical(project.canvas.physical_height, height, section_height),
+                },
+                thumbnail: None,
+                thumbnail_fingerprint: None,
+                ..project.clone()
+            };
+            section.metadata.file_id = None;
+            section.metadata.name = format!("{} (row {}, column {})", project.metadata.name, row + 1, column + 1);
+            shift_project(&mut section, -(x as i64), -(y as i64));
+
+            sections.push(PatternSection { row, column, x, y, project: section });
+        }
+    }
+
+    Ok(sections)
+}
+
+/// Tauri commands for merging and splitting patterns
+pub mod commands {
+    use super::*;
+
+    /// Merge `other` into `base` at an offset, returning the combined pattern
+    #[tauri::command]
+    pub fn merge_projects(base: NdpFile, other: NdpFile, options: MergeOptions) -> Result<NdpFile, String> {
+        super::merge_projects(&base, &other, options)
+    }
+
+    /// Split a pattern into a grid of sub-projects
+    #[tauri::command]
+    pub fn split_project(project: NdpFile, options: SplitOptions) -> Result<Vec<PatternSection>, String> {
+        super::split_project(&project, options)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::{self, stitch};
+    use crate::Stitch;
+
+    fn color(id: &str, code: Option<&str>, rgb: [u8; 3], symbol: &str) -> Color {
+        let color = match code {
+            Some(code) => fixtures::thread_color(id, "DMC", code, rgb),
+            None => fixtures::color(id, rgb),
+        };
+        Color { symbol: Some(symbol.to_string()), ..color }
+    }
+
+    fn project(width: u32, height: u32, palette: Vec<Color>, stitches: Vec<Stitch>) -> NdpFile {
+        let mut project = fixtures::project(width, height, palette, stitches);
+        project.metadata.file_id = Some("file-1".to_string());
+        project.canvas.physical_width = Some(width as f64 / 13.0);
+        project
+    }
+
+    #[test]
+    fn test_merge_reconciles_palettes() {
+        let base = project(
+            10,
+            10,
+            vec![color("c1", Some("321"), [200, 0, 0], "A"), color("c2", None, [0, 0, 0], "B")],
+            vec![stitch(0, 0, "c1")],
+        );
+        let other = project(
+            5,
+            5,
+            vec![
+                color("red", Some("321"), [190, 10, 10], "X"), // Same thread, different id
+                color("c2", None, [0, 0, 0], "B"),             // Same uncoded color
+                color("c1", Some("3865"), [250, 250, 250], "B"), // New thread, clashing id and symbol
+            ],
+            vec![stitch(0, 0, "red"), stitch(1, 0, "c2"), stitch(2, 0, "c1")],
+        );
+
+        let merged = merge_projects(&base, &other, MergeOptions { offset_x: -2, offset_y: 12 }).unwrap();
+        assert_eq!((merged.canvas.width, merged.canvas.height), (12, 17));
+        assert_eq!(merged.color_palette.len(), 3);
+        assert_eq!(merged.color_palette[2].id, "c1-2");
+        assert_eq!(merged.color_palette[2].symbol, None);
+
+        // Base content moved right to make room for the negative offset
+        assert_eq!((merged.layers[0].stitches[0].x, merged.layers[0].stitches[0].y), (2, 0));
+
+        let added = &merged.layers[1];
+        assert_eq!(added.id, "layer-1-2");
+        let placed: Vec<(u32, u32, &str)> =
+            added.stitches.iter().map(|s| (s.x, s.y, s.color_id.as_str())).collect();
+        assert_eq!(placed, vec![(0, 12, "c1"), (1, 12, "c2"), (2, 12, "c1-2")]);
+
+        let other_mesh = CanvasConfig { mesh_count: 18, ..other.canvas.clone() };
+        assert!(merge_projects(&base, &NdpFile { canvas: other_mesh, ..other }, MergeOptions::default()).is_err());
+    }
+
+    #[test]
+    fn test_split_with_overlap() {
+        let palette = vec![color("c1", Some("321"), [200, 0, 0], "A")];
+        let stitches = vec![stitch(0, 0, "c1"), stitch(4, 1, "c1"), stitch(5, 1, "c1"), stitch(9, 3, "c1")];
+        let source = project(10, 4, palette, stitches);
+
+        let sections = split_project(&source, SplitOptions { columns: 2, rows: 1, overlap: 1 }).unwrap();
+        assert_eq!(sections.len(), 2);
+
+        let (left, right) = (&sections[0], &sections[1]);
+        assert_eq!((left.x, left.project.canvas.width), (0, 6));
+        assert_eq!((right.x, right.project.canvas.width), (4, 6));
+        assert_eq!(right.project.metadata.name, "Roses (row 1, column 2)");
+        assert_eq!(right.project.metadata.file_id, None);
+        assert_eq!(right.project.color_palette.len(), 1);
+
+        // Stitches on the seam appear in both sections, in local coordinates
+        let local = |s: &PatternSection| -> Vec<(u32, u32)> {
+            s.project.layers[0].stitches.iter().map(|st| (st.x, st.y)).collect()
+        };
+        assert_eq!(local(left), vec![(0, 0), (4, 1), (5, 1)]);
+        assert_eq!(local(right), vec![(0, 1), (1, 1), (5, 3)]);
+
+        assert!(split_project(&source, SplitOptions { columns: 0, rows: 1, overlap: 0 }).is_err());
+        assert!(split_project(&source, SplitOptions { columns: 2, rows: 5, overlap: 0 }).is_err());
+    }
+}
@@ -0,0 +1,841 @@
+// Pattern Export Module
+// Renders NdpFile patterns to raster (PNG/JPEG) and vector (SVG) images
+
+use crate::{Color, NdpFile, Stitch};
+use image::{codecs::jpeg::JpegEncoder, DynamicImage, ImageFormat, Rgba, RgbaImage};
+use resvg::tiny_skia::{self, FillRule, LineCap, Paint, PathBuilder, Pixmap, Rect, Stroke, Transform};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fmt::Write;
+use std::io::Cursor;
+
+/// Largest allowed side of a rendered image in pixels
+pub const MAX_EXPORT_DIMENSION: u32 = 16384;
+
+/// Largest allowed rendered image in total pixels (about 400 MB of RGBA)
+pub const MAX_EXPORT_PIXELS: u64 = 100_000_000;
+
+/// Circle radius relative to cell size (matches the canvas renderer)
+const CIRCLE_RADIUS_FACTOR: f32 = 0.28;
+
+/// Unstitched canvas color shown behind the stitched preview
+const CANVAS_RGB: [u8; 3] = [236, 229, 212];
+
+/// Number of cells between bold grid lines in symbol charts
+const GRID_DIVISIONS: u32 = 10;
+
+/// How stitches are drawn in a raster export
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum RenderMode {
+    /// Solid color per stitch
+    #[default]
+    Flat,
+    /// Printable chart with symbols and grid lines
+    Symbols,
+    /// Realistic thread texture on canvas
+    Stitched,
+}
+
+/// Raster image encodings supported by the export
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum RasterFormat {
+    #[default]
+    Png,
+    Jpeg,
+}
+
+fn default_pixels_per_stitch() -> u32 {
+    10
+}
+
+fn default_jpeg_quality() -> u8 {
+    90
+}
+
+/// Options for raster image export
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ImageExportOptions {
+    #[serde(default = "default_pixels_per_stitch")]
+    pub pixels_per_stitch: u32,
+    #[serde(default)]
+    pub mode: RenderMode,
+    #[serde(default)]
+    pub format: RasterFormat,
+    #[serde(default = "default_jpeg_quality")]
+    pub jpeg_quality: u8,
+    /// Background behind empty cells (transparent when None; JPEG falls back to white)
+    #[serde(default)]
+    pub background: Option<[u8; 3]>,
+}
+
+impl Default for ImageExportOptions {
+    fn default() -> Self {
+        ImageExportOptions {
+            pixels_per_stitch: default_pixels_per_stitch(),
+            mode: RenderMode::default(),
+            format: RasterFormat::default(),
+            jpeg_quality: default_jpeg_quality(),
+            background: None,
+        }
+    }
+}
+
+/// Options for SVG vector export
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SvgExportOptions {
+    #[serde(default = "default_pixels_per_stitch")]
+    pub pixels_per_stitch: u32,
+    #[serde(default)]
+    pub show_symbols: bool,
+    #[serde(default)]
+    pub show_grid: bool,
+    #[serde(default)]
+    pub background: Option<[u8; 3]>,
+}
+
+impl Default for SvgExportOptions {
+    fn default() -> Self {
+        SvgExportOptions {
+            pixels_per_stitch: default_pixels_per_stitch(),
+            show_symbols: false,
+            show_grid: false,
+            background: None,
+        }
+    }
+}
+
+/// Geometry of a single stitch in cell units (0.0-1.0 spans one cell)
+#[derive(Debug, Clone, PartialEq)]
+enum StitchShape {
+    Rect { x: f32, y: f32, w: f32, h: f32 },
+    RoundedRect { x: f32, y: f32, w: f32, h: f32, r: f32 },
+    Triangle([(f32, f32); 3]),
+    Circle { cx: f32, cy: f32, r: f32 },
+    Line { x1: f32, y1: f32, x2: f32, y2: f32, width: f32 },
+}
+
+impl StitchShape {
+    /// Point where a symbol is centered inside the shape (None for thin shapes)
+    fn symbol_anchor(&self) -> Option<(f32, f32, f32)> {
+        match self {
+            StitchShape::Rect { x, y, w, h } => {
+                let size = if *w >= 1.0 && *h >= 1.0 { 0.7 } else { 0.5 };
+                Some((x + w / 2.0, y + h / 2.0, size))
+            }
+            StitchShape::Triangle(points) => {
+                let cx = points.iter().map(|p| p.0).sum::<f32>() / 3.0;
+                let cy = points.iter().map(|p| p.1).sum::<f32>() / 3.0;
+                Some((cx, cy, 0.5))
+            }
+            StitchShape::Circle { cx, cy, r } => Some((*cx, *cy, r * 1.6)),
+            StitchShape::RoundedRect { .. } | StitchShape::Line { .. } => None,
+        }
+    }
+}
+
+/// Center offset of a circle stitch within its cell
+fn circle_center(position: Option<&str>) -> (f32, f32) {
+    match position.unwrap_or("center") {
+        "top-left" => (0.0, 0.0),
+        "top-center" => (0.5, 0.0),
+        "top-right" => (1.0, 0.0),
+        "middle-left" => (0.0, 0.5),
+        "middle-right" => (1.0, 0.5),
+        "bottom-left" => (0.0, 1.0),
+        "bottom-center" => (0.5, 1.0),
+        "bottom-right" => (1.0, 1.0),
+        _ => (0.5, 0.5),
+    }
+}
+
+/// Map a stitch's type to its geometry
+fn stitch_shape(stitch: &Stitch) -> StitchShape {
+    const EXTEND: f32 = 0.1;
+    const THICKNESS: f32 = 0.2;
+
+    match stitch.stitch_type.as_deref().unwrap_or("square") {
+        "circle" => {
+            let (cx, cy) = circle_center(stitch.position.as_deref());
+            StitchShape::Circle { cx, cy, r: CIRCLE_RADIUS_FACTOR }
+        }
+        "circle-full" => StitchShape::Circle { cx: 0.5, cy: 0.5, r: 0.45 },
+        "half-tl" => StitchShape::Triangle([(0.0, 0.0), (1.0, 0.0), (0.0, 1.0)]),
+        "half-tr" => StitchShape::Triangle([(0.0, 0.0), (1.0, 0.0), (1.0, 1.0)]),
+        "half-bl" => StitchShape::Triangle([(0.0, 0.0), (0.0, 1.0), (1.0, 1.0)]),
+        "half-br" => StitchShape::Triangle([(1.0, 0.0), (0.0, 1.0), (1.0, 1.0)]),
+        "half-top" => StitchShape::Rect { x: 0.0, y: 0.0, w: 1.0, h: 0.5 },
+        "half-bottom" => StitchShape::Rect { x: 0.0, y: 0.5, w: 1.0, h: 0.5 },
+        "half-left" => StitchShape::Rect { x: 0.0, y: 0.0, w: 0.5, h: 1.0 },
+        "half-right" => StitchShape::Rect { x: 0.5, y: 0.0, w: 0.5, h: 1.0 },
+        "quarter-tl" => StitchShape::Rect { x: 0.0, y: 0.0, w: 0.5, h: 0.5 },
+        "quarter-tr" => StitchShape::Rect { x: 0.5, y: 0.0, w: 0.5, h: 0.5 },
+        "quarter-bl" => StitchShape::Rect { x: 0.0, y: 0.5, w: 0.5, h: 0.5 },
+        "quarter-br" => StitchShape::Rect { x: 0.5, y: 0.5, w: 0.5, h: 0.5 },
+        "border-top" => StitchShape::RoundedRect {
+            x: -EXTEND, y: 0.0, w: 1.0 + EXTEND * 2.0, h: THICKNESS, r: THICKNESS / 2.0,
+        },
+        "border-bottom" => StitchShape::RoundedRect {
+            x: -EXTEND, y: 1.0 - THICKNESS, w: 1.0 + EXTEND * 2.0, h: THICKNESS, r: THICKNESS / 2.0,
+        },
+        "border-left" => StitchShape::RoundedRect {
+            x: 0.0, y: -EXTEND, w: THICKNESS, h: 1.0 + EXTEND * 2.0, r: THICKNESS / 2.0,
+        },
+        "border-right" => StitchShape::RoundedRect {
+            x: 1.0 - THICKNESS, y: -EXTEND, w: THICKNESS, h: 1.0 + EXTEND * 2.0, r: THICKNESS / 2.0,
+        },
+        "cross-tlbr" => StitchShape::Line {
+            x1: -EXTEND, y1: -EXTEND, x2: 1.0 + EXTEND, y2: 1.0 + EXTEND, width: THICKNESS,
+        },
+        "cross-trbl" => StitchShape::Line {
+            x1: 1.0 + EXTEND, y1: -EXTEND, x2: -EXTEND, y2: 1.0 + EXTEND, width: THICKNESS,
+        },
+        _ => StitchShape::Rect { x: 0.0, y: 0.0, w: 1.0, h: 1.0 },
+    }
+}
+
+/// Circles, borders and cross lines overlap neighbouring cells and are drawn last
+fn is_overlay_stitch(stitch: &Stitch) -> bool {
+    matches!(
+        stitch.stitch_type.as_deref(),
+        Some("circle")
+            | Some("border-top")
+            | Some("border-bottom")
+            | Some("border-left")
+            | Some("border-right")
+            | Some("cross-tlbr")
+            | Some("cross-trbl")
+    )
+}
+
+/// Visible stitches inside the canvas, bottom layer first
+fn visible_stitches(project: &NdpFile) -> impl Iterator<Item = &Stitch> {
+    let (width, height) = (project.canvas.width, project.canvas.height);
+    project
+        .layers
+        .iter()
+        .filter(|layer| layer.visible)
+        .flat_map(|layer| layer.stitches.iter())
+        .filter(move |s| s.x < width && s.y < height)
+}
+
+/// Visible stitches in draw order: cell fills first, then overlapping shapes
+fn stitches_in_draw_order(project: &NdpFile) -> impl Iterator<Item = &Stitch> {
+    visible_stitches(project)
+        .filter(|s| !is_overlay_stitch(s))
+        .chain(visible_stitches(project).filter(|s| is_overlay_stitch(s)))
+}
+
+fn palette_lookup(project: &NdpFile) -> HashMap<&str, &Color> {
+    project
+        .color_palette
+        .iter()
+        .map(|c| (c.id.as_str(), c))
+        .collect()
+}
+
+/// Scale an RGB color towards black (factor < 1) or white (factor > 1)
+fn shade(rgb: [u8; 3], factor: f32) -> [u8; 3] {
+    let adjust = |c: u8| -> u8 {
+        let c = c as f32;
+        let v = if factor <= 1.0 {
+            c * factor
+        } else {
+            c + (255.0 - c) * (factor - 1.0)
+        };
+        v.round().clamp(0.0, 255.0) as u8
+    };
+    [adjust(rgb[0]), adjust(rgb[1]), adjust(rgb[2])]
+}
+
+/// Black or white, whichever reads better on top of the given color
+fn contrast_color(rgb: [u8; 3]) -> [u8; 3] {
+    let luminance = (0.299 * rgb[0] as f64 + 0.587 * rgb[1] as f64 + 0.114 * rgb[2] as f64) / 255.0;
+    if luminance > 0.5 {
+        [0, 0, 0]
+    } else {
+        [255, 255, 255]
+    }
+}
+
+fn hex(rgb: [u8; 3]) -> String {
+    format!("#{:02x}{:02x}{:02x}", rgb[0], rgb[1], rgb[2])
+}
+
+fn escape_xml(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for ch in text.chars() {
+        match ch {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            _ => escaped.push(ch),
+        }
+    }
+    escaped
+}
+
+fn paint(rgb: [u8; 3], alpha: u8) -> Paint<'static> {
+    let mut paint = Paint::default();
+    paint.set_color_rgba8(rgb[0], rgb[1], rgb[2], alpha);
+    paint.anti_alias = true;
+    paint
+}
+
+/// Check the requested output size and return the pixmap dimensions
+fn output_size(project: &NdpFile, pixels_per_stitch: u32) -> Result<(u32, u32), String> {
+    if pixels_per_stitch == 0 {
+        return Err("Pixels per stitch must be at least 1".to_string());
+    }
+    if project.canvas.width == 0 || project.canvas.height == 0 {
+        return Err("Pattern has zero dimensions".to_string());
+    }
+
+    let width = project.canvas.width.checked_mul(pixels_per_stitch);
+    let height = project.canvas.height.checked_mul(pixels_per_stitch);
+    let (width, height) = match (width, height) {
+        (Some(w), Some(h)) if w <= MAX_EXPORT_DIMENSION && h <= MAX_EXPORT_DIMENSION => (w, h),
+        _ => {
+            return Err(format!(
+                "Export too large: {}x{} stitches at {} px per stitch exceeds {} px",
+                project.canvas.width, project.canvas.height, pixels_per_stitch, MAX_EXPORT_DIMENSION
+            ))
+        }
+    };
+
+    let pixels = width as u64 * height as u64;
+    if pixels > MAX_EXPORT_PIXELS {
+        return Err(format!(
+            "Export too large: {}x{} px exceeds {} megapixels; lower the pixels per stitch",
+            width,
+            height,
+            MAX_EXPORT_PIXELS / 1_000_000
+        ));
+    }
+    Ok((width, height))
+}
+
+/// Build a tiny-skia path for a closed shape, in pixels
+fn shape_path(shape: &StitchShape, ox: f32, oy: f32, scale: f32) -> Option<tiny_skia::Path> {
+    match *shape {
+        StitchShape::Rect { x, y, w, h } => {
+            let rect = Rect::from_xywh(ox + x * scale, oy + y * scale, w * scale, h * scale)?;
+            Some(PathBuilder::from_rect(rect))
+        }
+        StitchShape::RoundedRect { x, y, w, h, r } => {
+            let (x, y, w, h) = (ox + x * scale, oy + y * scale, w * scale, h * scale);
+            let r = (r * scale).min(w / 2.0).min(h / 2.0);
+            let mut pb = PathBuilder::new();
+            pb.move_to(x + r, y);
+            pb.line_to(x + w - r, y);
+            pb.quad_to(x + w, y, x + w, y + r);
+            pb.line_to(x + w, y + h - r);
+            pb.quad_to(x + w, y + h, x + w - r, y + h);
+            pb.line_to(x + r, y + h);
+            pb.quad_to(x, y + h, x, y + h - r);
+            pb.line_to(x, y + r);
+            pb.quad_to(x, y, x + r, y);
+            pb.close();
+            pb.finish()
+        }
+        StitchShape::Triangle(points) => {
+            let mut pb = PathBuilder::new();
+            pb.move_to(ox + points[0].0 * scale, oy + points[0].1 * scale);
+            pb.line_to(ox + points[1].0 * scale, oy + points[1].1 * scale);
+            pb.line_to(ox + points[2].0 * scale, oy + points[2].1 * scale);
+            pb.close();
+            pb.finish()
+        }
+        StitchShape::Circle { cx, cy, r } => {
+            PathBuilder::from_circle(ox + cx * scale, oy + cy * scale, r * scale)
+        }
+        StitchShape::Line { .. } => None,
+    }
+}
+
+/// Draw a straight line with round caps
+fn stroke_line(pixmap: &mut Pixmap, from: (f32, f32), to: (f32, f32), width: f32, paint: &Paint) {
+    let mut pb = PathBuilder::new();
+    pb.move_to(from.0, from.1);
+    pb.line_to(to.0, to.1);
+    if let Some(path) = pb.finish() {
+        let stroke = Stroke {
+            width,
+            line_cap: LineCap::Round,
+            ..Default::default()
+        };
+        pixmap.stroke_path(&path, paint, &stroke, Transform::identity(), None);
+    }
+}
+
+/// Draw a solid shape at cell (ox, oy)
+fn fill_shape(pixmap: &mut Pixmap, shape: &StitchShape, ox: f32, oy: f32, scale: f32, rgb: [u8; 3]) {
+    let fill = paint(rgb, 255);
+    if let StitchShape::Line { x1, y1, x2, y2, width } = *shape {
+        stroke_line(
+            pixmap,
+            (ox + x1 * scale, oy + y1 * scale),
+            (ox + x2 * scale, oy + y2 * scale),
+            width * scale,
+            &fill,
+        );
+        return;
+    }
+
+    if let Some(path) = shape_path(shape, ox, oy, scale) {
+        pixmap.fill_path(&path, &fill, FillRule::Winding, Transform::identity(), None);
+    }
+}
+
+/// Draw a single thread strand with shadow, body and highlight
+fn draw_strand(pixmap: &mut Pixmap, from: (f32, f32), to: (f32, f32), width: f32, rgb: [u8; 3]) {
+    stroke_line(pixmap, from, to, width, &paint(shade(rgb, 0.7), 255));
+    stroke_line(pixmap, from, to, width * 0.7, &paint(rgb, 255));
+
+    // Offset the highlight towards the top-left light source
+    let (dx, dy) = (to.0 - from.0, to.1 - from.1);
+    let len = (dx * dx + dy * dy).sqrt().max(f32::EPSILON);
+    let (nx, ny) = (dy / len, -dx / len);
+    let (nx, ny) = if nx + ny > 0.0 { (-nx, -ny) } else { (nx, ny) };
+    let offset = width * 0.15;
+    stroke_line(
+        pixmap,
+        (from.0 + nx * offset, from.1 + ny * offset),
+        (to.0 + nx * offset, to.1 + ny * offset),
+        width * 0.2,
+        &paint(shade(rgb, 1.35), 160),
+    );
+}
+
+/// Draw one stitch with thread texture (tent stitches, cross lines, knots)
+fn draw_textured_stitch(pixmap: &mut Pixmap, stitch: &Stitch, scale: f32, rgb: [u8; 3]) {
+    let ox = stitch.x as f32 * scale;
+    let oy = stitch.y as f32 * scale;
+    let shape = stitch_shape(stitch);
+
+    match shape {
+        // Tent stitch: a single slanted strand from bottom-left to top-right
+        StitchShape::Rect { w, h, .. } if w >= 1.0 && h >= 1.0 => {
+            let inset = scale * 0.15;
+            draw_strand(
+                pixmap,
+                (ox + inset, oy + scale - inset),
+                (ox + scale - inset, oy + inset),
+                scale * 0.62,
+                rgb,
+            );
+        }
+        StitchShape::Line { x1, y1, x2, y2, width } => {
+            draw_strand(
+                pixmap,
+                (ox + x1 * scale, oy + y1 * scale),
+                (ox + x2 * scale, oy + y2 * scale),
+                width * scale * 1.4,
+                rgb,
+            );
+        }
+        StitchShape::RoundedRect { x, y, w, h, .. } => {
+            let (from, to, width) = if w > h {
+                ((x, y + h / 2.0), (x + w, y + h / 2.0), h)
+            } else {
+                ((x + w / 2.0, y), (x + w / 2.0, y + h), w)
+            };
+            draw_strand(
+                pixmap,
+                (ox + from.0 * scale, oy + from.1 * scale),
+                (ox + to.0 * scale, oy + to.1 * scale),
+                width * scale * 1.2,
+                rgb,
+            );
+        }
+        StitchShape::Circle { cx, cy, r } => {
+            // Knot: dark rim with an off-center highlight
+            let (px, py, pr) = (ox + cx * scale, oy + cy * scale, r * scale);
+            if let Some(path) = PathBuilder::from_circle(px, py, pr) {
+                pixmap.fill_path(&path, &paint(shade(rgb, 0.7), 255), FillRule::Winding, Transform::identity(), None);
+            }
+            if let Some(path) = PathBuilder::from_circle(px, py, pr * 0.8) {
+                pixmap.fill_path(&path, &paint(rgb, 255), FillRule::Winding, Transform::identity(), None);
+            }
+            if let Some(path) = PathBuilder::from_circle(px - pr * 0.3, py - pr * 0.3, pr * 0.3) {
+                pixmap.fill_path(&path, &paint(shade(rgb, 1.35), 160), FillRule::Winding, Transform::identity(), None);
+            }
+        }
+        // Partial cells: flat fill with a darker edge
+        _ => {
+            fill_shape(pixmap, &shape, ox, oy, scale, shade(rgb, 0.8));
+            let inset = scale * 0.08;
+            fill_shape(pixmap, &shape, ox + inset / 2.0, oy + inset / 2.0, scale - inset, rgb);
+        }
+    }
+}
+
+/// Draw the bare canvas mesh behind a stitched preview
+fn draw_canvas_mesh(pixmap: &mut Pixmap, project: &NdpFile, scale: f32) {
+    pixmap.fill(tiny_skia::Color::from_rgba8(CANVAS_RGB[0], CANVAS_RGB[1], CANVAS_RGB[2], 255));
+
+    if scale < 4.0 {
+        return;
+    }
+
+    // Holes in the mesh sit at every grid intersection
+    let hole = paint(shade(CANVAS_RGB, 0.75), 255);
+    let radius = (scale * 0.12).max(0.75);
+    for y in 0..=project.canvas.height {
+        for x in 0..=project.canvas.width {
+            if let Some(path) = PathBuilder::from_circle(x as f32 * scale, y as f32 * scale, radius) {
+                pixmap.fill_path(&path, &hole, FillRule::Winding, Transform::identity(), None);
+            }
+        }
+    }
+}
+
+/// Convert a premultiplied pixmap into a straight-alpha image
+fn pixmap_to_image(pixmap: &Pixmap) -> RgbaImage {
+    let mut img = RgbaImage::new(pixmap.width(), pixmap.height());
+    for (pixel, src) in img.pixels_mut().zip(pixmap.pixels()) {
+        let c = src.demultiply();
+        *pixel = Rgba([c.red(), c.green(), c.blue(), c.alpha()]);
+    }
+    img
+}
+
+/// Render a pattern to an RGBA image
+pub fn render_pattern(project: &NdpFile, options: &ImageExportOptions) -> Result<RgbaImage, String> {
+    let (width, height) = output_size(project, options.pixels_per_stitch)?;
+
+    if options.mode == RenderMode::Symbols {
+        let svg = render_svg(
+            project,
+            &SvgExportOptions {
+                pixels_per_stitch: options.pixels_per_stitch,
+                show_symbols: true,
+                show_grid: true,
+                background: Some(options.background.unwrap_or([255, 255, 255])),
+            },
+        )?;
+        return rasterize_svg(&svg);
+    }
+
+    let mut pixmap = Pixmap::new(width, height).ok_or("Failed to create pixmap")?;
+    let scale = options.pixels_per_stitch as f32;
+    let colors = palette_lookup(project);
+
+    if options.mode == RenderMode::Stitched {
+        draw_canvas_mesh(&mut pixmap, project, scale);
+    } else if let Some(bg) = options.background {
+        pixmap.fill(tiny_skia::Color::from_rgba8(bg[0], bg[1], bg[2], 255));
+    }
+
+    for stitch in stitches_in_draw_order(project) {
+        let Some(color) = colors.get(stitch.color_id.as_str()) else {
+            continue;
+        };
+
+        if options.mode == RenderMode::Stitched {
+            draw_textured_stitch(&mut pixmap, stitch, scale, color.rgb);
+        } else {
+            let shape = stitch_shape(stitch);
+            let ox = stitch.x as f32 * scale;
+            let oy = stitch.y as f32 * scale;
+            fill_shape(&mut pixmap, &shape, ox, oy, scale, color.rgb);
+        }
+    }
+
+    Ok(pixmap_to_image(&pixmap))
+}
+
+/// Render an SVG document to an RGBA image using system fonts for symbols
+fn rasterize_svg(svg: &str) -> Result<RgbaImage, String> {
+    let mut options = resvg::usvg::Options::default();
+    options.fontdb_mut().load_system_fonts();
+
+    let tree = resvg::usvg::Tree::from_str(svg, &options)
+        .map_err(|e| format!("Failed to parse SVG: {}", e))?;
+
+    let size = tree.size().to_int_size();
+    let mut pixmap = Pixmap::new(size.width(), size.height()).ok_or("Failed to create pixmap")?;
+    resvg::render(&tree, Transform::default(), &mut pixmap.as_mut());
+
+    Ok(pixmap_to_image(&pixmap))
+}
+
+/// Encode a rendered pattern as PNG or JPEG bytes
+pub fn encode_image(img: RgbaImage, options: &ImageExportOptions) -> Result<Vec<u8>, String> {
+    let mut bytes: Vec<u8> = Vec::new();
+
+    match options.format {
+        RasterFormat::Png => {
+            DynamicImage::ImageRgba8(img)
+                .write_to(&mut Cursor::new(&mut bytes), ImageFormat::Png)
+                .map_err(|e| format!("Failed to encode PNG: {}", e))?;
+        }
+        RasterFormat::Jpeg => {
+            // JPEG has no alpha channel, so flatten onto the background
+            let bg = options.background.unwrap_or([255, 255, 255]);
+            let mut flattened = image::RgbImage::new(img.width(), img.height());
+            for (dst, src) in flattened.pixels_mut().zip(img.pixels()) {
+                let a = src[3] as u32;
+                for i in 0..3 {
+                    dst[i] = ((src[i] as u32 * a + bg[i] as u32 * (255 - a)) / 255) as u8;
+                }
+            }
+
+            let quality = options.jpeg_quality.clamp(1, 100);
+            JpegEncoder::new_with_quality(&mut Cursor::new(&mut bytes), quality)
+                .encode_image(&flattened)
+                .map_err(|e| format!("Failed to encode JPEG: {}", e))?;
+        }
+    }
+
+    Ok(bytes)
+}
+
+/// Write one stitch shape as an SVG element (fill comes from the enclosing group)
+fn write_svg_shape(out: &mut String, shape: &StitchShape, ox: f32, oy: f32, scale: f32, rgb: [u8; 3]) {
+    let _ = match *shape {
+        StitchShape::Rect { x, y, w, h } => writeln!(
+            out,
+            r#"    <rect x="{}" y="{}" width="{}" height="{}"/>"#,
+            ox + x * scale, oy + y * scale, w * scale, h * scale
+        ),
+        StitchShape::RoundedRect { x, y, w, h, r } => writeln!(
+            out,
+            r#"    <rect x="{}" y="{}" width="{}" height="{}" rx="{}"/>"#,
+            ox + x * scale, oy + y * scale, w * scale, h * scale, r * scale
+        ),
+        StitchShape::Triangle(points) => writeln!(
+            out,
+            r#"    <polygon points="{},{} {},{} {},{}"/>"#,
+            ox + points[0].0 * scale, oy + points[0].1 * scale,
+            ox + points[1].0 * scale, oy + points[1].1 * scale,
+            ox + points[2].0 * scale, oy + points[2].1 * scale
+        ),
+        StitchShape::Circle { cx, cy, r } => writeln!(
+            out,
+            r##"    <circle cx="{}" cy="{}" r="{}" stroke="#000000" stroke-width="{}"/>"##,
+            ox + cx * scale, oy + cy * scale, r * scale, (scale * 0.05).max(1.0)
+        ),
+        StitchShape::Line { x1, y1, x2, y2, width } => writeln!(
+            out,
+            r#"    <line x1="{}" y1="{}" x2="{}" y2="{}" stroke="{}" stroke-width="{}" stroke-linecap="round"/>"#,
+            ox + x1 * scale, oy + y1 * scale, ox + x2 * scale, oy + y2 * scale, hex(rgb), width * scale
+        ),
+    };
+}
+
+/// Write stitches grouped by palette color, preserving palette order
+fn write_svg_color_groups<'a>(
+    out: &mut String,
+    project: &'a NdpFile,
+    stitches: impl Iterator<Item = &'a Stitch>,
+    scale: f32,
+) {
+    let mut by_color: HashMap<&str, Vec<&Stitch>> = HashMap::new();
+    for stitch in stitches {
+        by_color.entry(stitch.color_id.as_str()).or_default().push(stitch);
+    }
+
+    for color in &project.color_palette {
+        let Some(group) = by_color.get(color.id.as_str()) else {
+            continue;
+        };
+
+        let thread = match (&color.thread_brand, &color.thread_code, &color.blend_thread_code) {
+            (Some(brand), Some(code), Some(blend)) => {
+                format!(r#" data-thread="{} {}+{}""#, escape_xml(brand), escape_xml(code), escape_xml(blend))
+            }
+            (Some(brand), Some(code), None) => format!(r#" data-thread="{} {}""#, escape_xml(brand), escape_xml(code)),
+            _ => String::new(),
+        };
+        let _ = writeln!(
+            out,
+            r#"  <g data-color-id="{}" data-name="{}"{} fill="{}">"#,
+            escape_xml(&color.id),
+            escape_xml(&color.name),
+            thread,
+            hex(color.rgb)
+        );
+        for stitch in group {
+            let shape = stitch_shape(stitch);
+            write_svg_shape(out, &shape, stitch.x as f32 * scale, stitch.y as f32 * scale, scale, color.rgb);
+        }
+        out.push_str("  </g>\n");
+    }
+}
+
+/// Render a pattern as an SVG document with one element per stitch, grouped by color
+pub fn render_svg(project: &NdpFile, options: &SvgExportOptions) -> Result<String, String> {
+    let (width, height) = output_size(project, options.pixels_per_stitch)?;
+    let scale = options.pixels_per_stitch as f32;
+    let colors = palette_lookup(project);
+
+    let mut out = String::new();
+    let _ = writeln!(
+        out,
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="{w}" height="{h}" viewBox="0 0 {w} {h}">"#,
+        w = width,
+        h = height
+    );
+    let _ = writeln!(out, "  <title>{}</title>", escape_xml(&project.metadata.name));
+
+    if let Some(bg) = options.background {
+        let _ = writeln!(out, r#"  <rect width="{}" height="{}" fill="{}"/>"#, width, height, hex(bg));
+    }
+
+    out.push_str("  <g id=\"stitches\">\n");
+    write_svg_color_groups(&mut out, project, visible_stitches(project).filter(|s| !is_overlay_stitch(s)), scale);
+    out.push_str("  </g>\n");
+
+    if options.show_grid {
+        out.push_str("  <g id=\"grid\" stroke-linecap=\"square\">\n");
+        for x in 0..=project.canvas.width {
+            let major = x % GRID_DIVISIONS == 0;
+            let _ = writeln!(
+                out,
+                r#"    <line x1="{x}" y1="0" x2="{x}" y2="{h}" stroke="{}" stroke-width="{}"/>"#,
+                if major { "#999999" } else { "#e0e0e0" },
+                if major { 2 } else { 1 },
+                x = x as f32 * scale,
+                h = height
+            );
+        }
+        for y in 0..=project.canvas.height {
+            let major = y % GRID_DIVISIONS == 0;
+            let _ = writeln!(
+                out,
+                r#"    <line x1="0" y1="{y}" x2="{w}" y2="{y}" stroke="{}" stroke-width="{}"/>"#,
+                if major { "#999999" } else { "#e0e0e0" },
+                if major { 2 } else { 1 },
+                y = y as f32 * scale,
+                w = width
+            );
+        }
+        out.push_str("  </g>\n");
+    }
+
+    out.push_str("  <g id=\"overlay-stitches\">\n");
+    write_svg_color_groups(&mut out, project, visible_stitches(project).filter(|s| is_overlay_stitch(s)), scale);
+    out.push_str("  </g>\n");
+
+    if options.show_symbols {
+        out.push_str(
+            "  <g id=\"symbols\" font-family=\"sans-serif\" font-weight=\"bold\" text-anchor=\"middle\" dominant-baseline=\"central\">\n",
+        );
+        for stitch in stitches_in_draw_order(project) {
+            let Some(color) = colors.get(stitch.color_id.as_str()) else {
+                continue;
+            };
+            let Some(symbol) = color.symbol.as_deref().filter(|s| !s.is_empty()) else {
+                continue;
+            };
+            let Some((cx, cy, size)) = stitch_shape(stitch).symbol_anchor() else {
+                continue;
+            };
+            let _ = writeln!(
+                out,
+                r#"    <text x="{}" y="{}" font-size="{}" fill="{}">{}</text>"#,
+                (stitch.x as f32 + cx) * scale,
+                (stitch.y as f32 + cy) * scale,
+                size * scale,
+                hex(contrast_color(color.rgb)),
+                escape_xml(symbol)
+            );
+        }
+        out.push_str("  </g>\n");
+    }
+
+    out.push_str("</svg>\n");
+    Ok(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures;
+
+    fn stitch(x: u32, y: u32, color_id: &str, stitch_type: Option<&str>) -> Stitch {
+        Stitch { stitch_type: stitch_type.map(|s| s.to_string()), ..fixtures::stitch(x, y, color_id) }
+    }
+
+    fn test_project() -> NdpFile {
+        let red = Color {
+            name: "Red".to_string(),
+            symbol: Some("X".to_string()),
+            ..fixtures::thread_color("red", "DMC", "666", [255, 0, 0])
+        };
+        let blue = Color { name: "Blue".to_string(), ..fixtures::color("blue", [0, 0, 255]) };
+        let stitches = vec![
+            stitch(0, 0, "red", None),
+            stitch(1, 0, "blue", Some("circle")),
+            stitch(2, 1, "red", Some("half-tl")),
+        ];
+
+        let mut project = fixtures::project(4, 3, vec![red, blue], stitches);
+        project.metadata.name = "Test & Pattern".to_string();
+        project.canvas.mesh_count = 18;
+        project
+    }
+
+    #[test]
+    fn test_render_flat_dimensions_and_colors() {
+        let project = test_project();
+        let options = ImageExportOptions {
+            pixels_per_stitch: 8,
+            ..Default::default()
+        };
+        let img = render_pattern(&project, &options).unwrap();
+
+        assert_eq!(img.dimensions(), (32, 24));
+        assert_eq!(img.get_pixel(4, 4), &Rgba([255, 0, 0, 255]));
+        // Empty cells stay transparent
+        assert_eq!(img.get_pixel(28, 20)[3], 0);
+    }
+
+    #[test]
+    fn test_render_rejects_oversized_export() {
+        let mut project = test_project();
+        project.canvas.width = 5000;
+        let options = ImageExportOptions {
+            pixels_per_stitch: 10,
+            ..Default::default()
+        };
+        assert!(render_pattern(&project, &options).is_err());
+
+        // Each side fits, but the total is over the pixel budget
+        project.canvas.width = 1200;
+        project.canvas.height = 1200;
+        assert!(output_size(&project, 10).is_err());
+        assert_eq!(output_size(&project, 8), Ok((9600, 9600)));
+        assert!(render_pattern(&project, &options).is_err());
+    }
+
+    #[test]
+    fn test_jpeg_encoding_flattens_alpha() {
+        let project = test_project();
+        let options = ImageExportOptions {
+            pixels_per_stitch: 4,
+            format: RasterFormat::Jpeg,
+            mode: RenderMode::Stitched,
+            ..Default::default()
+        };
+        let img = render_pattern(&project, &options).unwrap();
+        let bytes = encode_image(img, &options).unwrap();
+        assert_eq!(&bytes[..2], &[0xFF, 0xD8]);
+    }
+
+    #[test]
+    fn test_svg_groups_stitches_by_color() {
+        let project = test_project();
+        let svg = render_svg(&project, &SvgExportOptions::default()).unwrap();
+
+        assert!(svg.contains(r##"data-color-id="red" data-name="Red" data-thread="DMC 666" fill="#ff0000""##));
+        assert_eq!(svg.matches("data-color-id=\"red\"").count(), 1);
+        assert_eq!(svg.matches("<circle").count(), 1);
+        assert_eq!(svg.matches("<polygon").count(), 1);
+        assert!(svg.contains("<title>Test &amp; Pattern</title>"));
+    }
+}
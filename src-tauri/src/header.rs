@@ -0,0 +1,366 @@
+// Project Header Module
+// Reads the summary fields of a project file without materializing its stitches
+
+use crate::thumbnail::{self, StableHasher};
+use crate::{CanvasConfig, NdpMetadata};
+use serde::de::{Deserializer, SeqAccess, Visitor};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::fs;
+use std::path::Path;
+
+/// Lightweight summary of a project file for the home screen
+#[derive(Debug, Clone, Serialize)]
+pub struct ProjectHeader {
+    pub metadata: NdpMetadata,
+    pub canvas: CanvasConfig,
+    pub color_count: usize,
+    pub thread_brands: Vec<String>,
+    pub layer_count: usize,
+    pub stitch_count: usize,
+    pub completed_count: usize,
+    pub progress_percent: f64,
+    pub thumbnail: Option<String>,
+    #[serde(skip)]
+    pub thumbnail_fingerprint: Option<String>,
+    #[serde(skip)]
+    pub content_fingerprint: String, // Same value thumbnail::content_fingerprint gives for the full project
+}
+
+impl ProjectHeader {
+    /// The embedded thumbnail, if its fingerprint matches the stitches it was read with
+    pub fn trusted_thumbnail(&self) -> Option<&str> {
+        match (&self.thumbnail, &self.thumbnail_fingerprint) {
+            (Some(thumbnail), Some(fingerprint)) if *fingerprint == self.content_fingerprint => Some(thumbnail.as_str()),
+            _ => None,
+        }
+    }
+}
+
+/// Stitch totals and thumbnail digest of one layer
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+struct StitchTally {
+    stitches: usize,
+    completed: usize,
+    digest: u64,
+}
+
+/// Stitch totals gathered while skipping over the layer data
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+struct LayerSummary {
+    layers: usize,
+    stitches: usize,
+    completed: usize,
+    visible_digests: Vec<(String, u64)>,
+}
+
+/// Palette size, the thread brands it uses and its thumbnail digest
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct PaletteSummary {
+    colors: usize,
+    brands: Vec<String>,
+    digest: u64,
+}
+
+impl Default for PaletteSummary {
+    fn default() -> Self {
+        PaletteSummary { colors: 0, brands: Vec::new(), digest: StableHasher::new().finish() }
+    }
+}
+
+/// On-disk fields needed for the header; everything else is skipped unparsed
+#[derive(Deserialize)]
+struct RawHeader {
+    metadata: NdpMetadata,
+    canvas: CanvasConfig,
+    #[serde(default, deserialize_with = "summarize_palette")]
+    color_palette: PaletteSummary,
+    #[serde(default, deserialize_with = "tally_layers")]
+    layers: LayerSummary,
+    #[serde(default)]
+    thumbnail: Option<String>,
+    #[serde(default)]
+    thumbnail_fingerprint: Option<String>,
+}
+
+/// The palette fields the header needs
+#[derive(Deserialize)]
+struct PaletteBrand {
+    #[serde(default)]
+    id: String,
+    #[serde(default)]
+    rgb: [u8; 3],
+    #[serde(default)]
+    thread_brand: Option<String>,
+}
+
+/// The stitch fields the header needs
+#[derive(Deserialize)]
+struct StitchProgress {
+    #[serde(default)]
+    x: u32,
+    #[serde(default)]
+    y: u32,
+    #[serde(default)]
+    color_id: String,
+    #[serde(default)]
+    stitch_type: Option<String>,
+    #[serde(default)]
+    position: Option<String>,
+    #[serde(default)]
+    completed: bool,
+}
+
+/// A layer reduced to its stitch totals
+#[derive(Deserialize)]
+struct LayerProgress {
+    #[serde(default)]
+    id: String,
+    #[serde(default = "default_visible")]
+    visible: bool,
+    #[serde(default, deserialize_with = "tally_stitches")]
+    stitches: StitchTally,
+}
+
+fn default_visible() -> bool {
+    true
+}
+
+/// Count palette colors and collect the distinct thread brands in order of appearance
+fn summarize_palette<'de, D: Deserializer<'de>>(deserializer: D) -> Result<PaletteSummary, D::Error> {
+    struct PaletteVisitor;
+
+    impl<'de> Visitor<'de> for PaletteVisitor {
+        type Value = PaletteSummary;
+
+        fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            f.write_str("a sequence of colors")
+        }
+
+        fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<PaletteSummary, A::Error> {
+            let mut summary = PaletteSummary::default();
+            let mut hasher = StableHasher::new();
+            while let Some(color) = seq.next_element::<PaletteBrand>()? {
+                summary.colors += 1;
+                thumbnail::write_color(&mut hasher, &color.id, color.rgb);
+                if let Some(brand) = color.thread_brand.filter(|b| !b.is_empty()) {
+                    if !summary.brands.contains(&brand) {
+                        summary.brands.push(brand);
+                    }
+                }
+            }
+            summary.digest = hasher.finish();
+            Ok(summary)
+        }
+    }
+
+    deserializer.deserialize_seq(PaletteVisitor)
+}
+
+/// Count stitches and completed stitches in one layer, digesting them as they pass
+fn tally_stitches<'de, D: Deserializer<'de>>(deserializer: D) -> Result<StitchTally, D::Error> {
+    struct StitchVisitor;
+
+    impl<'de> Visitor<'de> for StitchVisitor {
+        type Value = StitchTally;
+
+        fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            f.write_str("a sequence of stitches")
+        }
+
+        fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<StitchTally, A::Error> {
+            let mut tally = StitchTally::default();
+            let mut hasher = StableHasher::new();
+            while let Some(stitch) = seq.next_element::<StitchProgress>()? {
+                tally.stitches += 1;
+                if stitch.completed {
+                    tally.completed += 1;
+                }
+                thumbnail::write_stitch(
+                    &mut hasher,
+                    stitch.x,
+                    stitch.y,
+                    &stitch.color_id,
+                    stitch.stitch_type.as_deref(),
+                    stitch.position.as_deref(),
+                );
+            }
+            hasher.write_u64(tally.stitches as u64);
+            tally.digest = hasher.finish();
+            Ok(tally)
+        }
+    }
+
+    deserializer.deserialize_seq(StitchVisitor)
+}
+
+/// Sum stitch totals across all layers and keep the digests of the visible ones
+fn tally_layers<'de, D: Deserializer<'de>>(deserializer: D) -> Result<LayerSummary, D::Error> {
+    struct LayerVisitor;
+
+    impl<'de> Visitor<'de> for LayerVisitor {
+        type Value = LayerSummary;
+
+        fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            f.write_str("a sequence of layers")
+        }
+
+        fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<LayerSummary, A::Error> {
+            let mut summary = LayerSummary::default();
+            while let Some(layer) = seq.next_element::<LayerProgress>()? {
+                summary.layers += 1;
+                summary.stitches += layer.stitches.stitches;
+                summary.completed += layer.stitches.completed;
+                if layer.visible {
+                    summary.visible_digests.push((layer.id, layer.stitches.digest));
+                }
+            }
+            Ok(summary)
+        }
+    }
+
+    deserializer.deserialize_seq(LayerVisitor)
+}
+
+impl<'de> Deserialize<'de> for ProjectHeader {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let raw = RawHeader::deserialize(deserializer)?;
+        let progress_percent = if raw.layers.stitches == 0 {
+            0.0
+        } else {
+            raw.layers.completed as f64 * 100.0 / raw.layers.stitches as f64
+        };
+        let content_fingerprint = thumbnail::combine_fingerprint(
+            raw.canvas.width,
+            raw.canvas.height,
+            raw.color_palette.digest,
+            raw.layers.visible_digests.iter().map(|(id, digest)| (id.as_str(), *digest)),
+        );
+
+        Ok(ProjectHeader {
+            metadata: raw.metadata,
+            canvas: raw.canvas,
+            color_count: raw.color_palette.colors,
+            thread_brands: raw.color_palette.brands,
+            layer_count: raw.layers.layers,
+            stitch_count: raw.layers.stitches,
+            completed_count: raw.layers.completed,
+            progress_percent,
+            thumbnail: raw.thumbnail,
+            thumbnail_fingerprint: raw.thumbnail_fingerprint,
+            content_fingerprint,
+        })
+    }
+}
+
+/// Parse a project header from a JSON project or package
+pub fn parse_header(contents: &[u8]) -> Result<ProjectHeader, String> {
+    if contents.is_empty() {
+        return Err("File is empty".to_string());
+    }
+
+    if crate::package::is_package(contents) {
+        return crate::package::read_package_header(contents);
+    }
+
+    parse_json_header(contents)
+}
+
+/// Parse the header of a single-file JSON project
+pub fn parse_json_header(contents: &[u8]) -> Result<ProjectHeader, String> {
+    serde_json::from_slice(contents)
+        .map_err(|e| format!("Failed to parse project header: {} (content length: {} bytes)", e, contents.len()))
+}
+
+/// Read only the header of a project file on disk
+pub fn read_header(path: &Path) -> Result<ProjectHeader, String> {
+    let contents = fs::read(path)
+        .map_err(|e| format!("Failed to read file: {} (path: {:?})", e, path))?;
+    parse_header(&contents)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PROJECT_JSON: &str = r#"{
+        "version": "1.0",
+        "metadata": {
+            "name": "Roses",
+            "author": "Ann",
+            "created_at": "2025-01-01T00:00:00Z",
+            "modified_at": "2025-01-02T00:00:00Z",
+            "software": "StitchALot Studio v1.0"
+        },
+        "canvas": { "width": 40, "height": 30, "mesh_count": 13, "physical_width": null, "physical_height": null },
+        "color_palette": [
+            { "id": "c1", "name": "Red", "rgb": [255, 0, 0], "thread_brand": null, "thread_code": null, "symbol": null },
+            { "id": "c2", "name": "Green", "rgb": [0, 255, 0], "thread_brand": "DMC", "thread_code": "702", "symbol": null },
+            { "id": "c3", "name": "Blue", "rgb": [0, 0, 255], "thread_brand": "DMC", "thread_code": "796", "symbol": null }
+        ],
+        "layers": [
+            { "id": "l1", "name": "Base", "visible": true, "locked": false, "stitches": [
+                { "x": 0, "y": 0, "color_id": "c1", "completed": true },
+                { "x": 1, "y": 0, "color_id": "c1", "completed": false, "stitch_type": "circle", "position": "center" }
+            ] },
+            { "id": "l2", "name": "Text", "visible": false, "locked": false, "stitches": [
+                { "x": 2, "y": 2, "color_id": "c2", "completed": true },
+                { "x": 3, "y": 2, "color_id": "c2", "completed": false }
+            ], "metadata": { "type": "text", "text": "Hi", "fontFamily": "Arial", "fontWeight": 400, "italic": false, "colorId": "c2", "boldness": 0 } }
+        ],
+        "overlays": [
+            { "id": "o1", "name": "Photo", "data_url": "data:image/png;base64,AAAA", "opacity": 50, "visible": true, "locked": false,
+              "x": 0, "y": 0, "width": 10, "height": 10, "natural_width": 10, "natural_height": 10 }
+        ],
+        "thumbnail": "data:image/png;base64,BBBB"
+    }"#;
+
+    #[test]
+    fn test_parse_header_counts() {
+        let header = parse_header(PROJECT_JSON.as_bytes()).unwrap();
+
+        assert_eq!(header.metadata.name, "Roses");
+        assert_eq!(header.canvas.width, 40);
+        assert_eq!(header.canvas.mesh_count, 13);
+        assert_eq!(header.color_count, 3);
+        assert_eq!(header.thread_brands, vec!["DMC".to_string()]);
+        assert_eq!(header.layer_count, 2);
+        assert_eq!(header.stitch_count, 4);
+        assert_eq!(header.completed_count, 2);
+        assert!((header.progress_percent - 50.0).abs() < f64::EPSILON);
+        assert_eq!(header.thumbnail.as_deref(), Some("data:image/png;base64,BBBB"));
+    }
+
+    #[test]
+    fn test_only_current_thumbnails_are_trusted() {
+        let header = parse_header(PROJECT_JSON.as_bytes()).unwrap();
+        assert!(header.trusted_thumbnail().is_none());
+
+        // The streamed fingerprint matches the one computed from the full project
+        let project: crate::NdpFile = serde_json::from_str(PROJECT_JSON).unwrap();
+        let fingerprint = thumbnail::content_fingerprint(&project);
+        assert_eq!(header.content_fingerprint, fingerprint);
+
+        let with_fingerprint = |fingerprint: &str| {
+            PROJECT_JSON.replace(r#""thumbnail": "#, &format!(r#""thumbnail_fingerprint": "{}", "thumbnail": "#, fingerprint))
+        };
+        let header = parse_header(with_fingerprint(&fingerprint).as_bytes()).unwrap();
+        assert_eq!(header.trusted_thumbnail(), Some("data:image/png;base64,BBBB"));
+
+        // A fingerprint left over from before the stitches changed is not trusted
+        let stale = parse_header(with_fingerprint("0123456789abcdef").as_bytes()).unwrap();
+        assert!(stale.trusted_thumbnail().is_none());
+        let edited = with_fingerprint(&fingerprint).replace(r#""x": 1, "y": 0"#, r#""x": 5, "y": 0"#);
+        assert!(parse_header(edited.as_bytes()).unwrap().trusted_thumbnail().is_none());
+
+        // Hidden layers don't show in the thumbnail
+        let hidden = with_fingerprint(&fingerprint).replace(r#""x": 3, "y": 2"#, r#""x": 7, "y": 2"#);
+        assert!(parse_header(hidden.as_bytes()).unwrap().trusted_thumbnail().is_some());
+    }
+
+    #[test]
+    fn test_parse_header_rejects_invalid_files() {
+        assert!(parse_header(b"").is_err());
+        assert!(parse_header(b"{\"version\": \"1.0\"}").is_err());
+    }
+}
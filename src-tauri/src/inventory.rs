@@ -0,0 +1,720 @@
+// Thread Inventory Module
+// Skeins on hand per brand and code, stash-aware matching and shopping lists
+
+use crate::paths::{self, PathAccess};
+use crate::recolor::stitch_counts;
+use crate::threads::color_matching::{delta_e2000, find_closest_color, ColorMatch, ColorMatchAlgorithm};
+use crate::threads::{self, custom::parse_csv_records, ThreadBrand};
+use crate::NdpFile;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::io::{Cursor, Read};
+use std::path::PathBuf;
+use std::sync::Mutex;
+use tauri::Manager;
+use zip::ZipArchive;
+
+/// Storage file for the inventory in app data
+const INVENTORY_FILE: &str = "thread-inventory.json";
+
+/// In-memory cache for the inventory
+static INVENTORY_CACHE: Mutex<Option<ThreadInventory>> = Mutex::new(None);
+
+/// Largest spreadsheet part read from an .xlsx file
+const MAX_XLSX_PART_BYTES: u64 = 64 * 1024 * 1024;
+
+/// How much worse (CIEDE2000) an owned thread may be and still win in "prefer" mode
+const STASH_PREFERENCE_DELTA_E: f64 = 3.0;
+
+/// Thread used by one stitch, in stitch widths (front, back and a share of the tails)
+const THREAD_PER_STITCH: f64 = 4.0;
+
+/// Extra allowance for tails, mistakes and unpicking
+const WASTE_FACTOR: f64 = 1.2;
+
+/// Skein size assumed for threads without one (standard stranded floss)
+const DEFAULT_SKEIN_LENGTH: f64 = 8.0;
+const DEFAULT_STRANDS: u8 = 6;
+
+/// Skeins of one thread on hand
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct InventoryItem {
+    pub brand: ThreadBrand,
+    pub code: String,
+    pub skeins: f64,
+}
+
+/// Every thread the user owns
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ThreadInventory {
+    #[serde(default)]
+    pub items: Vec<InventoryItem>,
+}
+
+impl ThreadInventory {
+    fn position(&self, brand: &ThreadBrand, code: &str) -> Option<usize> {
+        self.items
+            .iter()
+            .position(|item| &item.brand == brand && item.code.eq_ignore_ascii_case(code.trim()))
+    }
+
+    /// Skeins on hand (0 when the thread isn't owned)
+    pub fn skeins(&self, brand: &ThreadBrand, code: &str) -> f64 {
+        self.position(brand, code).map(|i| self.items[i].skeins).unwrap_or(0.0)
+    }
+
+    pub fn owns(&self, brand: &ThreadBrand, code: &str) -> bool {
+        self.skeins(brand, code) > 0.0
+    }
+
+    /// Set the count for a thread; zero or less removes it
+    pub fn set(&mut self, brand: &ThreadBrand, code: &str, skeins: f64) {
+        match (self.position(brand, code), skeins > 0.0) {
+            (Some(i), true) => self.items[i].skeins = skeins,
+            (Some(i), false) => {
+                self.items.remove(i);
+            }
+            (None, true) => {
+                self.items.push(InventoryItem { brand: brand.clone(), code: code.trim().to_string(), skeins });
+                self.items.sort_by(|a, b| {
+                    (a.brand.as_str(), natural_key(&a.code)).cmp(&(b.brand.as_str(), natural_key(&b.code)))
+                });
+            }
+            (None, false) => {}
+        }
+    }
+}
+
+/// Spreadsheet rows as (column, value) cells
+type SheetRows = Vec<Vec<(usize, String)>>;
+
+/// Sort key that puts "310" before "3021" and numbers before names
+fn natural_key(code: &str) -> (u64, String) {
+    let digits: String = code.chars().take_while(|c| c.is_ascii_digit()).collect();
+    (digits.parse().unwrap_or(u64::MAX), code.to_lowercase())
+}
+
+// ============================================================================
+// Storage
+// ============================================================================
+
+fn get_inventory_path(app: &tauri::AppHandle) -> Result<PathBuf, String> {
+    let app_dir = app
+        .path()
+        .app_data_dir()
+        .map_err(|e| format!("Failed to get app data directory: {}", e))?;
+
+    fs::create_dir_all(&app_dir)
+        .map_err(|e| format!("Failed to create app data directory: {}", e))?;
+
+    Ok(app_dir.join(INVENTORY_FILE))
+}
+
+/// Load the inventory from storage
+pub fn load_inventory(app: &tauri::AppHandle) -> Result<ThreadInventory, String> {
+    {
+        let cache = INVENTORY_CACHE.lock().unwrap();
+        if let Some(inventory) = cache.as_ref() {
+            return Ok(inventory.clone());
+        }
+    }
+
+    let path = get_inventory_path(app)?;
+    let inventory = if path.exists() {
+        let contents = fs::read_to_string(&path)
+            .map_err(|e| format!("Failed to read thread inventory: {}", e))?;
+        serde_json::from_str(&contents)
+            .map_err(|e| format!("Failed to parse thread inventory: {}", e))?
+    } else {
+        ThreadInventory::default()
+    };
+
+    {
+        let mut cache = INVENTORY_CACHE.lock().unwrap();
+        *cache = Some(inventory.clone());
+    }
+
+    Ok(inventory)
+}
+
+/// Save the inventory to storage
+pub fn save_inventory(app: &tauri::AppHandle, inventory: &ThreadInventory) -> Result<(), String> {
+    let path = get_inventory_path(app)?;
+
+    let json = serde_json::to_string_pretty(inventory)
+        .map_err(|e| format!("Failed to serialize thread inventory: {}", e))?;
+
+    fs::write(&path, json)
+        .map_err(|e| format!("Failed to write thread inventory: {}", e))?;
+
+    {
+        let mut cache = INVENTORY_CACHE.lock().unwrap();
+        *cache = Some(inventory.clone());
+    }
+
+    Ok(())
+}
+
+// ============================================================================
+// Import
+// ============================================================================
+
+/// Outcome of an inventory import
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InventoryImport {
+    pub imported: usize,
+    /// Codes with a count that aren't in the brand's library
+    pub unknown_codes: Vec<String>,
+    pub inventory: ThreadInventory,
+}
+
+/// Skein count from an "owned" cell: a number, or a mark such as "x" or "yes" for one skein
+fn parse_owned(value: &str) -> Option<f64> {
+    let value = value.trim();
+    if let Ok(count) = value.parse::<f64>() {
+        return (count.is_finite() && count > 0.0).then_some(count);
+    }
+    matches!(value.to_lowercase().as_str(), "x" | "y" | "yes" | "true" | "✓" | "✔").then_some(1.0)
+}
+
+/// Strip spreadsheet markers ("E146*", "White^") and resolve aliases to a library code
+fn resolve_code(brand: &ThreadBrand, raw: &str) -> Result<String, String> {
+    let code = raw.trim().trim_end_matches(['*', '^']).trim();
+    let code = match (brand, code.to_lowercase().as_str()) {
+        (ThreadBrand::DMC, "blanc") => "White",
+        _ => code,
+    };
+    threads::find_thread(brand, code).map(|t| t.code).ok_or_else(|| code.to_string())
+}
+
+/// Rows of (code, skeins) pairs, collected from every "code"/"owned" column pair in a sheet
+fn owned_pairs(rows: &SheetRows) -> Vec<(String, f64)> {
+    const CODE_HEADERS: [&str; 4] = ["number", "code", "dmc", "floss"];
+    const OWNED_HEADERS: [&str; 5] = ["owned", "skeins", "quantity", "qty", "count"];
+    let is_header = |value: &str, names: &[&str]| names.contains(&value.trim().to_lowercase().as_str());
+
+    let mut pairs = Vec::new();
+    // (code column, owned column) for the most recent header row
+    let mut columns: Vec<(usize, usize)> = Vec::new();
+    for row in rows {
+        let header_cells: Vec<(usize, bool)> = row
+            .iter()
+            .filter_map(|(col, value)| {
+                if is_header(value, &CODE_HEADERS) {
+                    Some((*col, true))
+                } else if is_header(value, &OWNED_HEADERS) {
+                    Some((*col, false))
+                } else {
+                    None
+                }
+            })
+            .collect();
+
+        // A header row starts a new set of columns; each code column pairs with the next owned column
+        let found: Vec<(usize, usize)> = header_cells
+            .iter()
+            .filter(|(_, is_code)| *is_code)
+            .filter_map(|(code_col, _)| {
+                header_cells
+                    .iter()
+                    .filter(|(col, is_code)| !is_code && col > code_col)
+                    .map(|(col, _)| *col)
+                    .min()
+                    .filter(|owned_col| {
+                        !header_cells.iter().any(|(col, is_code)| *is_code && col > code_col && col < owned_col)
+                    })
+                    .map(|owned_col| (*code_col, owned_col))
+            })
+            .collect();
+        if !found.is_empty() {
+            // Later header rows only add columns (side tables start below the main header)
+            for pair in found {
+                columns.retain(|(code_col, _)| *code_col != pair.0);
+                columns.push(pair);
+            }
+            continue;
+        }
+
+        let cell = |col: usize| row.iter().find(|(c, _)| *c == col).map(|(_, v)| v.as_str());
+        for (code_col, owned_col) in &columns {
+            if let (Some(code), Some(skeins)) = (cell(*code_col), cell(*owned_col).and_then(parse_owned)) {
+                if !code.trim().is_empty() {
+                    pairs.push((code.to_string(), skeins));
+                }
+            }
+        }
+    }
+    pairs
+}
+
+/// Apply imported (code, skeins) pairs to an inventory
+fn apply_import(inventory: &mut ThreadInventory, brand: &ThreadBrand, pairs: Vec<(String, f64)>, replace: bool) -> (usize, Vec<String>) {
+    if replace {
+        inventory.items.retain(|item| &item.brand != brand);
+    }
+
+    let mut imported = 0;
+    let mut unknown_codes = Vec::new();
+    for (raw, skeins) in pairs {
+        match resolve_code(brand, &raw) {
+            Ok(code) => {
+                inventory.set(brand, &code, skeins);
+                imported += 1;
+            }
+            Err(code) => {
+                if !unknown_codes.contains(&code) {
+                    unknown_codes.push(code);
+                }
+            }
+        }
+    }
+    (imported, unknown_codes)
+}
+
+// ============================================================================
+// XLSX reading
+// ============================================================================
+
+fn xml_unescape(text: &str) -> String {
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&apos;", "'")
+        .replace("&amp;", "&")
+}
+
+/// Value of an attribute in an opening tag
+fn xml_attr(tag: &str, name: &str) -> Option<String> {
+    let needle = format!(" {}=\"", name);
+    let start = tag.find(&needle)? + needle.len();
+    let end = tag[start..].find('"')? + start;
+    Some(xml_unescape(&tag[start..end]))
+}
+
+/// Every `<name ...>inner</name>` (or self-closing `<name .../>`) as (opening tag, inner)
+fn xml_elements<'a>(xml: &'a str, name: &str) -> Vec<(&'a str, &'a str)> {
+    let open = format!("<{}", name);
+    let close = format!("</{}>", name);
+    let mut elements = Vec::new();
+    let mut rest = xml;
+
+    while let Some(start) = rest.find(&open) {
+        let after = &rest[start + open.len()..];
+        // Skip longer names that share the prefix (<col> when looking for <c>)
+        if !after.starts_with([' ', '>', '/']) {
+            rest = after;
+            continue;
+        }
+        let Some(tag_end) = after.find('>') else { break };
+        let tag = &rest[start..start + open.len() + tag_end + 1];
+        let body = &after[tag_end + 1..];
+
+        if tag.ends_with("/>") {
+            elements.push((tag, ""));
+            rest = body;
+        } else if let Some(end) = body.find(&close) {
+            elements.push((tag, &body[..end]));
+            rest = &body[end + close.len()..];
+        } else {
+            break;
+        }
+    }
+    elements
+}
+
+/// Text of all `<t>` runs inside a string item
+fn xml_text(inner: &str) -> String {
+    xml_elements(inner, "t").iter().map(|(_, text)| xml_unescape(text)).collect()
+}
+
+/// Zero-based column from a cell reference ("AB12" -> 27)
+fn column_index(reference: &str) -> usize {
+    reference
+        .chars()
+        .take_while(|c| c.is_ascii_alphabetic())
+        .fold(0, |acc, c| acc * 26 + (c.to_ascii_uppercase() as usize - 'A' as usize + 1))
+        .saturating_sub(1)
+}
+
+fn read_xlsx_part(archive: &mut ZipArchive<Cursor<&[u8]>>, name: &str) -> Result<Option<String>, String> {
+    let entry = match archive.by_name(name) {
+        Ok(entry) => entry,
+        Err(_) => return Ok(None),
+    };
+    let mut text = String::new();
+    entry
+        .take(MAX_XLSX_PART_BYTES)
+        .read_to_string(&mut text)
+        .map_err(|e| format!("Failed to read spreadsheet part {}: {}", name, e))?;
+    Ok(Some(text))
+}
+
+/// Rows of (column, value) for every sheet, in workbook order
+fn read_xlsx_sheets(bytes: &[u8]) -> Result<Vec<SheetRows>, String> {
+    let mut archive = ZipArchive::new(Cursor::new(bytes)).map_err(|e| format!("Not a valid .xlsx file: {}", e))?;
+
+    let workbook = read_xlsx_part(&mut archive, "xl/workbook.xml")?.ok_or("Spreadsheet has no workbook")?;
+    let rels = read_xlsx_part(&mut archive, "xl/_rels/workbook.xml.rels")?.unwrap_or_default();
+    let shared: Vec<String> = read_xlsx_part(&mut archive, "xl/sharedStrings.xml")?
+        .map(|xml| xml_elements(&xml, "si").iter().map(|(_, inner)| xml_text(inner)).collect())
+        .unwrap_or_default();
+
+    let mut sheets = Vec::new();
+    for (tag, _) in xml_elements(&workbook, "sheet") {
+        let Some(rel_id) = xml_attr(tag, "r:id") else { continue };
+        let Some(target) = xml_elements(&rels, "Relationship")
+            .iter()
+            .find(|(rel, _)| xml_attr(rel, "Id").as_deref() == Some(rel_id.as_str()))
+            .and_then(|(rel, _)| xml_attr(rel, "Target"))
+        else {
+            continue;
+        };
+        let part = match target.strip_prefix('/') {
+            Some(absolute) => absolute.to_string(),
+            None => format!("xl/{}", target),
+        };
+        let Some(xml) = read_xlsx_part(&mut archive, &part)? else { continue };
+
+        let rows = xml_elements(&xml, "row")
+            .iter()
+            .map(|(_, row)| {
+                xml_elements(row, "c")
+                    .iter()
+                    .filter_map(|(cell, inner)| {
+                        let col = column_index(&xml_attr(cell, "r")?);
+                        let value = xml_elements(inner, "v").first().map(|(_, v)| xml_unescape(v));
+                        let value = match xml_attr(cell, "t").as_deref() {
+                            Some("s") => shared.get(value?.trim().parse::<usize>().ok()?)?.clone(),
+                            Some("inlineStr") => xml_text(inner),
+                            _ => value?,
+                        };
+                        Some((col, value))
+                    })
+                    .collect()
+            })
+            .collect();
+        sheets.push(rows);
+    }
+    Ok(sheets)
+}
+
+/// (code, skeins) pairs from the first sheet with code and owned columns
+fn parse_xlsx_inventory(bytes: &[u8]) -> Result<Vec<(String, f64)>, String> {
+    let sheets = read_xlsx_sheets(bytes)?;
+    sheets
+        .iter()
+        .map(owned_pairs)
+        .find(|pairs| !pairs.is_empty())
+        .ok_or_else(|| "No owned threads found; fill in the Owned column and try again".to_string())
+}
+
+/// (code, skeins) pairs from a CSV with code and owned columns
+fn parse_csv_inventory(text: &str) -> Vec<(String, f64)> {
+    let rows: SheetRows = parse_csv_records(text)
+        .into_iter()
+        .map(|record| record.into_iter().enumerate().collect())
+        .collect();
+    owned_pairs(&rows)
+}
+
+// ============================================================================
+// Stash-aware matching
+// ============================================================================
+
+/// How image conversion uses the inventory
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum StashMode {
+    #[default]
+    Off,
+    /// Use an owned thread when it is nearly as close as the best match
+    Prefer,
+    /// Only match owned threads
+    Only,
+}
+
+/// Closest palette entry, favoring `owned` when it is within STASH_PREFERENCE_DELTA_E of the best
+pub fn closest_preferring_owned(
+    target: [u8; 3],
+    all: &[(String, [u8; 3], String)],
+    owned: &[(String, [u8; 3], String)],
+    algorithm: ColorMatchAlgorithm,
+) -> Option<ColorMatch> {
+    let best = find_closest_color(target, all, algorithm)?;
+    match find_closest_color(target, owned, algorithm) {
+        Some(own) if delta_e2000(target, own.color) <= delta_e2000(target, best.color) + STASH_PREFERENCE_DELTA_E => Some(own),
+        _ => Some(best),
+    }
+}
+
+// ============================================================================
+// Shopping list
+// ============================================================================
+
+/// Options for estimating thread use
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
+pub struct ShoppingOptions {
+    /// Strands stitched together (defaults to the whole thread as sold)
+    #[serde(default)]
+    pub strands_used: Option<u8>,
+}
+
+/// Thread needed for one thread in the pattern
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ShoppingItem {
+    pub brand: Option<String>,
+    pub code: Option<String>,
+    pub name: String,
+    pub rgb: [u8; 3],
+    pub color_ids: Vec<String>,
+    pub stitches: usize,
+    pub meters_needed: f64,
+    pub skeins_needed: f64,
+    pub skeins_owned: f64,
+    pub skeins_to_buy: u32,
+}
+
+/// Thread needed for a whole pattern
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ShoppingList {
+    pub items: Vec<ShoppingItem>,
+    pub skeins_to_buy: u32,
+}
+
+/// Estimate the thread a pattern needs and what isn't in the inventory
+pub fn shopping_list(project: &NdpFile, inventory: &ThreadInventory, options: ShoppingOptions) -> ShoppingList {
+    let counts = stitch_counts(project);
+    let stitch_width = 0.0254 / project.canvas.mesh_count.max(1) as f64; // Meters
+
+    let mut items: Vec<ShoppingItem> = Vec::new();
+    for color in &project.color_palette {
+        let stitches = counts.get(&color.id).copied().unwrap_or(0);
+        if stitches == 0 {
+            continue;
+        }
+
+        let brand = color.thread_brand.as_deref().filter(|b| !b.is_empty()).map(ThreadBrand::from_name);
+        let meters = stitches as f64 * THREAD_PER_STITCH * stitch_width * WASTE_FACTOR;
+
+        // A blended color splits the needle's strands between its two threads
+        let codes: Vec<Option<&str>> = match color.blend_thread_code.as_deref().filter(|c| !c.is_empty()) {
+            Some(blend) => vec![color.thread_code.as_deref(), Some(blend)],
+            None => vec![color.thread_code.as_deref()],
+        };
+        let share = 1.0 / codes.len() as f64;
+
+        for code in codes {
+            let code = code.filter(|c| !c.is_empty());
+            let thread = brand.as_ref().zip(code).and_then(|(b, c)| threads::find_thread(b, c));
+
+            // Colors sharing a thread are bought together
+            let existing = items.iter_mut().find(|item| {
+                thread.is_some()
+                    && item.brand.as_deref() == thread.as_ref().map(|t| t.brand.as_str())
+                    && item.code.as_deref().zip(code).is_some_and(|(a, b)| a.eq_ignore_ascii_case(b))
+            });
+            let item = match existing {
+                Some(item) => item,
+                None => {
+                    items.push(ShoppingItem {
+                        brand: thread.as_ref().map(|t| t.brand.to_string()).or(color.thread_brand.clone()),
+                        code: thread.as_ref().map(|t| t.code.clone()).or(code.map(str::to_string)),
+                        name: thread.as_ref().map(|t| t.name.clone()).unwrap_or_else(|| color.name.clone()),
+                        rgb: thread.as_ref().map(|t| t.rgb).unwrap_or(color.rgb),
+                        color_ids: Vec::new(),
+                        stitches: 0,
+                        meters_needed: 0.0,
+                        skeins_needed: 0.0,
+                        skeins_owned: match (&thread, &brand) {
+                            (Some(t), Some(b)) => inventory.skeins(b, &t.code),
+                            _ => 0.0,
+                        },
+                        skeins_to_buy: 0,
+                    });
+                    items.last_mut().unwrap()
+                }
+            };
+
+            let skein_length = thread.as_ref().and_then(|t| t.skein_length).unwrap_or(DEFAULT_SKEIN_LENGTH);
+            let strands = thread.as_ref().and_then(|t| t.strands).unwrap_or(DEFAULT_STRANDS);
+            let strands_used = options.strands_used.unwrap_or(strands).max(1);
+
+            item.color_ids.push(color.id.clone());
+            item.stitches += stitches;
+            item.meters_needed += meters * share;
+            item.skeins_needed += meters * share * strands_used as f64 / (skein_length * strands as f64);
+        }
+    }
+
+    for item in &mut items {
+        item.skeins_to_buy = (item.skeins_needed - item.skeins_owned).max(0.0).ceil() as u32;
+    }
+    let skeins_to_buy = items.iter().map(|i| i.skeins_to_buy).sum();
+
+    ShoppingList { items, skeins_to_buy }
+}
+
+/// Tauri commands for the thread inventory
+pub mod commands {
+    use super::*;
+
+    /// Get the thread inventory
+    #[tauri::command]
+    pub fn get_thread_inventory(app: tauri::AppHandle) -> Result<ThreadInventory, String> {
+        load_inventory(&app)
+    }
+
+    /// Set the skeins on hand for one thread (0 removes it)
+    #[tauri::command]
+    pub fn set_inventory_skeins(app: tauri::AppHandle, brand: ThreadBrand, code: String, skeins: f64) -> Result<ThreadInventory, String> {
+        if !skeins.is_finite() {
+            return Err("Skein count must be a number".to_string());
+        }
+        let code = threads::find_thread(&brand, &code)
+            .map(|t| t.code)
+            .ok_or_else(|| format!("Unknown {} thread: {}", brand, code))?;
+
+        let mut inventory = load_inventory(&app)?;
+        inventory.set(&brand, &code, skeins);
+        save_inventory(&app, &inventory)?;
+        Ok(inventory)
+    }
+
+    /// Import owned threads from an inventory spreadsheet (.xlsx) or CSV
+    /// With `replace`, the brand's existing entries are cleared first.
+    #[tauri::command]
+    pub fn import_thread_inventory(
+        app: tauri::AppHandle,
+        path: String,
+        brand: Option<ThreadBrand>,
+        replace: Option<bool>,
+    ) -> Result<InventoryImport, String> {
+        let resolved = paths::resolve_path(&app, &path, PathAccess::ReadInventory)?;
+        let bytes = resolved.read(&app)?;
+
+        let pairs = if bytes.starts_with(b"PK\x03\x04") {
+            parse_xlsx_inventory(&bytes)?
+        } else {
+            let text = String::from_utf8(bytes).map_err(|_| "Inventory CSV must be UTF-8 text".to_string())?;
+            parse_csv_inventory(&text)
+        };
+
+        let brand = brand.unwrap_or(ThreadBrand::DMC);
+        let mut inventory = load_inventory(&app)?;
+        let (imported, unknown_codes) = apply_import(&mut inventory, &brand, pairs, replace.unwrap_or(false));
+        save_inventory(&app, &inventory)?;
+
+        Ok(InventoryImport { imported, unknown_codes, inventory })
+    }
+
+    /// Estimate the thread a pattern needs and what to buy
+    #[tauri::command]
+    pub fn get_shopping_list(app: tauri::AppHandle, project: NdpFile, options: Option<ShoppingOptions>) -> Result<ShoppingList, String> {
+        let inventory = load_inventory(&app)?;
+        Ok(shopping_list(&project, &inventory, options.unwrap_or_default()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{fixtures, Stitch};
+    use std::io::Write;
+    use zip::write::SimpleFileOptions;
+    use zip::ZipWriter;
+
+    fn workbook() -> Vec<u8> {
+        let parts = [
+            ("xl/workbook.xml", r#"<workbook><sheets><sheet name="Notes" sheetId="1" r:id="rId1"/><sheet name="By Number" sheetId="2" r:id="rId2"/></sheets></workbook>"#),
+            ("xl/_rels/workbook.xml.rels", r#"<Relationships><Relationship Id="rId1" Target="worksheets/sheet1.xml"/><Relationship Id="rId2" Target="/xl/worksheets/sheet2.xml"/></Relationships>"#),
+            ("xl/sharedStrings.xml", r#"<sst><si><t>Number</t></si><si><t>Name</t></si><si><t>Owned</t></si><si><t>Blanc^</t></si><si><r><t>E1</t></r><r><t>68*</t></r></si><si><t>Black &amp; Bold</t></si></sst>"#),
+            ("xl/worksheets/sheet1.xml", r#"<worksheet><sheetData><row r="1"><c r="A1" t="inlineStr"><is><t>Nothing here</t></is></c></row></sheetData></worksheet>"#),
+            (
+                "xl/worksheets/sheet2.xml",
+                concat!(
+                    r#"<worksheet><cols><col min="1" max="2"/></cols><sheetData>"#,
+                    r#"<row r="1"><c r="B1" t="s"><v>0</v></c><c r="C1" t="s"><v>1</v></c><c r="D1" t="s"><v>2</v></c><c r="F1" t="s"><v>0</v></c><c r="G1" t="s"><v>2</v></c></row>"#,
+                    r#"<row r="2"><c r="B2" t="s"><v>3</v></c><c r="D2"><v>2</v></c><c r="F2"><v>310</v></c><c r="G2" t="inlineStr"><is><t>x</t></is></c></row>"#,
+                    r#"<row r="3"><c r="B3"><v>3865</v></c><c r="D3" s="4"/><c r="F3" t="s"><v>4</v></c><c r="G3"><v>1.5</v></c></row>"#,
+                    r#"<row r="4"><c r="B4" t="s"><v>5</v></c><c r="D4"><v>1</v></c></row>"#,
+                    r#"</sheetData></worksheet>"#
+                ),
+            ),
+        ];
+
+        let mut writer = ZipWriter::new(Cursor::new(Vec::new()));
+        for (name, xml) in parts {
+            writer.start_file(name, SimpleFileOptions::default()).unwrap();
+            writer.write_all(xml.as_bytes()).unwrap();
+        }
+        writer.finish().unwrap().into_inner()
+    }
+
+    #[test]
+    fn test_import_xlsx_inventory() {
+        let pairs = parse_xlsx_inventory(&workbook()).unwrap();
+        assert_eq!(
+            pairs,
+            vec![("Blanc^".to_string(), 2.0), ("310".to_string(), 1.0), ("E168*".to_string(), 1.5), ("Black & Bold".to_string(), 1.0)]
+        );
+        assert_eq!(column_index("AB12"), 27);
+
+        let mut inventory = ThreadInventory::default();
+        inventory.set(&ThreadBrand::DMC, "3865", 4.0);
+        let (imported, unknown) = apply_import(&mut inventory, &ThreadBrand::DMC, pairs, true);
+        assert_eq!(imported, 3);
+        assert_eq!(unknown, vec!["Black & Bold".to_string()]);
+        assert_eq!(inventory.skeins(&ThreadBrand::DMC, "white"), 2.0);
+        assert_eq!(inventory.skeins(&ThreadBrand::DMC, "E168"), 1.5);
+        assert!(!inventory.owns(&ThreadBrand::DMC, "3865"));
+
+        let csv = "Code,Name,Skeins\n310,Black,3\n321,Red,\n";
+        assert_eq!(parse_csv_inventory(csv), vec![("310".to_string(), 3.0)]);
+    }
+
+    #[test]
+    fn test_shopping_list_and_stash_preference() {
+        let mut inventory = ThreadInventory::default();
+        inventory.set(&ThreadBrand::DMC, "310", 1.0);
+
+        let color = |id: &str, code: &str| fixtures::thread_color(id, "DMC", code, [0, 0, 0]);
+        let stitches = |id: &str, n: u32| -> Vec<Stitch> { (0..n).map(|x| fixtures::stitch(x, 0, id)).collect() };
+        let mut project = fixtures::project(
+            1000,
+            1,
+            vec![color("a", "310"), color("b", "310"), color("c", "321")],
+            [stitches("a", 900), stitches("b", 900), stitches("c", 100)].concat(),
+        );
+        project.canvas.mesh_count = 18;
+
+        let list = shopping_list(&project, &inventory, ShoppingOptions::default());
+        assert_eq!(list.items.len(), 2);
+        let black = &list.items[0];
+        assert_eq!(black.color_ids, vec!["a".to_string(), "b".to_string()]);
+        assert_eq!(black.stitches, 1800);
+        assert_eq!(black.skeins_owned, 1.0);
+        assert!(black.skeins_needed > 1.0 && black.skeins_needed < 2.0);
+        assert_eq!(black.skeins_to_buy, 1);
+        assert_eq!(list.items[1].skeins_to_buy, 1);
+        assert_eq!(list.skeins_to_buy, 2);
+
+        // Halving the strands halves the skeins
+        let fewer = shopping_list(&project, &inventory, ShoppingOptions { strands_used: Some(3) });
+        assert!((fewer.items[0].skeins_needed * 2.0 - black.skeins_needed).abs() < 1e-9);
+
+        // A blended color uses half as much of each thread
+        let mut blended = project.clone();
+        blended.color_palette[1].blend_thread_code = Some("321".to_string());
+        let list = shopping_list(&blended, &inventory, ShoppingOptions::default());
+        assert_eq!(list.items[0].color_ids, vec!["a".to_string(), "b".to_string()]);
+        assert!((list.items[0].meters_needed * 2.0 - black.meters_needed * 1.5).abs() < 1e-9);
+        assert_eq!(list.items[1].color_ids, vec!["b".to_string(), "c".to_string()]);
+
+        let all = vec![("black".to_string(), [0, 0, 0], "Black".to_string()), ("grey".to_string(), [40, 40, 40], "Grey".to_string())];
+        let owned = vec![all[1].clone()];
+        let near_black = closest_preferring_owned([5, 5, 5], &all, &owned, ColorMatchAlgorithm::Ciede2000).unwrap();
+        assert_eq!(near_black.color_id, "black");
+        let between = closest_preferring_owned([20, 20, 20], &all, &owned, ColorMatchAlgorithm::Ciede2000).unwrap();
+        assert_eq!(between.color_id, "grey");
+    }
+}
@@ -7,7 +7,7 @@ use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::fs;
 use std::io::Cursor;
-use std::path::{Path, PathBuf};
+use std::path::Path;
 use tauri::Manager;
 #[cfg(target_os = "macos")]
 use tauri::menu::{MenuBuilder, MenuItemBuilder, SubmenuBuilder, PredefinedMenuItem};
@@ -16,6 +16,19 @@ use tauri::Emitter;
 
 mod threads;
 mod licensing;
+mod export;
+mod thumbnail;
+mod header;
+mod library;
+mod trash;
+mod paths;
+mod package;
+mod compose;
+mod transform;
+mod recolor;
+mod inventory;
+#[cfg(not(any(target_os = "ios", target_os = "android")))]
+mod watcher;
 use threads::color_matching::ColorMatchAlgorithm;
 
 // NDP File Format structures
@@ -38,6 +51,10 @@ pub struct NdpFile {
     pub progress_shading_opacity: Option<u32>,
     #[serde(default)]
     pub thumbnail: Option<String>, // Base64 PNG thumbnail for fast preview
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub thumbnail_fingerprint: Option<String>, // Content hash the thumbnail was rendered from
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub assets: Option<Vec<ProjectAsset>>, // Custom fonts and symbols used by the design
 }
 
 fn default_zoom() -> Option<f64> {
@@ -75,6 +92,8 @@ pub struct Color {
     pub rgb: [u8; 3],
     pub thread_brand: Option<String>,
     pub thread_code: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub blend_thread_code: Option<String>, // Second thread in the needle for blended colors
     pub symbol: Option<String>,
 }
 
@@ -135,6 +154,94 @@ pub struct OverlayImage {
     pub natural_height: u32,
 }
 
+/// A custom font or symbol image embedded in a project
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ProjectAsset {
+    pub id: String,
+    pub name: String,
+    pub kind: String, // "font" or "symbol"
+    pub data_url: String,
+}
+
+/// Project builders shared by the test modules, so schema changes touch one place
+#[cfg(test)]
+pub(crate) mod fixtures {
+    use super::*;
+
+    /// A palette color without a thread or symbol, named after its id
+    pub fn color(id: &str, rgb: [u8; 3]) -> Color {
+        Color {
+            id: id.to_string(),
+            name: id.to_string(),
+            rgb,
+            thread_brand: None,
+            thread_code: None,
+            blend_thread_code: None,
+            symbol: None,
+        }
+    }
+
+    /// A palette color matched to a thread
+    pub fn thread_color(id: &str, brand: &str, code: &str, rgb: [u8; 3]) -> Color {
+        Color {
+            thread_brand: Some(brand.to_string()),
+            thread_code: Some(code.to_string()),
+            ..color(id, rgb)
+        }
+    }
+
+    /// A plain square stitch
+    pub fn stitch(x: u32, y: u32, color_id: &str) -> Stitch {
+        Stitch {
+            x,
+            y,
+            color_id: color_id.to_string(),
+            completed: false,
+            stitch_type: None,
+            position: None,
+        }
+    }
+
+    /// A single-layer project on 13-count canvas
+    pub fn project(width: u32, height: u32, color_palette: Vec<Color>, stitches: Vec<Stitch>) -> NdpFile {
+        NdpFile {
+            version: "1.0".to_string(),
+            metadata: NdpMetadata {
+                file_id: None,
+                name: "Roses".to_string(),
+                author: None,
+                created_at: "2025-01-01T00:00:00Z".to_string(),
+                modified_at: "2025-01-01T00:00:00Z".to_string(),
+                software: "NeedlePoint Designer v1.0".to_string(),
+            },
+            canvas: CanvasConfig {
+                width,
+                height,
+                mesh_count: 13,
+                physical_width: None,
+                physical_height: None,
+            },
+            color_palette,
+            layers: vec![Layer {
+                id: "layer-1".to_string(),
+                name: "Base Layer".to_string(),
+                visible: true,
+                locked: false,
+                stitches,
+                metadata: None,
+            }],
+            overlays: None,
+            zoom: Some(1.0),
+            is_progress_mode: None,
+            progress_shading_color: None,
+            progress_shading_opacity: None,
+            thumbnail: None,
+            thumbnail_fingerprint: None,
+            assets: None,
+        }
+    }
+}
+
 // Image processing structures
 #[derive(Debug, Serialize, Deserialize)]
 pub struct ImageInfo {
@@ -207,15 +314,15 @@ fn create_new_project(
         progress_shading_color: Some([128, 128, 128]),
         progress_shading_opacity: Some(70),
         thumbnail: None,
+        thumbnail_fingerprint: None,
+        assets: None,
     })
 }
 
-/// Load an SVG file and render it to a DynamicImage
-fn load_svg(path: &str) -> Result<DynamicImage, String> {
-    let svg_data = fs::read(path).map_err(|e| format!("Failed to read SVG file: {}", e))?;
-
+/// Render SVG source to a DynamicImage
+fn render_svg_data(svg_data: &[u8]) -> Result<DynamicImage, String> {
     let options = resvg::usvg::Options::default();
-    let tree = resvg::usvg::Tree::from_data(&svg_data, &options)
+    let tree = resvg::usvg::Tree::from_data(svg_data, &options)
         .map_err(|e| format!("Failed to parse SVG: {}", e))?;
 
     let size = tree.size();
@@ -267,13 +374,36 @@ fn is_svg_file(path: &str) -> bool {
         .unwrap_or(false)
 }
 
-#[tauri::command]
-fn load_image(path: String) -> Result<ImageInfo, String> {
-    let img = if is_svg_file(&path) {
-        load_svg(&path)?
-    } else {
-        image::open(&path).map_err(|e| format!("Failed to open image: {}", e))?
+/// Check if file contents look like SVG (for content URIs, which have no extension)
+fn is_svg_data(data: &[u8]) -> bool {
+    let start = data.iter().position(|b| !b.is_ascii_whitespace()).unwrap_or(data.len());
+    let head = &data[start..data.len().min(start + 256)];
+    head.starts_with(b"<svg") || (head.starts_with(b"<?xml") && head.windows(4).any(|w| w == b"<svg"))
+}
+
+/// Decode an image file, rendering SVG by extension (or by content for content URIs)
+fn decode_image_file(source: &paths::ResolvedPath, data: &[u8]) -> Result<DynamicImage, String> {
+    let is_svg = match source.as_path() {
+        Some(file) => is_svg_file(&file.to_string_lossy()),
+        None => is_svg_data(data),
     };
+    if is_svg {
+        render_svg_data(data)
+    } else {
+        image::load_from_memory(data).map_err(|e| format!("Failed to open image: {}", e))
+    }
+}
+
+/// Resolve and decode an image file picked in the webview
+fn read_image_file(app: &tauri::AppHandle, path: &str) -> Result<DynamicImage, String> {
+    let source = paths::resolve_path(app, path, paths::PathAccess::ReadImage)?;
+    let data = source.read(app)?;
+    decode_image_file(&source, &data)
+}
+
+#[tauri::command]
+fn load_image(app: tauri::AppHandle, path: String) -> Result<ImageInfo, String> {
+    let img = read_image_file(&app, &path)?;
 
     let (width, height) = img.dimensions();
 
@@ -363,7 +493,15 @@ fn load_image_from_base64(data: String, filename: String) -> Result<ImageInfo, S
 }
 
 /// Load image from either path or base64 data URL
-fn load_image_from_path_or_data(path: &str) -> Result<DynamicImage, String> {
+fn load_image_from_path_or_data(app: &tauri::AppHandle, path: &str) -> Result<DynamicImage, String> {
+    load_image_source(path, |raw| read_image_file(app, raw))
+}
+
+/// Decode a base64 data URL, or hand any other path to `read_file`
+fn load_image_source(
+    path: &str,
+    read_file: impl FnOnce(&str) -> Result<DynamicImage, String>,
+) -> Result<DynamicImage, String> {
     // Check if this is a data URL (base64)
     if path.starts_with("data:") {
         let base64_data = path.split(',')
@@ -415,15 +553,15 @@ fn load_image_from_path_or_data(path: &str) -> Result<DynamicImage, String> {
             image::load_from_memory(&image_bytes)
                 .map_err(|e| format!("Failed to load image from bytes: {}", e))
         }
-    } else if is_svg_file(path) {
-        load_svg(path)
     } else {
-        image::open(path).map_err(|e| format!("Failed to open image: {}", e))
+        read_file(path)
     }
 }
 
 #[tauri::command]
+#[allow(clippy::too_many_arguments)]
 fn process_image(
+    app: tauri::AppHandle,
     path: String,
     target_width: u32,
     target_height: u32,
@@ -432,7 +570,7 @@ fn process_image(
     remove_background: bool,
     background_threshold: u8,
 ) -> Result<ProcessedImage, String> {
-    let img = load_image_from_path_or_data(&path)?;
+    let img = load_image_from_path_or_data(&app, &path)?;
 
     // Resize to target dimensions
     let resized = img.resize_exact(
@@ -468,6 +606,7 @@ fn process_image(
             rgb: [c[0], c[1], c[2]],
             thread_brand: None,
             thread_code: None,
+            blend_thread_code: None,
             symbol: None,
         })
         .collect();
@@ -536,6 +675,7 @@ pub struct ProcessedImageWithThreads {
 /// This does all processing in Rust including color quantization, dithering, and thread matching
 #[tauri::command]
 fn process_image_with_threads(
+    app: tauri::AppHandle,
     path: String,
     target_width: u32,
     target_height: u32,
@@ -545,9 +685,15 @@ fn process_image_with_threads(
     background_threshold: u8,
     thread_brand: String,
     color_match_algorithm: String,
+    exclude_discontinued: Option<bool>,
+    exclude_specialty: Option<bool>,
+    stash_mode: Option<inventory::StashMode>,
+    blend_threads: Option<bool>,
+    blend_margin: Option<f64>,
+    appearance: Option<threads::appearance::Appearance>,
 ) -> Result<ProcessedImageWithThreads, String> {
     // Load image (supports both file paths and base64 data URLs)
-    let img = load_image_from_path_or_data(&path)?;
+    let img = load_image_from_path_or_data(&app, &path)?;
 
     // Resize to target dimensions
     let resized = img.resize_exact(
@@ -573,12 +719,17 @@ fn process_image_with_threads(
     let dithered = apply_dithering(&quantized, &palette, &dither);
 
     // Get thread library for selected brand
-    let brand = match thread_brand.as_str() {
-        "Anchor" => threads::ThreadBrand::Anchor,
-        "Kreinik" => threads::ThreadBrand::Kreinik,
-        _ => threads::ThreadBrand::DMC,
-    };
-    let thread_colors = threads::get_threads_by_brand(brand);
+    // Unknown brands fall back to DMC; custom library names are accepted
+    let brand = thread_brand.parse().unwrap_or(threads::ThreadBrand::DMC);
+    let mut thread_colors = threads::get_threads_by_brand(&brand);
+
+    // Optionally leave out threads that can't be bought or don't stitch like plain floss
+    thread_colors.retain(|t| {
+        !(exclude_discontinued.unwrap_or(false) && t.discontinued) && !(exclude_specialty.unwrap_or(false) && t.is_specialty())
+    });
+    if thread_colors.is_empty() {
+        return Err(format!("No {} threads left to match after filtering", brand));
+    }
 
     // Parse algorithm
     let algorithm = match color_match_algorithm.as_str() {
@@ -586,23 +737,53 @@ fn process_image_with_threads(
         "weighted" => ColorMatchAlgorithm::Weighted,
         "cie76" => ColorMatchAlgorithm::Cie76,
         "cie94" => ColorMatchAlgorithm::Cie94,
+        "cie94-graphic-arts" => ColorMatchAlgorithm::Cie94GraphicArts,
+        "ciede2000-textiles" => ColorMatchAlgorithm::Ciede2000Textiles,
+        "cmc" => ColorMatchAlgorithm::Cmc,
+        "cmc-1:1" => ColorMatchAlgorithm::Cmc11,
         _ => ColorMatchAlgorithm::Ciede2000,
     };
 
-    // Build thread palette for matching
+    // Build thread palette for matching, with each thread as it looks under the chosen lighting
+    let appearance = appearance.unwrap_or_default();
     let thread_palette: Vec<(String, [u8; 3], String)> = thread_colors
         .iter()
         .map(|t| (
             format!("{}-{}", t.brand, t.code),
-            t.rgb,
+            appearance.thread_rgb(t),
             t.name.clone(),
         ))
         .collect();
 
+    // Threads in the user's stash, when matching should favor or be limited to them
+    let stash_mode = stash_mode.unwrap_or_default();
+    let owned_threads: Vec<threads::ThreadColor> = if stash_mode == inventory::StashMode::Off {
+        Vec::new()
+    } else {
+        let stash = inventory::load_inventory(&app)?;
+        thread_colors.iter().filter(|t| stash.owns(&t.brand, &t.code)).cloned().collect()
+    };
+    if stash_mode == inventory::StashMode::Only && owned_threads.is_empty() {
+        return Err(format!("No {} threads in your stash to match against", brand));
+    }
+    let owned_palette: Vec<(String, [u8; 3], String)> = owned_threads
+        .iter()
+        .map(|t| (format!("{}-{}", t.brand, t.code), appearance.thread_rgb(t), t.name.clone()))
+        .collect();
+
+    // Two-thread blends, used where one beats the best single thread by the margin
+    let blend_margin = blend_margin.unwrap_or(threads::blend::DEFAULT_BLEND_MARGIN).max(0.0);
+    let blends = match (blend_threads.unwrap_or(false), stash_mode) {
+        (false, _) => Vec::new(),
+        (true, inventory::StashMode::Off) => threads::blend::blend_candidates(&thread_colors, &appearance),
+        (true, _) => threads::blend::blend_candidates(&owned_threads, &appearance),
+    };
+
     // Match each quantized color to nearest thread
     let mut matched_colors: Vec<Color> = Vec::new();
     let mut color_id_map: HashMap<usize, String> = HashMap::new();
     let mut seen_thread_ids: HashMap<String, usize> = HashMap::new();
+    let mut preview_colors: HashMap<String, [u8; 3]> = HashMap::new(); // color_id -> color under the lighting
 
     for (i, quantized_color) in palette.iter().enumerate() {
         if is_transparent(quantized_color) {
@@ -611,27 +792,61 @@ fn process_image_with_threads(
 
         let rgb = [quantized_color[0], quantized_color[1], quantized_color[2]];
 
-        if let Some(match_result) = threads::color_matching::find_closest_color(rgb, &thread_palette, algorithm) {
+        let closest = match stash_mode {
+            inventory::StashMode::Off => threads::color_matching::find_closest_color(rgb, &thread_palette, algorithm),
+            inventory::StashMode::Prefer => inventory::closest_preferring_owned(rgb, &thread_palette, &owned_palette, algorithm),
+            inventory::StashMode::Only => threads::color_matching::find_closest_color(rgb, &owned_palette, algorithm),
+        };
+        let blend = closest
+            .as_ref()
+            .and_then(|single| threads::blend::better_blend(rgb, single.color, &blends, algorithm, blend_margin));
+
+        // (thread id, name, catalog rgb, rgb under the lighting, thread code, second thread code)
+        let matched = match (closest, blend) {
+            (_, Some(blend)) => Some((
+                blend.id(),
+                blend.name(),
+                blend.catalog_rgb(),
+                blend.rgb,
+                Some(blend.first.code.clone()),
+                Some(blend.second.code.clone()),
+            )),
+            (Some(match_result), None) => {
+                let thread_info = thread_colors.iter()
+                    .find(|t| format!("{}-{}", t.brand, t.code) == match_result.color_id);
+                Some((
+                    match_result.color_id,
+                    match_result.name,
+                    thread_info.map(|t| t.rgb).unwrap_or(match_result.color),
+                    match_result.color,
+                    thread_info.map(|t| t.code.clone()),
+                    None,
+                ))
+            }
+            (None, None) => None,
+        };
+
+        if let Some((thread_id, name, thread_rgb, seen_rgb, thread_code, blend_thread_code)) = matched {
             // Check if we already have this thread color
-            if let Some(&existing_idx) = seen_thread_ids.get(&match_result.color_id) {
+            if let Some(&existing_idx) = seen_thread_ids.get(&thread_id) {
                 // Reuse existing color
                 color_id_map.insert(i, matched_colors[existing_idx].id.clone());
             } else {
                 // Add new thread color
-                let color_id = format!("{}-color-{}", match_result.color_id, i + 1);
-                let thread_info = thread_colors.iter()
-                    .find(|t| format!("{}-{}", t.brand, t.code) == match_result.color_id);
+                let color_id = format!("{}-color-{}", thread_id, i + 1);
 
                 let color = Color {
                     id: color_id.clone(),
-                    name: match_result.name,
-                    rgb: match_result.color,
+                    name,
+                    rgb: thread_rgb,
                     thread_brand: Some(brand.to_string()),
-                    thread_code: thread_info.map(|t| t.code.clone()),
+                    thread_code,
+                    blend_thread_code,
                     symbol: None,
                 };
 
-                seen_thread_ids.insert(match_result.color_id, matched_colors.len());
+                seen_thread_ids.insert(thread_id, matched_colors.len());
+                preview_colors.insert(color_id.clone(), seen_rgb);
                 color_id_map.insert(i, color_id);
                 matched_colors.push(color);
             }
@@ -679,7 +894,7 @@ fn process_image_with_threads(
         pixels.push(row);
     }
 
-    // Create preview with thread-matched colors
+    // Create preview with thread-matched colors as they look under the chosen lighting
     let mut preview_img = RgbaImage::new(target_width, target_height);
     for y in 0..target_height {
         for x in 0..target_width {
@@ -687,8 +902,8 @@ fn process_image_with_threads(
             if color_id.is_empty() {
                 preview_img.put_pixel(x, y, Rgba([0, 0, 0, 0]));
             } else {
-                if let Some(color) = matched_colors.iter().find(|c| &c.id == color_id) {
-                    preview_img.put_pixel(x, y, Rgba([color.rgb[0], color.rgb[1], color.rgb[2], 255]));
+                if let Some(rgb) = preview_colors.get(color_id) {
+                    preview_img.put_pixel(x, y, Rgba([rgb[0], rgb[1], rgb[2], 255]));
                 } else {
                     preview_img.put_pixel(x, y, Rgba([128, 128, 128, 255]));
                 }
@@ -728,29 +943,24 @@ fn find_closest_color_idx(pixel: &Rgba<u8>, palette: &[Rgba<u8>]) -> usize {
     closest_idx
 }
 
+/// Names (without extension) of the projects in the documents directory
+/// Deprecated: use list_ndp_file_headers, which returns the same files with their headers.
 #[tauri::command]
 fn list_ndp_files(app: tauri::AppHandle) -> Result<Vec<String>, String> {
-    // Get the app's document directory
+    Ok(list_ndp_file_headers(app)?
+        .into_iter()
+        .filter_map(|f| Path::new(&f.path).file_stem().map(|s| s.to_string_lossy().to_string()))
+        .collect())
+}
+
+/// Header summaries of the project files in the documents directory
+#[tauri::command]
+fn list_ndp_file_headers(app: tauri::AppHandle) -> Result<Vec<DirectoryFile>, String> {
     let doc_dir = app.path()
         .document_dir()
         .map_err(|e| format!("Failed to get documents directory: {}", e))?;
 
-    // List all .stitchalot files in the directory
-    let mut files = Vec::new();
-
-    if let Ok(entries) = fs::read_dir(&doc_dir) {
-        for entry in entries.flatten() {
-            if let Some(name) = entry.file_name().to_str() {
-                if name.to_lowercase().ends_with(".stitchalot") {
-                    // Return filename without extension
-                    let name_without_ext = name.trim_end_matches(".stitchalot").trim_end_matches(".STITCHALOT");
-                    files.push(name_without_ext.to_string());
-                }
-            }
-        }
-    }
-
-    Ok(files)
+    Ok(scan_project_files(&doc_dir))
 }
 
 /// File info returned by scan_directory
@@ -758,12 +968,14 @@ fn list_ndp_files(app: tauri::AppHandle) -> Result<Vec<String>, String> {
 struct DirectoryFile {
     path: String,
     modified: u64, // Unix timestamp in seconds
+    #[serde(skip_serializing_if = "Option::is_none")]
+    header: Option<header::ProjectHeader>, // None if the file couldn't be parsed
 }
 
 /// Scan a directory for .stitchalot files and return them sorted by modified date
 #[tauri::command]
-fn scan_directory(directory: String) -> Result<Vec<DirectoryFile>, String> {
-    let dir_path = Path::new(&directory);
+fn scan_directory(app: tauri::AppHandle, directory: String) -> Result<Vec<DirectoryFile>, String> {
+    let dir_path = paths::resolve_file(&app, &directory, paths::PathAccess::ScanDirectory)?;
 
     if !dir_path.exists() {
         return Err(format!("Directory does not exist: {}", directory));
@@ -773,26 +985,70 @@ fn scan_directory(directory: String) -> Result<Vec<DirectoryFile>, String> {
         return Err(format!("Path is not a directory: {}", directory));
     }
 
+    Ok(scan_project_files(&dir_path))
+}
+
+/// Event emitted to the frontend when background work fails without failing a command
+pub const BACKGROUND_ERROR_EVENT: &str = "background-error";
+
+/// Payload of BACKGROUND_ERROR_EVENT
+#[derive(Debug, Clone, Serialize)]
+pub struct BackgroundError {
+    pub context: String,
+    pub message: String,
+}
+
+/// Report a failure that shouldn't abort the operation that hit it
+/// Logged to stderr and sent to the frontend so it can be surfaced to the user.
+pub(crate) fn report_error(app: &tauri::AppHandle, context: &str, error: impl std::fmt::Display) {
+    use tauri::Emitter as _;
+
+    let payload = BackgroundError {
+        context: context.to_string(),
+        message: error.to_string(),
+    };
+    eprintln!("{}: {}", payload.context, payload.message);
+    let _ = app.emit(BACKGROUND_ERROR_EVENT, payload);
+}
+
+/// File extensions treated as project files
+const PROJECT_EXTENSIONS: [&str; 2] = ["stitchalot", "ndp"];
+
+/// Whether a path names a project file (by extension)
+fn is_project_path(path: &Path) -> bool {
+    path.extension()
+        .and_then(|ext| ext.to_str())
+        .map(|ext| PROJECT_EXTENSIONS.iter().any(|p| ext.eq_ignore_ascii_case(p)))
+        .unwrap_or(false)
+}
+
+/// List project files in a directory with their headers, newest first
+fn scan_project_files(dir_path: &Path) -> Vec<DirectoryFile> {
     let mut files = Vec::new();
 
     if let Ok(entries) = fs::read_dir(dir_path) {
         for entry in entries.flatten() {
-            if let Some(name) = entry.file_name().to_str() {
-                if name.to_lowercase().ends_with(".stitchalot") {
-                    let path = entry.path();
-                    let modified = entry
-                        .metadata()
-                        .ok()
-                        .and_then(|m| m.modified().ok())
-                        .and_then(|t| t.duration_since(std::time::UNIX_EPOCH).ok())
-                        .map(|d| d.as_secs())
-                        .unwrap_or(0);
-
-                    files.push(DirectoryFile {
-                        path: path.to_string_lossy().to_string(),
-                        modified,
-                    });
-                }
+            let path = entry.path();
+            if is_project_path(&path) {
+                let modified = entry
+                    .metadata()
+                    .ok()
+                    .and_then(|m| m.modified().ok())
+                    .and_then(|t| t.duration_since(std::time::UNIX_EPOCH).ok())
+                    .map(|d| d.as_secs())
+                    .unwrap_or(0);
+
+                // Thumbnails are fetched separately via get_thumbnails_batch
+                let header = header::read_header(&path).ok().map(|mut h| {
+                    h.thumbnail = None;
+                    h
+                });
+
+                files.push(DirectoryFile {
+                    path: path.to_string_lossy().to_string(),
+                    modified,
+                    header,
+                });
             }
         }
     }
@@ -800,267 +1056,123 @@ fn scan_directory(directory: String) -> Result<Vec<DirectoryFile>, String> {
     // Sort by modified date, newest first
     files.sort_by(|a, b| b.modified.cmp(&a.modified));
 
-    Ok(files)
+    files
 }
 
 #[tauri::command]
-fn save_project(app: tauri::AppHandle, path: String, project: NdpFile) -> Result<String, String> {
-    let json = serde_json::to_string_pretty(&project)
-        .map_err(|e| format!("Failed to serialize project: {}", e))?;
-
-    // Handle file:// URLs (iOS may pass these from the save dialog)
-    let path_without_scheme = if path.starts_with("file://") {
-        path.strip_prefix("file://").unwrap_or(&path).to_string()
-    } else {
-        path.clone()
-    };
-
-    // URL-decode the path (iOS may pass URL-encoded paths with %20 for spaces, etc.)
-    let decoded_path = urlencoding::decode(&path_without_scheme)
-        .map(|s| s.into_owned())
-        .unwrap_or(path_without_scheme.clone());
-
-    // On iOS, always save to Documents directory
-    // The frontend now passes just a filename on iOS (no dialog)
-    #[cfg(target_os = "ios")]
-    {
-        let doc_dir = app.path()
-            .document_dir()
-            .map_err(|e| format!("Failed to get documents directory: {}", e))?;
-        fs::create_dir_all(&doc_dir)
-            .map_err(|e| format!("Failed to create documents directory: {}", e))?;
-
-        // Extract just the filename from the path (in case a full path was passed)
-        let filename = std::path::Path::new(&decoded_path)
-            .file_name()
-            .map(|s| s.to_string_lossy().to_string())
-            .unwrap_or_else(|| format!("{}.stitchalot", project.metadata.name));
-        let save_path = doc_dir.join(&filename);
-
-        // Write to Documents directory
-        fs::write(&save_path, &json)
-            .map_err(|e| format!("Failed to write file: {} (path: {:?})", e, save_path))?;
-
-        // Verify the write succeeded
-        let written_size = fs::metadata(&save_path).map(|m| m.len()).unwrap_or(0);
-        if written_size == 0 {
-            return Err(format!(
-                "File was written but is empty! Expected {} bytes, got 0 (path: {:?})",
-                json.len(),
-                save_path
-            ));
-        }
-
-        // Cache the thumbnail for fast home page loading
-        let final_path = save_path.to_string_lossy().to_string();
-        if let Some(ref thumb) = project.thumbnail {
-            let _ = cache_thumbnail(&app, &final_path, thumb);
-        }
-
-        return Ok(final_path);
+fn save_project(
+    app: tauri::AppHandle,
+    path: String,
+    mut project: NdpFile,
+    format: Option<package::ProjectFormat>,
+) -> Result<String, String> {
+    // On iOS this always lands in the Documents directory
+    let save_path = paths::resolve_path(&app, &path, paths::PathAccess::WriteProject)?;
+
+    // Keep an existing file in the format it was written in unless told otherwise
+    let format = format
+        .or_else(|| save_path.as_path().and_then(package::detect_format))
+        .unwrap_or_default();
+
+    // Render the thumbnail from the project contents so it is never missing or stale
+    if let Err(e) = thumbnail::refresh_thumbnail(&mut project) {
+        report_error(&app, "Failed to render thumbnail", e);
     }
 
-    #[cfg(not(target_os = "ios"))]
-    {
-        let path_buf = std::path::PathBuf::from(&decoded_path);
+    let contents = package::encode_project(&project, format)?;
 
-        // Create parent directory if it doesn't exist
-        if let Some(parent) = path_buf.parent() {
-            fs::create_dir_all(parent)
-                .map_err(|e| format!("Failed to create directory: {} (path: {:?})", e, parent))?;
-        }
-
-        fs::write(&path_buf, &json)
-            .map_err(|e| format!("Failed to write file: {} (path: {:?}, content length: {} bytes)", e, path_buf, json.len()))?;
+    save_path.write(&app, &contents)?;
 
+    // Content URIs can't be inspected or cached by path
+    let final_path = save_path.to_string();
+    if let Some(path_buf) = save_path.as_path() {
         // Verify the write succeeded by checking file size
-        let written_size = fs::metadata(&path_buf)
+        let written_size = fs::metadata(path_buf)
             .map(|m| m.len())
             .unwrap_or(0);
 
         if written_size == 0 {
             return Err(format!(
                 "File was written but is empty! Expected {} bytes, got 0 (path: {:?})",
-                json.len(),
+                contents.len(),
                 path_buf
             ));
         }
 
         // Cache the thumbnail for fast home page loading
-        let final_path = path_buf.to_string_lossy().to_string();
         if let Some(ref thumb) = project.thumbnail {
-            let _ = cache_thumbnail(&app, &final_path, thumb);
+            let _ = thumbnail::cache_thumbnail(&app, path_buf, thumb);
         }
 
-        // Return the actual path where the file was saved
-        Ok(final_path)
+        // Keep the library index in step with what was written
+        if let Err(e) = library::record_saved_project(&app, &final_path, &project) {
+            report_error(&app, "Failed to update library index", e);
+        }
     }
+
+    // Return the actual path where the file was saved
+    Ok(final_path)
 }
 
 #[tauri::command]
 fn open_project(app: tauri::AppHandle, path: String) -> Result<NdpFile, String> {
-    // Handle file:// URLs (iOS often passes these from the document picker)
-    let path_without_scheme = if path.starts_with("file://") {
-        path.strip_prefix("file://").unwrap_or(&path).to_string()
-    } else {
-        path.clone()
-    };
-
-    // URL-decode the path (iOS may pass URL-encoded paths with %20 for spaces, etc.)
-    let decoded_path = urlencoding::decode(&path_without_scheme)
-        .map(|s| s.into_owned())
-        .unwrap_or(path_without_scheme.clone());
-
-    // On iOS, files are saved to Documents directory, so try there first
-    #[cfg(target_os = "ios")]
-    let read_path = {
-        // Extract filename from the provided path
-        let filename = std::path::Path::new(&decoded_path)
-            .file_name()
-            .map(|s| s.to_string_lossy().to_string());
-
-        // First try the Documents directory (where we save files)
-        if let Some(ref fname) = filename {
-            if let Ok(doc_dir) = app.path().document_dir() {
-                let doc_path = doc_dir.join(fname);
-                if doc_path.exists() {
-                    doc_path
-                } else {
-                    // Fall back to the original path
-                    std::path::PathBuf::from(&decoded_path)
-                }
-            } else {
-                std::path::PathBuf::from(&decoded_path)
-            }
-        } else {
-            std::path::PathBuf::from(&decoded_path)
-        }
-    };
-
-    #[cfg(not(target_os = "ios"))]
-    let read_path = {
-        let _ = &app; // Suppress unused warning
-        std::path::PathBuf::from(&decoded_path)
-    };
+    // On iOS, files saved to the Documents directory take precedence
+    let read_path = paths::resolve_path(&app, &path, paths::PathAccess::ReadProject)?;
 
-    let contents = fs::read_to_string(&read_path)
-        .map_err(|e| format!("Failed to read file: {} (path: {:?})", e, read_path))?;
+    let contents = read_path.read(&app)?;
 
     // Check if the file is empty
     if contents.is_empty() {
-        return Err(format!("File is empty (path: {:?}, original: {})", read_path, path));
+        return Err(format!("File is empty (path: {}, original: {})", read_path, path));
     }
 
-    let project: NdpFile = serde_json::from_str(&contents)
-        .map_err(|e| format!("Failed to parse project file: {} (content length: {} bytes)", e, contents.len()))?;
-
-    Ok(project)
+    // Single-file JSON and packages are both accepted
+    package::decode_project(&contents)
 }
 
 #[tauri::command]
-fn delete_file(path: String) -> Result<(), String> {
-    fs::remove_file(&path)
-        .map_err(|e| format!("Failed to delete file: {}", e))?;
-    Ok(())
+fn delete_file(app: tauri::AppHandle, path: String) -> Result<trash::TrashEntry, String> {
+    let file_path = paths::resolve_file(&app, &path, paths::PathAccess::DeleteProject)?;
+
+    // Projects go to the trash and can be restored until it is purged
+    let entry = trash::move_to_trash(&app, &file_path)?;
+    thumbnail::forget_thumbnail(&app, &file_path);
+    if let Err(e) = library::forget_project(&app, &file_path.to_string_lossy()) {
+        report_error(&app, "Failed to update project library", e);
+    }
+    Ok(entry)
 }
 
 // ============================================================================
 // Thumbnail Cache Functions
 // ============================================================================
 
-/// Get the thumbnail cache directory path
-fn get_thumbnail_cache_dir(app: &tauri::AppHandle) -> Result<PathBuf, String> {
-    let cache_dir = app.path()
-        .app_cache_dir()
-        .map_err(|e| format!("Failed to get cache directory: {}", e))?;
-    let thumb_cache = cache_dir.join("thumbnails");
-    Ok(thumb_cache)
-}
-
-/// Generate a cache key from a file path (simple hash)
-fn get_cache_key(file_path: &str) -> String {
-    use std::collections::hash_map::DefaultHasher;
-    use std::hash::{Hash, Hasher};
-
-    let mut hasher = DefaultHasher::new();
-    file_path.hash(&mut hasher);
-    format!("{:x}", hasher.finish())
-}
-
-/// Save a thumbnail to the cache
-fn cache_thumbnail(app: &tauri::AppHandle, file_path: &str, thumbnail_data: &str) -> Result<(), String> {
-    let cache_dir = get_thumbnail_cache_dir(app)?;
-    fs::create_dir_all(&cache_dir)
-        .map_err(|e| format!("Failed to create cache directory: {}", e))?;
-
-    let cache_key = get_cache_key(file_path);
-    let cache_file = cache_dir.join(format!("{}.txt", cache_key));
-
-    fs::write(&cache_file, thumbnail_data)
-        .map_err(|e| format!("Failed to write cache file: {}", e))?;
+/// Read the thumbnail of a project file
+/// Only the header is parsed unless the thumbnail is missing or may be stale
+fn read_project_thumbnail(read_path: &Path) -> Result<Option<String>, String> {
+    let contents = fs::read(read_path)
+        .map_err(|e| format!("Failed to read file: {}", e))?;
 
-    Ok(())
-}
+    if let Ok(header) = header::parse_header(&contents) {
+        if let Some(thumb) = header.trusted_thumbnail() {
+            return Ok(Some(thumb.to_string()));
+        }
+    }
 
-/// Load a thumbnail from the cache
-fn get_cached_thumbnail(app: &tauri::AppHandle, file_path: &str) -> Option<String> {
-    let cache_dir = get_thumbnail_cache_dir(app).ok()?;
-    let cache_key = get_cache_key(file_path);
-    let cache_file = cache_dir.join(format!("{}.txt", cache_key));
+    // Fall back to rendering from the full project contents
+    let project = package::decode_project(&contents)?;
 
-    fs::read_to_string(&cache_file).ok()
+    Ok(thumbnail::current_thumbnail(&project))
 }
 
 /// Load thumbnail from file and cache it
-fn load_and_cache_thumbnail(app: &tauri::AppHandle, path: &str) -> Option<String> {
-    // Handle file:// URLs
-    let path_without_scheme = if path.starts_with("file://") {
-        path.strip_prefix("file://").unwrap_or(path).to_string()
-    } else {
-        path.to_string()
-    };
-
-    // URL-decode the path
-    let decoded_path = urlencoding::decode(&path_without_scheme)
-        .map(|s| s.into_owned())
-        .unwrap_or(path_without_scheme.clone());
-
-    // Resolve path (platform-specific)
-    #[cfg(target_os = "ios")]
-    let read_path = {
-        let filename = std::path::Path::new(&decoded_path)
-            .file_name()
-            .map(|s| s.to_string_lossy().to_string());
-
-        if let Some(ref fname) = filename {
-            if let Ok(doc_dir) = app.path().document_dir() {
-                let doc_path = doc_dir.join(fname);
-                if doc_path.exists() {
-                    doc_path
-                } else {
-                    std::path::PathBuf::from(&decoded_path)
-                }
-            } else {
-                std::path::PathBuf::from(&decoded_path)
-            }
-        } else {
-            std::path::PathBuf::from(&decoded_path)
-        }
-    };
-
-    #[cfg(not(target_os = "ios"))]
-    let read_path = std::path::PathBuf::from(&decoded_path);
-
-    // Read and parse file
-    let contents = fs::read_to_string(&read_path).ok()?;
-    let project: NdpFile = serde_json::from_str(&contents).ok()?;
-
-    // Cache the thumbnail if present
-    if let Some(ref thumb) = project.thumbnail {
-        let _ = cache_thumbnail(app, path, thumb);
+fn load_and_cache_thumbnail(app: &tauri::AppHandle, read_path: &Path) -> Option<String> {
+    let thumb = read_project_thumbnail(read_path).ok()?;
+    if let Some(ref thumb) = thumb {
+        let _ = thumbnail::cache_thumbnail(app, read_path, thumb);
     }
 
-    project.thumbnail
+    thumb
 }
 
 /// Batch load thumbnails - tries cache first, falls back to file
@@ -1070,9 +1182,13 @@ fn get_thumbnails_batch(
     paths: Vec<String>
 ) -> HashMap<String, Option<String>> {
     paths.iter().map(|path| {
-        // Try cache first
-        let thumb = get_cached_thumbnail(&app, path)
-            .or_else(|| load_and_cache_thumbnail(&app, path));
+        // Try cache first (misses if the file changed since it was cached)
+        let thumb = paths::resolve_file(&app, path, paths::PathAccess::ReadProject)
+            .ok()
+            .and_then(|read_path| {
+                thumbnail::get_cached_thumbnail(&app, &read_path)
+                    .or_else(|| load_and_cache_thumbnail(&app, &read_path))
+            });
         (path.clone(), thumb)
     }).collect()
 }
@@ -1080,58 +1196,29 @@ fn get_thumbnails_batch(
 /// Get just the thumbnail from a file (fast preview loading)
 #[tauri::command]
 fn get_file_thumbnail(app: tauri::AppHandle, path: String) -> Result<Option<String>, String> {
-    // Handle file:// URLs
-    let path_without_scheme = if path.starts_with("file://") {
-        path.strip_prefix("file://").unwrap_or(&path).to_string()
-    } else {
-        path.clone()
-    };
-
-    // URL-decode the path
-    let decoded_path = urlencoding::decode(&path_without_scheme)
-        .map(|s| s.into_owned())
-        .unwrap_or(path_without_scheme.clone());
-
-    // On iOS, files are saved to Documents directory, so try there first
-    #[cfg(target_os = "ios")]
-    let read_path = {
-        let filename = std::path::Path::new(&decoded_path)
-            .file_name()
-            .map(|s| s.to_string_lossy().to_string());
-
-        if let Some(ref fname) = filename {
-            if let Ok(doc_dir) = app.path().document_dir() {
-                let doc_path = doc_dir.join(fname);
-                if doc_path.exists() {
-                    doc_path
-                } else {
-                    std::path::PathBuf::from(&decoded_path)
-                }
-            } else {
-                std::path::PathBuf::from(&decoded_path)
-            }
-        } else {
-            std::path::PathBuf::from(&decoded_path)
-        }
-    };
-
-    #[cfg(not(target_os = "ios"))]
-    let read_path = {
-        let _ = &app; // Suppress unused warning
-        std::path::PathBuf::from(&decoded_path)
-    };
-
-    let contents = fs::read_to_string(&read_path)
-        .map_err(|e| format!("Failed to read file: {}", e))?;
+    let read_path = paths::resolve_file(&app, &path, paths::PathAccess::ReadProject)?;
+    read_project_thumbnail(&read_path)
+}
 
-    let project: NdpFile = serde_json::from_str(&contents)
-        .map_err(|e| format!("Failed to parse file: {}", e))?;
+/// Read a project's header (metadata, canvas, palette size, progress, thumbnail)
+/// without loading its stitches
+#[tauri::command]
+fn get_project_header(app: tauri::AppHandle, path: String) -> Result<header::ProjectHeader, String> {
+    let read_path = paths::resolve_file(&app, &path, paths::PathAccess::ReadProject)?;
+    header::read_header(&read_path)
+}
 
-    Ok(project.thumbnail)
+/// Delete all cached thumbnails, returning the number of bytes freed
+#[tauri::command]
+fn clear_thumbnail_cache(app: tauri::AppHandle) -> Result<u64, String> {
+    thumbnail::clear_thumbnail_cache(&app)
 }
 
 #[tauri::command]
 fn save_pdf(app: tauri::AppHandle, path: String, data: String) -> Result<String, String> {
+    // On iOS/mobile, this is the app's documents directory
+    let save_path = paths::resolve_path(&app, &path, paths::PathAccess::WriteExport)?;
+
     // Decode base64 data
     let bytes = STANDARD.decode(&data)
         .map_err(|e| format!("Failed to decode PDF data: {}", e))?;
@@ -1141,49 +1228,57 @@ fn save_pdf(app: tauri::AppHandle, path: String, data: String) -> Result<String,
         return Err("PDF data is empty after base64 decode".to_string());
     }
 
-    // On iOS/mobile, save to the app's documents directory
-    #[cfg(any(target_os = "ios", target_os = "android"))]
-    let save_path = {
-        // Get the app's document directory
-        let doc_dir = app.path()
-            .document_dir()
-            .map_err(|e| format!("Failed to get documents directory: {}", e))?;
-
-        // Create the directory if it doesn't exist
-        fs::create_dir_all(&doc_dir)
-            .map_err(|e| format!("Failed to create documents directory: {}", e))?;
-
-        // Extract filename from path or use the provided path as filename
-        let filename = std::path::Path::new(&path)
-            .file_name()
-            .map(|s| s.to_string_lossy().to_string())
-            .unwrap_or_else(|| "pattern.pdf".to_string());
-
-        doc_dir.join(filename)
-    };
-
-    #[cfg(not(any(target_os = "ios", target_os = "android")))]
-    let save_path = {
-        let _ = &app; // Suppress unused warning on desktop
-        std::path::PathBuf::from(&path)
-    };
-
     // Write to file
-    fs::write(&save_path, &bytes)
-        .map_err(|e| format!("Failed to write PDF file: {} (path: {:?})", e, save_path))?;
+    save_path.write(&app, &bytes)?;
 
     // Verify the write succeeded
-    let written_size = fs::metadata(&save_path).map(|m| m.len()).unwrap_or(0);
-    if written_size == 0 {
-        return Err(format!(
-            "PDF file was written but is empty! Expected {} bytes, got 0 (path: {:?})",
-            bytes_len,
-            save_path
-        ));
+    if let Some(file) = save_path.as_path() {
+        let written_size = fs::metadata(file).map(|m| m.len()).unwrap_or(0);
+        if written_size == 0 {
+            return Err(format!(
+                "PDF file was written but is empty! Expected {} bytes, got 0 (path: {:?})",
+                bytes_len,
+                file
+            ));
+        }
     }
 
     // Return the actual path where the file was saved
-    Ok(save_path.to_string_lossy().to_string())
+    Ok(save_path.to_string())
+}
+
+/// Render the pattern to a PNG or JPEG image and write it to disk
+#[tauri::command]
+fn export_pattern_image(
+    app: tauri::AppHandle,
+    path: String,
+    project: NdpFile,
+    options: export::ImageExportOptions,
+) -> Result<String, String> {
+    let save_path = paths::resolve_path(&app, &path, paths::PathAccess::WriteExport)?;
+
+    let img = export::render_pattern(&project, &options)?;
+    let bytes = export::encode_image(img, &options)?;
+
+    save_path.write(&app, &bytes)?;
+
+    Ok(save_path.to_string())
+}
+
+/// Render the pattern as an SVG document and write it to disk
+#[tauri::command]
+fn export_pattern_svg(
+    app: tauri::AppHandle,
+    path: String,
+    project: NdpFile,
+    options: export::SvgExportOptions,
+) -> Result<String, String> {
+    let save_path = paths::resolve_path(&app, &path, paths::PathAccess::WriteExport)?;
+    let svg = export::render_svg(&project, &options)?;
+
+    save_path.write(&app, svg.as_bytes())?;
+
+    Ok(save_path.to_string())
 }
 
 #[tauri::command]
@@ -1641,6 +1736,47 @@ fn load_session_history(app: tauri::AppHandle) -> Result<Option<String>, String>
     Ok(Some(contents))
 }
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use paths::{PathAccess, PathScope, PathStrategy};
+
+    #[test]
+    fn test_image_imports_are_scoped() {
+        // process_image and process_image_with_threads both load through load_image_source
+        let dir = std::env::temp_dir().join(format!("stitchalot-image-import-test-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        for sub in ["pictures", "elsewhere"] {
+            fs::create_dir_all(dir.join(sub)).unwrap();
+            RgbaImage::new(2, 2).save(dir.join(sub).join("photo.png")).unwrap();
+        }
+
+        let scope = PathScope {
+            roots: vec![dir.join("pictures")],
+            files: Vec::new(),
+        };
+        let load = |rel: &str| {
+            let raw = format!("{}/{}", dir.to_string_lossy(), rel);
+            load_image_source(&raw, |raw| {
+                let (source, _) =
+                    paths::resolve_in_scope(&PathStrategy::Desktop, raw, PathAccess::ReadImage, &scope, &[], &|_| false)?;
+                let data = fs::read(source.as_path().unwrap()).map_err(|e| e.to_string())?;
+                decode_image_file(&source, &data)
+            })
+        };
+
+        assert_eq!(load("pictures/photo.png").unwrap().dimensions(), (2, 2));
+        assert!(load("elsewhere/photo.png").is_err());
+        assert!(load("pictures/../elsewhere/photo.png").is_err());
+
+        // Data URLs carry their own pixels and skip the resolver
+        let data_url = format!("data:image/png;base64,{}", STANDARD.encode(fs::read(dir.join("elsewhere/photo.png")).unwrap()));
+        assert!(load_image_source(&data_url, |_| Err("resolver called".to_string())).is_ok());
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+}
+
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
 pub fn run() {
     let mut builder = tauri::Builder::default()
@@ -1774,6 +1910,10 @@ pub fn run() {
                     let _ = window.set_decorations(false);
                 }
             }
+
+            if let Err(e) = threads::custom::load_libraries(app.handle()) {
+                report_error(app.handle(), "Failed to load custom thread libraries", e);
+            }
             Ok(())
         })
         .invoke_handler(tauri::generate_handler![
@@ -1784,13 +1924,18 @@ pub fn run() {
             process_image,
             process_image_with_threads,
             list_ndp_files,
+            list_ndp_file_headers,
             scan_directory,
             save_project,
             open_project,
             delete_file,
             get_file_thumbnail,
+            get_project_header,
             get_thumbnails_batch,
+            clear_thumbnail_cache,
             save_pdf,
+            export_pattern_image,
+            export_pattern_svg,
             #[cfg(not(any(target_os = "ios", target_os = "android")))]
             pick_screen_color,
             #[cfg(not(any(target_os = "ios", target_os = "android")))]
@@ -1806,8 +1951,60 @@ pub fn run() {
             licensing::commands::activate_license,
             licensing::commands::deactivate_device,
             #[cfg(debug_assertions)]
-            licensing::commands::reset_license_state
+            licensing::commands::reset_license_state,
+            // Project library commands
+            library::commands::search_projects,
+            library::commands::index_directories,
+            library::commands::set_project_tags,
+            library::commands::list_project_tags,
+            // Trash commands
+            trash::commands::list_trash,
+            trash::commands::restore_from_trash,
+            trash::commands::empty_trash,
+            trash::commands::get_trash_retention_days,
+            trash::commands::set_trash_retention_days,
+            // Project folder access
+            paths::commands::list_project_roots,
+            paths::commands::add_project_root,
+            paths::commands::remove_project_root,
+            // Merge and split patterns
+            compose::commands::merge_projects,
+            compose::commands::split_project,
+            // Project transforms
+            transform::commands::transform_project,
+            // Pattern color operations
+            recolor::commands::replace_color,
+            recolor::commands::merge_similar_colors,
+            recolor::commands::reduce_palette,
+            recolor::commands::rematch_palette,
+            // Thread conversion
+            threads::conversion::commands::convert_thread,
+            // Thread libraries
+            threads::search::commands::get_thread_libraries,
+            threads::search::commands::search_threads,
+            threads::search::commands::find_nearest_threads,
+            threads::search::commands::get_thread,
+            // Custom thread libraries
+            threads::custom::commands::validate_thread_library,
+            threads::custom::commands::import_thread_library,
+            threads::custom::commands::list_custom_thread_libraries,
+            threads::custom::commands::get_custom_thread_library,
+            threads::custom::commands::save_custom_thread_library,
+            threads::custom::commands::delete_custom_thread_library,
+            // Thread inventory
+            inventory::commands::get_thread_inventory,
+            inventory::commands::set_inventory_skeins,
+            inventory::commands::import_thread_inventory,
+            inventory::commands::get_shopping_list,
+            // Project folder watcher (desktop only)
+            #[cfg(not(any(target_os = "ios", target_os = "android")))]
+            watcher::commands::watch_project_directories,
+            #[cfg(not(any(target_os = "ios", target_os = "android")))]
+            watcher::commands::stop_watching_projects,
+            #[cfg(not(any(target_os = "ios", target_os = "android")))]
+            watcher::commands::get_watched_directories
         ])
         .run(tauri::generate_context!())
         .expect("error while running tauri application");
 }
+
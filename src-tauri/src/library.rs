@@ -0,0 +1,593 @@
+// Project Library Module
+// Persistent index of project files with search and filtering
+
+use crate::header::{self, ProjectHeader};
+use crate::paths::{self, PathAccess};
+use crate::NdpFile;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::time::{SystemTime, UNIX_EPOCH};
+use tauri::Manager;
+
+/// Storage file for the library index in app data
+const LIBRARY_INDEX_FILE: &str = "library-index.json";
+
+/// Current index format version
+const LIBRARY_INDEX_VERSION: u32 = 1;
+
+/// In-memory cache for the library index
+static LIBRARY_INDEX_CACHE: Mutex<Option<LibraryIndex>> = Mutex::new(None);
+
+/// One indexed project file
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct LibraryEntry {
+    pub path: String,
+    pub name: String,
+    pub author: Option<String>,
+    pub width: u32,
+    pub height: u32,
+    pub mesh_count: u32,
+    pub thread_brands: Vec<String>,
+    pub color_count: usize,
+    pub stitch_count: usize,
+    pub progress_percent: f64,
+    #[serde(default)]
+    pub tags: Vec<String>,
+    pub modified: u64,   // File modified time, Unix seconds
+    pub indexed_at: u64, // When this entry was written, Unix seconds
+}
+
+impl LibraryEntry {
+    /// Build an entry from a full project (used on save)
+    pub fn from_project(path: &str, project: &NdpFile, modified: u64) -> Self {
+        let mut thread_brands: Vec<String> = Vec::new();
+        for brand in project.color_palette.iter().filter_map(|c| c.thread_brand.as_ref()) {
+            if !brand.is_empty() && !thread_brands.contains(brand) {
+                thread_brands.push(brand.clone());
+            }
+        }
+
+        let stitch_count: usize = project.layers.iter().map(|l| l.stitches.len()).sum();
+        let completed: usize = project
+            .layers
+            .iter()
+            .map(|l| l.stitches.iter().filter(|s| s.completed).count())
+            .sum();
+
+        LibraryEntry {
+            path: path.to_string(),
+            name: project.metadata.name.clone(),
+            author: project.metadata.author.clone(),
+            width: project.canvas.width,
+            height: project.canvas.height,
+            mesh_count: project.canvas.mesh_count,
+            thread_brands,
+            color_count: project.color_palette.len(),
+            stitch_count,
+            progress_percent: if stitch_count == 0 {
+                0.0
+            } else {
+                completed as f64 * 100.0 / stitch_count as f64
+            },
+            tags: Vec::new(),
+            modified,
+            indexed_at: unix_now(),
+        }
+    }
+
+    /// Build an entry from a header (used when scanning folders)
+    pub fn from_header(path: &str, header: &ProjectHeader, modified: u64) -> Self {
+        LibraryEntry {
+            path: path.to_string(),
+            name: header.metadata.name.clone(),
+            author: header.metadata.author.clone(),
+            width: header.canvas.width,
+            height: header.canvas.height,
+            mesh_count: header.canvas.mesh_count,
+            thread_brands: header.thread_brands.clone(),
+            color_count: header.color_count,
+            stitch_count: header.stitch_count,
+            progress_percent: header.progress_percent,
+            tags: Vec::new(),
+            modified,
+            indexed_at: unix_now(),
+        }
+    }
+
+    /// Whether every whitespace-separated query term appears in a searchable field
+    fn matches_text(&self, query: &str) -> bool {
+        let file_name = Path::new(&self.path)
+            .file_name()
+            .map(|n| n.to_string_lossy().to_lowercase())
+            .unwrap_or_default();
+        let haystack: Vec<String> = [self.name.to_lowercase(), file_name]
+            .into_iter()
+            .chain(self.author.iter().map(|a| a.to_lowercase()))
+            .chain(self.tags.iter().map(|t| t.to_lowercase()))
+            .chain(self.thread_brands.iter().map(|b| b.to_lowercase()))
+            .collect();
+
+        query
+            .split_whitespace()
+            .map(|term| term.to_lowercase())
+            .all(|term| haystack.iter().any(|field| field.contains(&term)))
+    }
+}
+
+/// Persistent index keyed by file path
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LibraryIndex {
+    pub version: u32,
+    pub entries: BTreeMap<String, LibraryEntry>,
+}
+
+impl Default for LibraryIndex {
+    fn default() -> Self {
+        LibraryIndex {
+            version: LIBRARY_INDEX_VERSION,
+            entries: BTreeMap::new(),
+        }
+    }
+}
+
+impl LibraryIndex {
+    /// Insert or replace an entry, keeping user-assigned tags
+    pub fn upsert(&mut self, mut entry: LibraryEntry) {
+        if let Some(existing) = self.entries.get(&entry.path) {
+            if entry.tags.is_empty() {
+                entry.tags = existing.tags.clone();
+            }
+        }
+        self.entries.insert(entry.path.clone(), entry);
+    }
+
+    pub fn remove(&mut self, path: &str) -> Option<LibraryEntry> {
+        self.entries.remove(path)
+    }
+
+    /// Run a search, returning matching entries in the requested order
+    pub fn search(&self, query: &LibraryQuery) -> Vec<LibraryEntry> {
+        let mut results: Vec<LibraryEntry> = self
+            .entries
+            .values()
+            .filter(|entry| query.matches(entry))
+            .cloned()
+            .collect();
+
+        match query.sort {
+            LibrarySort::Modified => results.sort_by_key(|e| std::cmp::Reverse(e.modified)),
+            LibrarySort::Name => results.sort_by_key(|e| e.name.to_lowercase()),
+            LibrarySort::Progress => results.sort_by(|a, b| b.progress_percent.total_cmp(&a.progress_percent)),
+            LibrarySort::Size => results.sort_by_key(|e| std::cmp::Reverse(e.width as u64 * e.height as u64)),
+        }
+
+        if let Some(limit) = query.limit {
+            results.truncate(limit);
+        }
+
+        results
+    }
+}
+
+/// Result ordering for library searches
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum LibrarySort {
+    #[default]
+    Modified,
+    Name,
+    Progress,
+    Size,
+}
+
+/// Text query plus optional filters; empty fields match everything
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct LibraryQuery {
+    #[serde(default)]
+    pub text: Option<String>,
+    /// Match projects using any of these brands
+    #[serde(default)]
+    pub thread_brands: Vec<String>,
+    /// Match projects carrying all of these tags
+    #[serde(default)]
+    pub tags: Vec<String>,
+    #[serde(default)]
+    pub mesh_count: Option<u32>,
+    #[serde(default)]
+    pub min_width: Option<u32>,
+    #[serde(default)]
+    pub max_width: Option<u32>,
+    #[serde(default)]
+    pub min_height: Option<u32>,
+    #[serde(default)]
+    pub max_height: Option<u32>,
+    #[serde(default)]
+    pub max_colors: Option<usize>,
+    #[serde(default)]
+    pub min_progress: Option<f64>,
+    #[serde(default)]
+    pub max_progress: Option<f64>,
+    #[serde(default)]
+    pub sort: LibrarySort,
+    #[serde(default)]
+    pub limit: Option<usize>,
+}
+
+impl LibraryQuery {
+    fn matches(&self, entry: &LibraryEntry) -> bool {
+        if let Some(text) = self.text.as_deref().filter(|t| !t.trim().is_empty()) {
+            if !entry.matches_text(text) {
+                return false;
+            }
+        }
+
+        if !self.thread_brands.is_empty()
+            && !self
+                .thread_brands
+                .iter()
+                .any(|b| entry.thread_brands.iter().any(|eb| eb.eq_ignore_ascii_case(b)))
+        {
+            return false;
+        }
+
+        if !self
+            .tags
+            .iter()
+            .all(|t| entry.tags.iter().any(|et| et.eq_ignore_ascii_case(t)))
+        {
+            return false;
+        }
+
+        let in_range = |value: u32, min: Option<u32>, max: Option<u32>| {
+            min.is_none_or(|m| value >= m) && max.is_none_or(|m| value <= m)
+        };
+
+        self.mesh_count.is_none_or(|m| entry.mesh_count == m)
+            && in_range(entry.width, self.min_width, self.max_width)
+            && in_range(entry.height, self.min_height, self.max_height)
+            && self.max_colors.is_none_or(|m| entry.color_count <= m)
+            && self.min_progress.is_none_or(|m| entry.progress_percent >= m)
+            && self.max_progress.is_none_or(|m| entry.progress_percent <= m)
+    }
+}
+
+fn unix_now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+/// Modified time of a file in Unix seconds (0 if unavailable)
+fn file_modified(path: &Path) -> u64 {
+    fs::metadata(path)
+        .ok()
+        .and_then(|m| m.modified().ok())
+        .and_then(|t| t.duration_since(UNIX_EPOCH).ok())
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+/// Get the library index storage path
+fn get_index_path(app: &tauri::AppHandle) -> Result<PathBuf, String> {
+    let app_dir = app
+        .path()
+        .app_data_dir()
+        .map_err(|e| format!("Failed to get app data directory: {}", e))?;
+
+    fs::create_dir_all(&app_dir)
+        .map_err(|e| format!("Failed to create app data directory: {}", e))?;
+
+    Ok(app_dir.join(LIBRARY_INDEX_FILE))
+}
+
+/// Read the library index file, or start an empty index
+fn read_index_file(app: &tauri::AppHandle) -> Result<LibraryIndex, String> {
+    let path = get_index_path(app)?;
+    if !path.exists() {
+        return Ok(LibraryIndex::default());
+    }
+
+    let contents = fs::read_to_string(&path)
+        .map_err(|e| format!("Failed to read library index: {}", e))?;
+    // A corrupt index is rebuilt from scratch rather than blocking the library
+    Ok(serde_json::from_str(&contents).unwrap_or_default())
+}
+
+/// Write the library index file
+fn write_index_file(app: &tauri::AppHandle, index: &LibraryIndex) -> Result<(), String> {
+    let path = get_index_path(app)?;
+
+    let json = serde_json::to_string(index)
+        .map_err(|e| format!("Failed to serialize library index: {}", e))?;
+
+    fs::write(&path, json)
+        .map_err(|e| format!("Failed to write library index: {}", e))
+}
+
+/// Load the library index from storage
+pub fn load_index(app: &tauri::AppHandle) -> Result<LibraryIndex, String> {
+    let mut cache = LIBRARY_INDEX_CACHE.lock().unwrap();
+    if cache.is_none() {
+        *cache = Some(read_index_file(app)?);
+    }
+    Ok(cache.as_ref().unwrap().clone())
+}
+
+/// Apply a change to the index and save it, holding the cache lock throughout
+/// so concurrent updates (saves, the file watcher, commands) can't overwrite each other
+pub fn update_index<T>(
+    app: &tauri::AppHandle,
+    update: impl FnOnce(&mut LibraryIndex) -> Result<T, String>,
+) -> Result<T, String> {
+    let mut cache = LIBRARY_INDEX_CACHE.lock().unwrap();
+    let mut index = match cache.take() {
+        Some(index) => index,
+        None => read_index_file(app)?,
+    };
+
+    let result = update(&mut index);
+    let saved = match &result {
+        Ok(_) => write_index_file(app, &index),
+        Err(_) => Ok(()),
+    };
+
+    // Cache the change only once it is on disk; after a failed write the next
+    // load starts again from the file
+    if saved.is_ok() {
+        *cache = Some(index);
+    }
+    saved?;
+    result
+}
+
+/// Update the index after a project has been written to disk
+pub fn record_saved_project(app: &tauri::AppHandle, path: &str, project: &NdpFile) -> Result<(), String> {
+    let entry = LibraryEntry::from_project(path, project, file_modified(Path::new(path)));
+    update_index(app, |index| {
+        index.upsert(entry);
+        Ok(())
+    })
+}
+
+/// Update the index from a header read off disk (e.g. a file changed outside the app)
+pub fn record_project_header(app: &tauri::AppHandle, path: &str, header: &ProjectHeader) -> Result<(), String> {
+    let entry = LibraryEntry::from_header(path, header, file_modified(Path::new(path)));
+    update_index(app, |index| {
+        index.upsert(entry);
+        Ok(())
+    })
+}
+
+/// Move an entry to a new path, keeping its tags
+pub fn rename_project(app: &tauri::AppHandle, from: &str, to: &str) -> Result<(), String> {
+    update_index(app, |index| {
+        if let Some(mut entry) = index.remove(from) {
+            entry.path = to.to_string();
+            index.upsert(entry);
+        }
+        Ok(())
+    })
+}
+
+/// Drop a file from the index (e.g. after it was deleted)
+pub fn forget_project(app: &tauri::AppHandle, path: &str) -> Result<(), String> {
+    update_index(app, |index| {
+        index.remove(path);
+        Ok(())
+    })
+}
+
+/// Re-index the project files in a directory, dropping entries for files that
+/// no longer exist there. Unchanged files keep their existing entries.
+fn index_directory_into(index: &mut LibraryIndex, directory: &Path) -> usize {
+    let mut seen: Vec<String> = Vec::new();
+
+    if let Ok(entries) = fs::read_dir(directory) {
+        for entry in entries.flatten() {
+            let path = entry.path();
+            if !crate::is_project_path(&path) {
+                continue;
+            }
+
+            let path_str = path.to_string_lossy().to_string();
+            let modified = file_modified(&path);
+            seen.push(path_str.clone());
+
+            let unchanged = index
+                .entries
+                .get(&path_str)
+                .map(|e| e.modified == modified)
+                .unwrap_or(false);
+            if unchanged {
+                continue;
+            }
+
+            if let Ok(header) = header::read_header(&path) {
+                index.upsert(LibraryEntry::from_header(&path_str, &header, modified));
+            }
+        }
+    }
+
+    let stale: Vec<String> = index
+        .entries
+        .keys()
+        .filter(|p| Path::new(p).parent() == Some(directory) && !seen.contains(p))
+        .cloned()
+        .collect();
+    for path in stale {
+        index.remove(&path);
+    }
+
+    seen.len()
+}
+
+/// Tauri commands for the project library
+pub mod commands {
+    use super::*;
+
+    /// Search the library index
+    #[tauri::command]
+    pub fn search_projects(app: tauri::AppHandle, query: LibraryQuery) -> Result<Vec<LibraryEntry>, String> {
+        let index = load_index(&app)?;
+        Ok(index.search(&query))
+    }
+
+    /// Scan one or more project folders into the index
+    /// Returns the number of project files found
+    #[tauri::command]
+    pub fn index_directories(app: tauri::AppHandle, directories: Vec<String>) -> Result<usize, String> {
+        let dir_paths = directories
+            .iter()
+            .map(|directory| paths::resolve_file(&app, directory, PathAccess::ScanDirectory))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        update_index(&app, |index| {
+            Ok(dir_paths
+                .iter()
+                .filter(|dir_path| dir_path.is_dir())
+                .map(|dir_path| index_directory_into(index, dir_path))
+                .sum())
+        })
+    }
+
+    /// Replace the tags on an indexed project
+    #[tauri::command]
+    pub fn set_project_tags(app: tauri::AppHandle, path: String, tags: Vec<String>) -> Result<LibraryEntry, String> {
+        let mut cleaned: Vec<String> = Vec::new();
+        for tag in tags.iter().map(|t| t.trim()).filter(|t| !t.is_empty()) {
+            if !cleaned.iter().any(|c| c.eq_ignore_ascii_case(tag)) {
+                cleaned.push(tag.to_string());
+            }
+        }
+
+        update_index(&app, |index| {
+            let entry = index
+                .entries
+                .get_mut(&path)
+                .ok_or_else(|| format!("Project is not in the library: {}", path))?;
+            entry.tags = cleaned;
+            Ok(entry.clone())
+        })
+    }
+
+    /// List every tag in use, sorted alphabetically
+    #[tauri::command]
+    pub fn list_project_tags(app: tauri::AppHandle) -> Result<Vec<String>, String> {
+        let index = load_index(&app)?;
+        let mut tags: Vec<String> = Vec::new();
+        for tag in index.entries.values().flat_map(|e| e.tags.iter()) {
+            if !tags.iter().any(|t| t.eq_ignore_ascii_case(tag)) {
+                tags.push(tag.clone());
+            }
+        }
+        tags.sort_by_key(|t| t.to_lowercase());
+        Ok(tags)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(path: &str, name: &str, brands: &[&str], tags: &[&str], progress: f64, modified: u64) -> LibraryEntry {
+        LibraryEntry {
+            path: path.to_string(),
+            name: name.to_string(),
+            author: Some("Ann".to_string()),
+            width: 100,
+            height: 80,
+            mesh_count: 13,
+            thread_brands: brands.iter().map(|b| b.to_string()).collect(),
+            color_count: 12,
+            stitch_count: 8000,
+            progress_percent: progress,
+            tags: tags.iter().map(|t| t.to_string()).collect(),
+            modified,
+            indexed_at: 0,
+        }
+    }
+
+    fn test_index() -> LibraryIndex {
+        let mut index = LibraryIndex::default();
+        index.upsert(entry("/p/roses.stitchalot", "Rose Garden", &["DMC"], &["floral", "gift"], 10.0, 3));
+        index.upsert(entry("/p/owl.stitchalot", "Night Owl", &["Anchor"], &["animals"], 90.0, 2));
+        index.upsert(entry("/p/belt.stitchalot", "Belt Sampler", &["DMC", "Kreinik"], &["gift"], 50.0, 1));
+        index
+    }
+
+    #[test]
+    fn test_search_text_and_sort() {
+        let index = test_index();
+
+        let all = index.search(&LibraryQuery::default());
+        let names: Vec<&str> = all.iter().map(|e| e.name.as_str()).collect();
+        assert_eq!(names, vec!["Rose Garden", "Night Owl", "Belt Sampler"]);
+
+        let query = LibraryQuery {
+            text: Some("owl ann".to_string()),
+            ..Default::default()
+        };
+        let results = index.search(&query);
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].name, "Night Owl");
+    }
+
+    #[test]
+    fn test_search_filters() {
+        let index = test_index();
+
+        let query = LibraryQuery {
+            thread_brands: vec!["dmc".to_string()],
+            tags: vec!["gift".to_string()],
+            min_progress: Some(25.0),
+            ..Default::default()
+        };
+        let results = index.search(&query);
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].name, "Belt Sampler");
+
+        let query = LibraryQuery {
+            sort: LibrarySort::Progress,
+            limit: Some(2),
+            ..Default::default()
+        };
+        let names: Vec<String> = index.search(&query).into_iter().map(|e| e.name).collect();
+        assert_eq!(names, vec!["Night Owl", "Belt Sampler"]);
+    }
+
+    #[test]
+    fn test_upsert_keeps_tags() {
+        let mut index = test_index();
+        index.upsert(entry("/p/owl.stitchalot", "Night Owl v2", &["Anchor"], &[], 95.0, 5));
+
+        let owl = &index.entries["/p/owl.stitchalot"];
+        assert_eq!(owl.name, "Night Owl v2");
+        assert_eq!(owl.tags, vec!["animals".to_string()]);
+    }
+
+    #[test]
+    fn test_index_directory_project_types() {
+        let dir = std::env::temp_dir().join(format!("stitchalot-library-test-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        let project = crate::fixtures::project(4, 3, vec![], vec![]);
+        let json = serde_json::to_string(&project).unwrap();
+        fs::write(dir.join("roses.stitchalot"), &json).unwrap();
+        fs::write(dir.join("legacy.ndp"), &json).unwrap();
+        fs::write(dir.join("notes.txt"), &json).unwrap();
+
+        let mut index = LibraryIndex::default();
+        index_directory_into(&mut index, &dir);
+        let mut paths: Vec<&str> = index.entries.keys().map(|p| p.as_str()).collect();
+        paths.sort();
+        let expected = [dir.join("legacy.ndp"), dir.join("roses.stitchalot")];
+        assert_eq!(paths, expected.iter().map(|p| p.to_str().unwrap()).collect::<Vec<_>>());
+        assert_eq!(index.entries[expected[0].to_str().unwrap()].name, "Roses");
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+}
@@ -0,0 +1,431 @@
+// Project Package Module
+// Zip container format storing overlays, thumbnails and assets as separate entries
+
+use crate::header::{self, ProjectHeader};
+use crate::NdpFile;
+use base64::{engine::general_purpose::STANDARD, Engine};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::io::{Cursor, Read, Write};
+use std::path::Path;
+use zip::write::SimpleFileOptions;
+use zip::{CompressionMethod, ZipArchive, ZipWriter};
+
+/// First entry of every package, stored uncompressed so the type is easy to sniff
+const MIMETYPE_ENTRY: &str = "mimetype";
+const PACKAGE_MIMETYPE: &str = "application/vnd.stitchalot.package+zip";
+
+/// Entry holding the project JSON with binary data replaced by references
+const PROJECT_ENTRY: &str = "project.json";
+
+/// Entry listing every binary entry and its MIME type
+const MANIFEST_ENTRY: &str = "manifest.json";
+
+/// Current package format version
+const PACKAGE_VERSION: u32 = 1;
+
+/// Prefix marking a field that refers to a package entry instead of holding inline data
+const ENTRY_REFERENCE_PREFIX: &str = "package:";
+
+/// Largest entry that will be decompressed (guards against zip bombs)
+const MAX_ENTRY_BYTES: u64 = 512 * 1024 * 1024;
+
+/// Zip local file header signature
+const ZIP_SIGNATURE: &[u8] = b"PK\x03\x04";
+
+/// On-disk project format
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum ProjectFormat {
+    /// A single JSON document with images inline as base64
+    #[default]
+    Json,
+    /// A zip container with images and assets as separate binary entries
+    Package,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct Manifest {
+    version: u32,
+    #[serde(default)]
+    entries: BTreeMap<String, String>, // Entry name -> MIME type
+}
+
+/// Whether file contents are a project package
+pub fn is_package(bytes: &[u8]) -> bool {
+    bytes.starts_with(ZIP_SIGNATURE)
+}
+
+/// Format of an existing project file, if it can be read
+pub fn detect_format(path: &Path) -> Option<ProjectFormat> {
+    let mut signature = [0u8; 4];
+    fs::File::open(path).ok()?.read_exact(&mut signature).ok()?;
+    Some(if is_package(&signature) {
+        ProjectFormat::Package
+    } else {
+        ProjectFormat::Json
+    })
+}
+
+/// Serialize a project in the given format
+pub fn encode_project(project: &NdpFile, format: ProjectFormat) -> Result<Vec<u8>, String> {
+    match format {
+        ProjectFormat::Json => serde_json::to_string_pretty(project)
+            .map(String::into_bytes)
+            .map_err(|e| format!("Failed to serialize project: {}", e)),
+        ProjectFormat::Package => write_package(project),
+    }
+}
+
+/// Parse a project from either format
+pub fn decode_project(bytes: &[u8]) -> Result<NdpFile, String> {
+    if is_package(bytes) {
+        return read_package(bytes);
+    }
+
+    serde_json::from_slice(bytes)
+        .map_err(|e| format!("Failed to parse project file: {} (content length: {} bytes)", e, bytes.len()))
+}
+
+/// Split a base64 data URL into its MIME type and decoded bytes
+/// Returns None for anything else (remote URLs, non-base64 data URLs), which stays inline.
+fn decode_data_url(data_url: &str) -> Option<(String, Vec<u8>)> {
+    let rest = data_url.strip_prefix("data:")?;
+    let (meta, data) = rest.split_once(',')?;
+    let mime = meta.strip_suffix(";base64")?;
+    let bytes = STANDARD.decode(data).ok()?;
+    Some((mime.to_string(), bytes))
+}
+
+fn encode_data_url(mime: &str, bytes: &[u8]) -> String {
+    format!("data:{};base64,{}", mime, STANDARD.encode(bytes))
+}
+
+fn extension_for_mime(mime: &str) -> &'static str {
+    match mime {
+        "image/png" => "png",
+        "image/jpeg" | "image/jpg" => "jpg",
+        "image/webp" => "webp",
+        "image/gif" => "gif",
+        "image/svg+xml" => "svg",
+        "font/ttf" | "application/x-font-ttf" => "ttf",
+        "font/otf" | "application/x-font-otf" => "otf",
+        "font/woff" => "woff",
+        "font/woff2" => "woff2",
+        _ => "bin",
+    }
+}
+
+/// Already-compressed formats are stored rather than deflated again
+fn is_compressed(mime: &str) -> bool {
+    matches!(
+        mime,
+        "image/png" | "image/jpeg" | "image/jpg" | "image/webp" | "image/gif" | "font/woff" | "font/woff2"
+    )
+}
+
+/// Entry name component derived from an id (ids come from the frontend)
+fn sanitize_id(id: &str) -> String {
+    let cleaned: String = id
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() || c == '-' || c == '_' { c } else { '_' })
+        .take(64)
+        .collect();
+    if cleaned.is_empty() {
+        "item".to_string()
+    } else {
+        cleaned
+    }
+}
+
+/// Collects binary entries while the project JSON is rewritten
+struct EntryCollector {
+    entries: Vec<(String, String, Vec<u8>)>, // Name, MIME type, contents
+}
+
+impl EntryCollector {
+    /// Move a data URL into its own entry, returning the reference to store in its place
+    fn extract(&mut self, folder: &str, id: &str, data_url: &str) -> Option<String> {
+        let (mime, bytes) = decode_data_url(data_url)?;
+        let base = if folder.is_empty() {
+            sanitize_id(id)
+        } else {
+            format!("{}/{}", folder, sanitize_id(id))
+        };
+        let ext = extension_for_mime(&mime);
+
+        let mut name = format!("{}.{}", base, ext);
+        let mut n = 1;
+        while self.entries.iter().any(|(existing, _, _)| existing == &name) {
+            n += 1;
+            name = format!("{}-{}.{}", base, n, ext);
+        }
+
+        self.entries.push((name.clone(), mime, bytes));
+        Some(format!("{}{}", ENTRY_REFERENCE_PREFIX, name))
+    }
+}
+
+fn write_package(project: &NdpFile) -> Result<Vec<u8>, String> {
+    let mut stripped = project.clone();
+    let mut collector = EntryCollector { entries: Vec::new() };
+
+    if let Some(overlays) = stripped.overlays.as_mut() {
+        for overlay in overlays.iter_mut() {
+            if let Some(reference) = collector.extract("overlays", &overlay.id, &overlay.data_url) {
+                overlay.data_url = reference;
+            }
+        }
+    }
+
+    if let Some(assets) = stripped.assets.as_mut() {
+        for asset in assets.iter_mut() {
+            if let Some(reference) = collector.extract("assets", &asset.id, &asset.data_url) {
+                asset.data_url = reference;
+            }
+        }
+    }
+
+    if let Some(thumbnail) = stripped.thumbnail.take() {
+        stripped.thumbnail = Some(collector.extract("", "thumbnail", &thumbnail).unwrap_or(thumbnail));
+    }
+
+    let project_json = serde_json::to_vec_pretty(&stripped)
+        .map_err(|e| format!("Failed to serialize project: {}", e))?;
+    let manifest = Manifest {
+        version: PACKAGE_VERSION,
+        entries: collector
+            .entries
+            .iter()
+            .map(|(name, mime, _)| (name.clone(), mime.clone()))
+            .collect(),
+    };
+    let manifest_json = serde_json::to_vec_pretty(&manifest)
+        .map_err(|e| format!("Failed to serialize package manifest: {}", e))?;
+
+    let stored = SimpleFileOptions::default().compression_method(CompressionMethod::Stored);
+    let deflated = SimpleFileOptions::default().compression_method(CompressionMethod::Deflated);
+
+    let mut writer = ZipWriter::new(Cursor::new(Vec::new()));
+    let mut add = |name: &str, options: SimpleFileOptions, data: &[u8]| -> Result<(), String> {
+        writer
+            .start_file(name, options)
+            .and_then(|_| writer.write_all(data).map_err(Into::into))
+            .map_err(|e| format!("Failed to write package entry {}: {}", name, e))
+    };
+
+    add(MIMETYPE_ENTRY, stored, PACKAGE_MIMETYPE.as_bytes())?;
+    add(MANIFEST_ENTRY, deflated, &manifest_json)?;
+    add(PROJECT_ENTRY, deflated, &project_json)?;
+    for (name, mime, bytes) in &collector.entries {
+        add(name, if is_compressed(mime) { stored } else { deflated }, bytes)?;
+    }
+
+    writer
+        .finish()
+        .map(Cursor::into_inner)
+        .map_err(|e| format!("Failed to finish package: {}", e))
+}
+
+type PackageArchive<'a> = ZipArchive<Cursor<&'a [u8]>>;
+
+fn open_archive(bytes: &[u8]) -> Result<PackageArchive<'_>, String> {
+    ZipArchive::new(Cursor::new(bytes)).map_err(|e| format!("Failed to open project package: {}", e))
+}
+
+fn read_entry(archive: &mut PackageArchive, name: &str) -> Result<Vec<u8>, String> {
+    let entry = archive
+        .by_name(name)
+        .map_err(|e| format!("Project package is missing {}: {}", name, e))?;
+    if entry.size() > MAX_ENTRY_BYTES {
+        return Err(format!("Package entry {} is too large ({} bytes)", name, entry.size()));
+    }
+
+    let mut data = Vec::with_capacity(entry.size() as usize);
+    entry
+        .take(MAX_ENTRY_BYTES)
+        .read_to_end(&mut data)
+        .map_err(|e| format!("Failed to read package entry {}: {}", name, e))?;
+    Ok(data)
+}
+
+fn read_manifest(archive: &mut PackageArchive) -> Result<Manifest, String> {
+    let data = read_entry(archive, MANIFEST_ENTRY)?;
+    let manifest: Manifest = serde_json::from_slice(&data)
+        .map_err(|e| format!("Failed to parse package manifest: {}", e))?;
+    if manifest.version > PACKAGE_VERSION {
+        return Err(format!(
+            "Project package version {} is newer than this app supports",
+            manifest.version
+        ));
+    }
+    Ok(manifest)
+}
+
+/// Replace an entry reference with the entry's contents as a data URL
+/// Values that aren't references are returned unchanged.
+fn inline_reference(archive: &mut PackageArchive, manifest: &Manifest, value: &str) -> Result<String, String> {
+    let Some(name) = value.strip_prefix(ENTRY_REFERENCE_PREFIX) else {
+        return Ok(value.to_string());
+    };
+
+    let mime = manifest
+        .entries
+        .get(name)
+        .ok_or_else(|| format!("Project package manifest does not list {}", name))?;
+    let bytes = read_entry(archive, name)?;
+    Ok(encode_data_url(mime, &bytes))
+}
+
+fn read_package(bytes: &[u8]) -> Result<NdpFile, String> {
+    let mut archive = open_archive(bytes)?;
+    let manifest = read_manifest(&mut archive)?;
+    let project_json = read_entry(&mut archive, PROJECT_ENTRY)?;
+    let mut project: NdpFile = serde_json::from_slice(&project_json)
+        .map_err(|e| format!("Failed to parse project file: {} (content length: {} bytes)", e, project_json.len()))?;
+
+    if let Some(overlays) = project.overlays.as_mut() {
+        for overlay in overlays.iter_mut() {
+            overlay.data_url = inline_reference(&mut archive, &manifest, &overlay.data_url)?;
+        }
+    }
+
+    if let Some(assets) = project.assets.as_mut() {
+        for asset in assets.iter_mut() {
+            asset.data_url = inline_reference(&mut archive, &manifest, &asset.data_url)?;
+        }
+    }
+
+    if let Some(thumbnail) = project.thumbnail.take() {
+        project.thumbnail = Some(inline_reference(&mut archive, &manifest, &thumbnail)?);
+    }
+
+    Ok(project)
+}
+
+/// Read a package's header without decoding overlays or assets
+pub fn read_package_header(bytes: &[u8]) -> Result<ProjectHeader, String> {
+    let mut archive = open_archive(bytes)?;
+    let manifest = read_manifest(&mut archive)?;
+    let project_json = read_entry(&mut archive, PROJECT_ENTRY)?;
+    let mut project_header = header::parse_json_header(&project_json)?;
+
+    if let Some(thumbnail) = project_header.thumbnail.take() {
+        // A broken thumbnail entry shouldn't hide the project from listings
+        project_header.thumbnail = inline_reference(&mut archive, &manifest, &thumbnail).ok();
+    }
+
+    Ok(project_header)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures;
+    use crate::{OverlayImage, ProjectAsset, Stitch};
+
+    const PNG_DATA_URL: &str = "data:image/png;base64,iVBORw0KGgoAAAANSUhEUgAAAAEAAAABCAYAAAAfFcSJAAAADUlEQVR42mP8z8BQDwAEhQGAhKmMIQAAAABJRU5ErkJggg==";
+
+    fn sample_project() -> NdpFile {
+        let mut color = fixtures::color("c1", [200, 0, 0]);
+        color.name = "Red".to_string();
+        let stitch = Stitch { completed: true, ..fixtures::stitch(1, 1, "c1") };
+
+        let mut project = fixtures::project(4, 4, vec![color], vec![stitch]);
+        project.metadata.software = "StitchALot Studio v1.0".to_string();
+        project.overlays = Some(vec![
+            OverlayImage {
+                id: "photo/1".to_string(),
+                name: "Photo".to_string(),
+                data_url: PNG_DATA_URL.to_string(),
+                opacity: 50,
+                visible: true,
+                locked: false,
+                x: 0,
+                y: 0,
+                width: 4,
+                height: 4,
+                natural_width: 1,
+                natural_height: 1,
+            },
+            OverlayImage {
+                id: "remote".to_string(),
+                name: "Remote".to_string(),
+                data_url: "https://example.com/photo.png".to_string(),
+                opacity: 100,
+                visible: false,
+                locked: false,
+                x: 0,
+                y: 0,
+                width: 4,
+                height: 4,
+                natural_width: 1,
+                natural_height: 1,
+            },
+        ]);
+        project.assets = Some(vec![ProjectAsset {
+            id: "font1".to_string(),
+            name: "Script.ttf".to_string(),
+            kind: "font".to_string(),
+            data_url: "data:font/ttf;base64,AAEAAAALAIAAAwAw".to_string(),
+        }]);
+        project.progress_shading_opacity = Some(70);
+        project.thumbnail = Some(PNG_DATA_URL.to_string());
+        project.thumbnail_fingerprint = Some(crate::thumbnail::content_fingerprint(&project));
+        project
+    }
+
+    #[test]
+    fn test_package_round_trip() {
+        let project = sample_project();
+        let bytes = encode_project(&project, ProjectFormat::Package).unwrap();
+        assert!(is_package(&bytes));
+
+        let mut archive = open_archive(&bytes).unwrap();
+        assert_eq!(archive.name_for_index(0), Some(MIMETYPE_ENTRY));
+        let names: Vec<String> = archive.file_names().map(|n| n.to_string()).collect();
+        assert!(names.contains(&"overlays/photo_1.png".to_string()));
+        assert!(names.contains(&"assets/font1.ttf".to_string()));
+        assert!(names.contains(&"thumbnail.png".to_string()));
+
+        // Binary data is not duplicated inside the project JSON
+        let project_json = String::from_utf8(read_entry(&mut archive, PROJECT_ENTRY).unwrap()).unwrap();
+        assert!(!project_json.contains("base64"));
+        assert!(project_json.contains("https://example.com/photo.png"));
+
+        let decoded = decode_project(&bytes).unwrap();
+        let overlays = decoded.overlays.unwrap();
+        assert_eq!(overlays[0].data_url, PNG_DATA_URL);
+        assert_eq!(overlays[1].data_url, "https://example.com/photo.png");
+        assert_eq!(decoded.assets.unwrap()[0].data_url, "data:font/ttf;base64,AAEAAAALAIAAAwAw");
+        assert_eq!(decoded.thumbnail.as_deref(), Some(PNG_DATA_URL));
+        assert_eq!(decoded.layers[0].stitches.len(), 1);
+    }
+
+    #[test]
+    fn test_decode_json_and_header() {
+        let project = sample_project();
+        let json = encode_project(&project, ProjectFormat::Json).unwrap();
+        assert!(!is_package(&json));
+        assert_eq!(decode_project(&json).unwrap().metadata.name, "Roses");
+
+        let package = encode_project(&project, ProjectFormat::Package).unwrap();
+        let package_header = header::parse_header(&package).unwrap();
+        assert_eq!(package_header.metadata.name, "Roses");
+        assert_eq!(package_header.stitch_count, 1);
+        assert_eq!(package_header.trusted_thumbnail(), Some(PNG_DATA_URL));
+    }
+
+    #[test]
+    fn test_rejects_broken_packages() {
+        let package = encode_project(&sample_project(), ProjectFormat::Package).unwrap();
+        assert!(decode_project(&package[..package.len() / 2]).is_err());
+
+        // A reference to an entry the manifest doesn't list
+        let mut project = sample_project();
+        project.overlays.as_mut().unwrap()[1].data_url = "package:overlays/missing.png".to_string();
+        let bytes = encode_project(&project, ProjectFormat::Package).unwrap();
+        assert!(decode_project(&bytes).is_err());
+    }
+}
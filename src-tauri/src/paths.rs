@@ -0,0 +1,759 @@
+// Path Scope Module
+// Resolves paths sent from the webview and checks them against the allowed locations
+
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::fs;
+use std::io::Write;
+use std::path::{Component, Path, PathBuf};
+use std::sync::Mutex;
+use tauri::Manager;
+use tauri_plugin_fs::{FilePath, FsExt, OpenOptions};
+
+/// Storage file for user-granted locations in app data
+const PATH_SCOPE_FILE: &str = "path-scope.json";
+
+/// Granted files kept across restarts (oldest are dropped first)
+const MAX_GRANTED_FILES: usize = 500;
+
+/// Image formats accepted by load_image
+const IMAGE_EXTENSIONS: [&str; 9] = ["png", "jpg", "jpeg", "gif", "bmp", "webp", "tif", "tiff", "svg"];
+
+/// File formats the export commands may write
+const EXPORT_EXTENSIONS: [&str; 5] = ["pdf", "png", "jpg", "jpeg", "svg"];
+
+/// Thread library formats accepted by import_thread_library
+const THREAD_LIBRARY_EXTENSIONS: [&str; 2] = ["json", "csv"];
+
+/// Stash spreadsheet formats accepted by import_thread_inventory
+const INVENTORY_EXTENSIONS: [&str; 2] = ["xlsx", "csv"];
+
+/// In-memory cache for the persisted scope
+static PATH_SCOPE_CACHE: Mutex<Option<PathScope>> = Mutex::new(None);
+
+/// What a command is going to do with a path
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PathAccess {
+    ReadProject,       // open_project, thumbnails, headers
+    WriteProject,      // save_project
+    DeleteProject,     // delete_file
+    ScanDirectory,     // scan_directory
+    ReadImage,         // load_image, process_image, process_image_with_threads
+    WriteExport,       // save_pdf, image and SVG export
+    ReadThreadLibrary, // import_thread_library, validate_thread_library
+    ReadInventory,     // import_thread_inventory
+}
+
+/// Locations the user has granted through a file or folder dialog
+/// The app's own documents and data folders are always allowed and are not stored here.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PathScope {
+    #[serde(default)]
+    pub roots: Vec<PathBuf>, // Project folders, including subfolders
+    #[serde(default)]
+    pub files: Vec<PathBuf>, // Individual files picked in a dialog
+}
+
+impl PathScope {
+    fn allows(&self, path: &Path, builtin_roots: &[PathBuf]) -> bool {
+        let path = canonical_path(path);
+        builtin_roots
+            .iter()
+            .chain(self.roots.iter())
+            .any(|root| path.starts_with(canonical_path(root)))
+            || self.files.iter().any(|file| canonical_path(file) == path)
+    }
+
+    /// Remember a dialog-approved path so it stays usable after a restart
+    fn grant(&mut self, path: &Path, access: PathAccess) {
+        let path = canonical_path(path);
+        if access == PathAccess::ScanDirectory {
+            if !self.roots.contains(&path) {
+                self.roots.push(path);
+            }
+            return;
+        }
+
+        self.files.retain(|f| f != &path);
+        self.files.push(path);
+        if self.files.len() > MAX_GRANTED_FILES {
+            let excess = self.files.len() - MAX_GRANTED_FILES;
+            self.files.drain(..excess);
+        }
+    }
+}
+
+/// Turn a webview path into a plain filesystem path
+/// Strips file:// URLs, URL-decodes (iOS sends %20 etc.) and rejects `..` components.
+pub fn normalize_path(raw: &str) -> Result<PathBuf, String> {
+    let without_scheme = raw.strip_prefix("file://").unwrap_or(raw);
+    let decoded = urlencoding::decode(without_scheme)
+        .map(|s| s.into_owned())
+        .unwrap_or_else(|_| without_scheme.to_string());
+
+    if decoded.trim().is_empty() {
+        return Err("Path is empty".to_string());
+    }
+
+    let mut normalized = PathBuf::new();
+    for component in Path::new(&decoded).components() {
+        match component {
+            Component::ParentDir => {
+                return Err(format!("Path traversal is not allowed: {}", raw));
+            }
+            Component::CurDir => {}
+            other => normalized.push(other),
+        }
+    }
+
+    Ok(normalized)
+}
+
+/// Resolve symlinks where possible; paths that don't exist yet resolve through their parent
+fn canonical_path(path: &Path) -> PathBuf {
+    if let Ok(canonical) = fs::canonicalize(path) {
+        return canonical;
+    }
+
+    match (path.parent(), path.file_name()) {
+        (Some(parent), Some(name)) => fs::canonicalize(parent)
+            .map(|p| p.join(name))
+            .unwrap_or_else(|_| path.to_path_buf()),
+        _ => path.to_path_buf(),
+    }
+}
+
+fn has_extension(path: &Path, extensions: &[&str]) -> bool {
+    path.extension()
+        .and_then(|ext| ext.to_str())
+        .map(|ext| extensions.iter().any(|e| ext.eq_ignore_ascii_case(e)))
+        .unwrap_or(false)
+}
+
+/// Check a normalized path for the given access
+/// Returns Ok(true) when the path was only allowed by a dialog and should be granted.
+fn check_access(
+    path: &Path,
+    access: PathAccess,
+    scope: &PathScope,
+    builtin_roots: &[PathBuf],
+    dialog_allows: &dyn Fn(&Path) -> bool,
+) -> Result<bool, String> {
+    if !path.is_absolute() {
+        return Err(format!("Path must be absolute: {:?}", path));
+    }
+
+    let type_ok = match access {
+        PathAccess::ReadProject | PathAccess::WriteProject | PathAccess::DeleteProject => crate::is_project_path(path),
+        PathAccess::ReadImage => has_extension(path, &IMAGE_EXTENSIONS),
+        PathAccess::WriteExport => has_extension(path, &EXPORT_EXTENSIONS),
+        PathAccess::ReadThreadLibrary => has_extension(path, &THREAD_LIBRARY_EXTENSIONS),
+        PathAccess::ReadInventory => has_extension(path, &INVENTORY_EXTENSIONS),
+        PathAccess::ScanDirectory => true,
+    };
+    if !type_ok {
+        return Err(format!("File type is not allowed for this operation: {:?}", path));
+    }
+
+    if scope.allows(path, builtin_roots) {
+        return Ok(false);
+    }
+
+    if dialog_allows(path) {
+        return Ok(true);
+    }
+
+    Err(format!("Access denied: {:?} is outside the allowed project folders", path))
+}
+
+/// A webview path after platform mapping
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ResolvedPath {
+    /// A regular filesystem path
+    File(PathBuf),
+    /// An Android `content://` URI from the system document picker
+    ContentUri(String),
+}
+
+impl ResolvedPath {
+    pub fn as_path(&self) -> Option<&Path> {
+        match self {
+            ResolvedPath::File(path) => Some(path),
+            ResolvedPath::ContentUri(_) => None,
+        }
+    }
+
+    /// The filesystem path, for operations that can't go through a content URI
+    pub fn into_file(self) -> Result<PathBuf, String> {
+        match self {
+            ResolvedPath::File(path) => Ok(path),
+            ResolvedPath::ContentUri(uri) => Err(format!("Operation is not supported for content URIs: {}", uri)),
+        }
+    }
+
+    fn file_path(uri: &str) -> FilePath {
+        match uri.parse::<FilePath>() {
+            Ok(file_path) => file_path,
+            Err(never) => match never {},
+        }
+    }
+
+    /// Read the whole file
+    pub fn read(&self, app: &tauri::AppHandle) -> Result<Vec<u8>, String> {
+        let result = match self {
+            ResolvedPath::File(path) => fs::read(path),
+            ResolvedPath::ContentUri(uri) => app.fs().read(Self::file_path(uri)),
+        };
+        result.map_err(|e| format!("Failed to read file: {} (path: {})", e, self))
+    }
+
+    /// Replace the file's contents, creating parent directories as needed
+    pub fn write(&self, app: &tauri::AppHandle, contents: &[u8]) -> Result<(), String> {
+        let result = match self {
+            ResolvedPath::File(path) => {
+                if let Some(parent) = path.parent() {
+                    fs::create_dir_all(parent)
+                        .map_err(|e| format!("Failed to create directory: {} (path: {:?})", e, parent))?;
+                }
+                fs::write(path, contents)
+            }
+            ResolvedPath::ContentUri(uri) => {
+                let mut options = OpenOptions::new();
+                options.write(true).create(true).truncate(true);
+                app.fs()
+                    .open(Self::file_path(uri), options)
+                    .and_then(|mut file| file.write_all(contents))
+            }
+        };
+        result.map_err(|e| format!("Failed to write file: {} (path: {}, content length: {} bytes)", e, self, contents.len()))
+    }
+}
+
+impl fmt::Display for ResolvedPath {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ResolvedPath::File(path) => write!(f, "{}", path.to_string_lossy()),
+            ResolvedPath::ContentUri(uri) => f.write_str(uri),
+        }
+    }
+}
+
+/// How webview paths map onto storage on each platform
+/// Desktop builds only construct `Desktop`; the mobile variants are exercised by tests.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(not(any(target_os = "ios", target_os = "android")), allow(dead_code))]
+pub enum PathStrategy {
+    /// Paths are used as given
+    Desktop,
+    /// Projects and exports live in the app's Documents folder. The frontend may
+    /// pass a bare file name; reads fall back to the given path (e.g. iCloud Drive).
+    IosSandbox { documents: PathBuf },
+    /// Exports go to the app's Documents folder; the document picker returns content URIs
+    Android { documents: PathBuf },
+}
+
+impl PathStrategy {
+    /// The strategy for the platform this build targets
+    pub fn for_app(app: &tauri::AppHandle) -> Result<Self, String> {
+        #[cfg(any(target_os = "ios", target_os = "android"))]
+        {
+            let documents = app.path()
+                .document_dir()
+                .map_err(|e| format!("Failed to get documents directory: {}", e))?;
+
+            #[cfg(target_os = "ios")]
+            return Ok(PathStrategy::IosSandbox { documents });
+
+            #[cfg(target_os = "android")]
+            return Ok(PathStrategy::Android { documents });
+        }
+
+        #[cfg(not(any(target_os = "ios", target_os = "android")))]
+        {
+            let _ = app; // Suppress unused warning on desktop
+            Ok(PathStrategy::Desktop)
+        }
+    }
+
+    fn documents(&self) -> Option<&Path> {
+        match self {
+            PathStrategy::Desktop => None,
+            PathStrategy::IosSandbox { documents } | PathStrategy::Android { documents } => Some(documents),
+        }
+    }
+
+    /// Whether this access is redirected into the Documents folder
+    fn uses_documents(&self, access: PathAccess) -> bool {
+        match self {
+            PathStrategy::Desktop => false,
+            PathStrategy::IosSandbox { .. } => matches!(
+                access,
+                PathAccess::ReadProject | PathAccess::WriteProject | PathAccess::WriteExport
+            ),
+            PathStrategy::Android { .. } => access == PathAccess::WriteExport,
+        }
+    }
+
+    /// Map a raw webview path for the given access
+    pub fn resolve(&self, raw: &str, access: PathAccess) -> Result<ResolvedPath, String> {
+        if raw.starts_with("content://") {
+            let supported = matches!(self, PathStrategy::Android { .. })
+                && matches!(
+                    access,
+                    PathAccess::ReadProject
+                        | PathAccess::WriteProject
+                        | PathAccess::ReadImage
+                        | PathAccess::ReadThreadLibrary
+                        | PathAccess::ReadInventory
+                );
+            if !supported {
+                return Err(format!("Content URIs are not supported for this operation: {}", raw));
+            }
+            return Ok(ResolvedPath::ContentUri(raw.to_string()));
+        }
+
+        let path = normalize_path(raw)?;
+        let documents = match self.documents() {
+            Some(documents) if self.uses_documents(access) => documents,
+            _ => return Ok(ResolvedPath::File(path)),
+        };
+
+        let file_name = path
+            .file_name()
+            .ok_or_else(|| format!("Path has no file name: {}", raw))?;
+        let doc_path = documents.join(file_name);
+
+        if access == PathAccess::ReadProject && !doc_path.exists() {
+            return Ok(ResolvedPath::File(path));
+        }
+        Ok(ResolvedPath::File(doc_path))
+    }
+}
+
+/// Folders the app may always use
+fn builtin_roots(app: &tauri::AppHandle) -> Vec<PathBuf> {
+    let paths = app.path();
+    [paths.document_dir(), paths.app_data_dir(), paths.app_cache_dir()]
+        .into_iter()
+        .flatten()
+        .collect()
+}
+
+/// Whether the dialog plugin has approved this path in the current session
+fn dialog_allows(app: &tauri::AppHandle, path: &Path) -> bool {
+    app.try_fs_scope()
+        .map(|scope| scope.is_allowed(path))
+        .unwrap_or(false)
+}
+
+fn get_scope_path(app: &tauri::AppHandle) -> Result<PathBuf, String> {
+    let app_dir = app
+        .path()
+        .app_data_dir()
+        .map_err(|e| format!("Failed to get app data directory: {}", e))?;
+
+    fs::create_dir_all(&app_dir)
+        .map_err(|e| format!("Failed to create app data directory: {}", e))?;
+
+    Ok(app_dir.join(PATH_SCOPE_FILE))
+}
+
+fn load_scope(app: &tauri::AppHandle) -> Result<PathScope, String> {
+    {
+        let cache = PATH_SCOPE_CACHE.lock().unwrap();
+        if let Some(scope) = cache.as_ref() {
+            return Ok(scope.clone());
+        }
+    }
+
+    let path = get_scope_path(app)?;
+    let scope: PathScope = if path.exists() {
+        let contents = fs::read_to_string(&path)
+            .map_err(|e| format!("Failed to read path scope: {}", e))?;
+        serde_json::from_str(&contents)
+            .map_err(|e| format!("Failed to parse path scope: {}", e))?
+    } else {
+        PathScope::default()
+    };
+
+    *PATH_SCOPE_CACHE.lock().unwrap() = Some(scope.clone());
+    Ok(scope)
+}
+
+fn save_scope(app: &tauri::AppHandle, scope: &PathScope) -> Result<(), String> {
+    let path = get_scope_path(app)?;
+    let json = serde_json::to_string_pretty(scope)
+        .map_err(|e| format!("Failed to serialize path scope: {}", e))?;
+    fs::write(&path, json)
+        .map_err(|e| format!("Failed to write path scope: {}", e))?;
+
+    *PATH_SCOPE_CACHE.lock().unwrap() = Some(scope.clone());
+    Ok(())
+}
+
+/// Map a raw path for the platform and check it against the scope
+/// Returns the resolved path and whether it was only allowed by a dialog and should be granted.
+pub(crate) fn resolve_in_scope(
+    strategy: &PathStrategy,
+    raw: &str,
+    access: PathAccess,
+    scope: &PathScope,
+    builtin_roots: &[PathBuf],
+    dialog_allows: &dyn Fn(&Path) -> bool,
+) -> Result<(ResolvedPath, bool), String> {
+    let resolved = strategy.resolve(raw, access)?;
+
+    // Content URIs are capabilities granted by the system picker and need no scope check
+    let granted = match &resolved {
+        ResolvedPath::File(path) => check_access(path, access, scope, builtin_roots, dialog_allows)?,
+        ResolvedPath::ContentUri(_) => false,
+    };
+
+    Ok((resolved, granted))
+}
+
+/// Resolve a path from the webview for a file-touching command
+/// Every command that reads, writes or lists files must go through here.
+pub fn resolve_path(app: &tauri::AppHandle, raw: &str, access: PathAccess) -> Result<ResolvedPath, String> {
+    let mut scope = load_scope(app)?;
+    let (resolved, granted) = resolve_in_scope(
+        &PathStrategy::for_app(app)?,
+        raw,
+        access,
+        &scope,
+        &builtin_roots(app),
+        &|p| dialog_allows(app, p),
+    )?;
+
+    if let (true, Some(path)) = (granted, resolved.as_path()) {
+        scope.grant(path, access);
+        save_scope(app, &scope)?;
+    }
+
+    Ok(resolved)
+}
+
+/// Resolve a path that must be on the filesystem (listing, deleting, caching)
+pub fn resolve_file(app: &tauri::AppHandle, raw: &str, access: PathAccess) -> Result<PathBuf, String> {
+    resolve_path(app, raw, access)?.into_file()
+}
+
+/// Tauri commands for managing project folders
+pub mod commands {
+    use super::*;
+
+    /// Project folders granted by the user
+    #[tauri::command]
+    pub fn list_project_roots(app: tauri::AppHandle) -> Result<Vec<String>, String> {
+        let scope = load_scope(&app)?;
+        Ok(scope.roots.iter().map(|p| p.to_string_lossy().to_string()).collect())
+    }
+
+    /// Add a project folder; it must have just been picked in a folder dialog
+    #[tauri::command]
+    pub fn add_project_root(app: tauri::AppHandle, directory: String) -> Result<String, String> {
+        let path = resolve_file(&app, &directory, PathAccess::ScanDirectory)?;
+        if !path.is_dir() {
+            return Err(format!("Path is not a directory: {}", directory));
+        }
+
+        let mut scope = load_scope(&app)?;
+        scope.grant(&path, PathAccess::ScanDirectory);
+        save_scope(&app, &scope)?;
+
+        Ok(canonical_path(&path).to_string_lossy().to_string())
+    }
+
+    /// Stop allowing a project folder
+    #[tauri::command]
+    pub fn remove_project_root(app: tauri::AppHandle, directory: String) -> Result<(), String> {
+        let path = canonical_path(&normalize_path(&directory)?);
+        let mut scope = load_scope(&app)?;
+        scope.roots.retain(|root| canonical_path(root) != path);
+        save_scope(&app, &scope)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Fixture {
+        dir: PathBuf,
+        scope: PathScope,
+        builtin: Vec<PathBuf>,
+    }
+
+    impl Fixture {
+        fn new(name: &str) -> Self {
+            let dir = std::env::temp_dir().join(format!("stitchalot-paths-test-{}-{}", name, std::process::id()));
+            let _ = fs::remove_dir_all(&dir);
+            for sub in ["docs", "projects/nested", "elsewhere"] {
+                fs::create_dir_all(dir.join(sub)).unwrap();
+            }
+            fs::write(dir.join("docs/a.stitchalot"), b"{}").unwrap();
+            fs::write(dir.join("projects/nested/b.stitchalot"), b"{}").unwrap();
+            fs::write(dir.join("elsewhere/c.stitchalot"), b"{}").unwrap();
+            fs::write(dir.join("elsewhere/photo.png"), b"").unwrap();
+            fs::write(dir.join("elsewhere/notes.txt"), b"").unwrap();
+
+            let scope = PathScope {
+                roots: vec![dir.join("projects")],
+                files: Vec::new(),
+            };
+            let builtin = vec![dir.join("docs")];
+            Fixture { dir, scope, builtin }
+        }
+
+        fn check(&self, path: &str, access: PathAccess) -> Result<bool, String> {
+            self.check_with_dialog(path, access, &[])
+        }
+
+        fn check_with_dialog(&self, path: &str, access: PathAccess, picked: &[&str]) -> Result<bool, String> {
+            let path = normalize_path(&format!("{}/{}", self.dir.to_string_lossy(), path))?;
+            let picked: Vec<PathBuf> = picked.iter().map(|p| self.dir.join(p)).collect();
+            check_access(&path, access, &self.scope, &self.builtin, &|p| picked.iter().any(|x| x == p))
+        }
+    }
+
+    impl Drop for Fixture {
+        fn drop(&mut self) {
+            let _ = fs::remove_dir_all(&self.dir);
+        }
+    }
+
+    #[test]
+    fn test_normalize_path() {
+        assert_eq!(
+            normalize_path("file:///Users/me/My%20Patterns/./rose.stitchalot").unwrap(),
+            PathBuf::from("/Users/me/My Patterns/rose.stitchalot")
+        );
+        assert!(normalize_path("/Users/me/../../etc/passwd").is_err());
+        assert!(normalize_path("/Users/me/%2E%2E/secret.stitchalot").is_err());
+        assert!(normalize_path("").is_err());
+    }
+
+    #[test]
+    fn test_open_project_scope() {
+        let fx = Fixture::new("open");
+        assert_eq!(fx.check("docs/a.stitchalot", PathAccess::ReadProject), Ok(false));
+        assert_eq!(fx.check("projects/nested/b.stitchalot", PathAccess::ReadProject), Ok(false));
+        assert!(fx.check("elsewhere/c.stitchalot", PathAccess::ReadProject).is_err());
+        assert!(fx.check("projects/../elsewhere/c.stitchalot", PathAccess::ReadProject).is_err());
+        assert!(fx.check("elsewhere/notes.txt", PathAccess::ReadProject).is_err());
+
+        // Picked in the open dialog this session
+        assert_eq!(
+            fx.check_with_dialog("elsewhere/c.stitchalot", PathAccess::ReadProject, &["elsewhere/c.stitchalot"]),
+            Ok(true)
+        );
+    }
+
+    #[test]
+    fn test_save_project_scope() {
+        let fx = Fixture::new("save");
+        assert_eq!(fx.check("projects/new.stitchalot", PathAccess::WriteProject), Ok(false));
+        assert!(fx.check("elsewhere/new.stitchalot", PathAccess::WriteProject).is_err());
+        assert!(fx.check("projects/new.json", PathAccess::WriteProject).is_err());
+        assert!(check_access(Path::new("new.stitchalot"), PathAccess::WriteProject, &fx.scope, &fx.builtin, &|_| true).is_err());
+    }
+
+    #[test]
+    fn test_delete_file_scope() {
+        let fx = Fixture::new("delete");
+        assert_eq!(fx.check("docs/a.stitchalot", PathAccess::DeleteProject), Ok(false));
+        assert!(fx.check("elsewhere/c.stitchalot", PathAccess::DeleteProject).is_err());
+        assert!(fx.check_with_dialog("elsewhere/notes.txt", PathAccess::DeleteProject, &["elsewhere/notes.txt"]).is_err());
+    }
+
+    #[test]
+    fn test_scan_directory_scope() {
+        let fx = Fixture::new("scan");
+        assert_eq!(fx.check("projects/nested", PathAccess::ScanDirectory), Ok(false));
+        assert!(fx.check("elsewhere", PathAccess::ScanDirectory).is_err());
+
+        // A folder picked in a dialog is granted as a project root
+        assert_eq!(fx.check_with_dialog("elsewhere", PathAccess::ScanDirectory, &["elsewhere"]), Ok(true));
+        let mut scope = fx.scope.clone();
+        scope.grant(&fx.dir.join("elsewhere"), PathAccess::ScanDirectory);
+        assert!(scope.allows(&fx.dir.join("elsewhere/c.stitchalot"), &fx.builtin));
+    }
+
+    #[test]
+    fn test_save_pdf_scope() {
+        let fx = Fixture::new("pdf");
+        assert_eq!(fx.check("projects/pattern.pdf", PathAccess::WriteExport), Ok(false));
+        assert!(fx.check("elsewhere/pattern.pdf", PathAccess::WriteExport).is_err());
+        assert!(fx.check("projects/pattern.sh", PathAccess::WriteExport).is_err());
+    }
+
+    #[test]
+    fn test_load_image_scope() {
+        let fx = Fixture::new("image");
+        assert!(fx.check("elsewhere/photo.png", PathAccess::ReadImage).is_err());
+        assert_eq!(
+            fx.check_with_dialog("elsewhere/photo.png", PathAccess::ReadImage, &["elsewhere/photo.png"]),
+            Ok(true)
+        );
+        assert!(fx.check_with_dialog("elsewhere/notes.txt", PathAccess::ReadImage, &["elsewhere/notes.txt"]).is_err());
+
+        // Granted files stay allowed without the dialog
+        let mut scope = fx.scope.clone();
+        scope.grant(&fx.dir.join("elsewhere/photo.png"), PathAccess::ReadImage);
+        assert!(scope.allows(&fx.dir.join("elsewhere/photo.png"), &fx.builtin));
+        assert!(!scope.allows(&fx.dir.join("elsewhere/c.stitchalot"), &fx.builtin));
+    }
+
+    #[test]
+    fn test_thread_library_scope() {
+        let fx = Fixture::new("threads");
+        assert_eq!(fx.check("docs/silks.csv", PathAccess::ReadThreadLibrary), Ok(false));
+        assert!(fx.check("elsewhere/silks.csv", PathAccess::ReadThreadLibrary).is_err());
+        assert_eq!(
+            fx.check_with_dialog("elsewhere/silks.json", PathAccess::ReadThreadLibrary, &["elsewhere/silks.json"]),
+            Ok(true)
+        );
+        assert!(fx.check_with_dialog("elsewhere/silks.txt", PathAccess::ReadThreadLibrary, &["elsewhere/silks.txt"]).is_err());
+
+        assert_eq!(fx.check("docs/stash.xlsx", PathAccess::ReadInventory), Ok(false));
+        assert!(fx.check("docs/stash.json", PathAccess::ReadInventory).is_err());
+        assert_eq!(
+            fx.check_with_dialog("elsewhere/stash.csv", PathAccess::ReadInventory, &["elsewhere/stash.csv"]),
+            Ok(true)
+        );
+    }
+
+    /// An allowed path for each access, and a path with the wrong file type where types are checked
+    fn access_sample(access: PathAccess) -> (&'static str, Option<&'static str>) {
+        match access {
+            PathAccess::ReadProject => ("projects/nested/b.stitchalot", Some("projects/nested/b.txt")),
+            PathAccess::WriteProject => ("projects/new.ndp", Some("projects/new.exe")),
+            PathAccess::DeleteProject => ("docs/a.stitchalot", Some("docs/a.json")),
+            PathAccess::ScanDirectory => ("projects/nested", None),
+            PathAccess::ReadImage => ("projects/photo.JPG", Some("projects/photo.svgz")),
+            PathAccess::WriteExport => ("projects/pattern.pdf", Some("projects/pattern.sh")),
+            PathAccess::ReadThreadLibrary => ("docs/silks.csv", Some("docs/silks.xlsx")),
+            PathAccess::ReadInventory => ("docs/stash.xlsx", Some("docs/stash.json")),
+        }
+    }
+
+    #[test]
+    fn test_resolve_in_scope_for_every_access() {
+        let fx = Fixture::new("resolve");
+        let resolve = |rel: &str, access: PathAccess| {
+            let raw = format!("{}/{}", fx.dir.to_string_lossy(), rel);
+            resolve_in_scope(&PathStrategy::Desktop, &raw, access, &fx.scope, &fx.builtin, &|_| false)
+        };
+
+        for access in [
+            PathAccess::ReadProject,
+            PathAccess::WriteProject,
+            PathAccess::DeleteProject,
+            PathAccess::ScanDirectory,
+            PathAccess::ReadImage,
+            PathAccess::WriteExport,
+            PathAccess::ReadThreadLibrary,
+            PathAccess::ReadInventory,
+        ] {
+            let (allowed, wrong_type) = access_sample(access);
+            assert_eq!(resolve(allowed, access), Ok((ResolvedPath::File(fx.dir.join(allowed)), false)), "{:?}", access);
+
+            let name = Path::new(allowed).file_name().unwrap().to_string_lossy();
+            let outside = resolve(&format!("elsewhere/{}", name), access).unwrap_err();
+            assert!(outside.contains("outside the allowed"), "{:?}: {}", access, outside);
+
+            let traversal = resolve(&format!("projects/../elsewhere/{}", name), access).unwrap_err();
+            assert!(traversal.contains("traversal"), "{:?}: {}", access, traversal);
+
+            if let Some(wrong_type) = wrong_type {
+                let wrong = resolve(wrong_type, access).unwrap_err();
+                assert!(wrong.contains("File type is not allowed"), "{:?}: {}", access, wrong);
+            }
+        }
+
+        // A dialog pick is reported for granting
+        let raw = fx.dir.join("elsewhere/c.stitchalot");
+        let picked = resolve_in_scope(&PathStrategy::Desktop, &raw.to_string_lossy(), PathAccess::ReadProject, &fx.scope, &fx.builtin, &|p| p == raw);
+        assert_eq!(picked, Ok((ResolvedPath::File(raw.clone()), true)));
+    }
+
+    fn strategy_fixture(name: &str) -> (Fixture, PathBuf) {
+        let fx = Fixture::new(name);
+        let documents = fx.dir.join("docs");
+        (fx, documents)
+    }
+
+    #[test]
+    fn test_desktop_strategy() {
+        let strategy = PathStrategy::Desktop;
+        assert_eq!(
+            strategy.resolve("file:///home/me/rose.stitchalot", PathAccess::WriteProject),
+            Ok(ResolvedPath::File(PathBuf::from("/home/me/rose.stitchalot")))
+        );
+        assert!(strategy.resolve("content://docs/rose", PathAccess::ReadProject).is_err());
+    }
+
+    #[test]
+    fn test_ios_strategy_uses_documents() {
+        let (fx, documents) = strategy_fixture("ios");
+        let strategy = PathStrategy::IosSandbox { documents: documents.clone() };
+
+        // Saves and exports always land in Documents, even from a bare file name
+        assert_eq!(
+            strategy.resolve("Rose%20Garden.stitchalot", PathAccess::WriteProject),
+            Ok(ResolvedPath::File(documents.join("Rose Garden.stitchalot")))
+        );
+        assert_eq!(
+            strategy.resolve("/private/tmp/pattern.pdf", PathAccess::WriteExport),
+            Ok(ResolvedPath::File(documents.join("pattern.pdf")))
+        );
+
+        // Reads prefer Documents and fall back to the picked location
+        let picked = fx.dir.join("elsewhere/a.stitchalot");
+        assert_eq!(
+            strategy.resolve(&picked.to_string_lossy(), PathAccess::ReadProject),
+            Ok(ResolvedPath::File(documents.join("a.stitchalot")))
+        );
+        let picked = fx.dir.join("elsewhere/c.stitchalot");
+        assert_eq!(
+            strategy.resolve(&format!("file://{}", picked.to_string_lossy()), PathAccess::ReadProject),
+            Ok(ResolvedPath::File(picked))
+        );
+
+        // Images are read where they were picked
+        let photo = fx.dir.join("elsewhere/photo.png");
+        assert_eq!(
+            strategy.resolve(&photo.to_string_lossy(), PathAccess::ReadImage),
+            Ok(ResolvedPath::File(photo))
+        );
+        assert!(strategy.resolve("../Rose.stitchalot", PathAccess::WriteProject).is_err());
+    }
+
+    #[test]
+    fn test_android_strategy() {
+        let (fx, documents) = strategy_fixture("android");
+        let strategy = PathStrategy::Android { documents: documents.clone() };
+
+        let uri = "content://com.android.providers.downloads.documents/document/msf%3A31";
+        assert_eq!(
+            strategy.resolve(uri, PathAccess::ReadProject),
+            Ok(ResolvedPath::ContentUri(uri.to_string()))
+        );
+        assert!(strategy.resolve(uri, PathAccess::DeleteProject).is_err());
+        assert!(strategy.resolve(uri, PathAccess::ScanDirectory).is_err());
+
+        assert_eq!(
+            strategy.resolve("/sdcard/Download/pattern.pdf", PathAccess::WriteExport),
+            Ok(ResolvedPath::File(documents.join("pattern.pdf")))
+        );
+        let project = fx.dir.join("projects/nested/b.stitchalot");
+        assert_eq!(
+            strategy.resolve(&project.to_string_lossy(), PathAccess::WriteProject),
+            Ok(ResolvedPath::File(project))
+        );
+
+        let resolved = strategy.resolve(uri, PathAccess::ReadImage).unwrap();
+        assert!(resolved.as_path().is_none());
+        assert!(resolved.into_file().is_err());
+    }
+}
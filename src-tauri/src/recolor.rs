@@ -0,0 +1,513 @@
+// Pattern Color Module
+// Replaces, merges and reduces the colors used by a pattern
+
+use crate::threads::appearance::Appearance;
+use crate::threads::blend::ThreadBlend;
+use crate::threads::color_matching::{color_distance, delta_e2000, ColorMatchAlgorithm};
+use crate::threads::conversion::{convert_thread, ConversionSource};
+use crate::threads::{self, ThreadBrand, ThreadColor};
+use crate::{Color, LayerMetadata, NdpFile};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// A palette color that was folded into another
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ColorReplacement {
+    pub from: Color,
+    pub to_id: String,
+    pub stitch_count: usize,
+}
+
+/// What a color operation changed
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ColorChangeSummary {
+    pub replacements: Vec<ColorReplacement>,
+    pub removed: Vec<Color>, // Unused colors dropped from the palette
+    pub stitches_affected: usize,
+    pub colors_before: usize,
+    pub colors_after: usize,
+}
+
+/// A recolored project together with a summary of the changes
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ColorOperationResult {
+    pub project: NdpFile,
+    pub summary: ColorChangeSummary,
+}
+
+/// Number of stitches using each color id, across all layers
+pub(crate) fn stitch_counts(project: &NdpFile) -> HashMap<String, usize> {
+    let mut counts = HashMap::new();
+    for stitch in project.layers.iter().flat_map(|l| &l.stitches) {
+        *counts.entry(stitch.color_id.clone()).or_insert(0) += 1;
+    }
+    counts
+}
+
+/// Point stitches and text layers at new color ids
+pub(crate) fn remap_colors(project: &mut NdpFile, remap: &HashMap<String, String>) {
+    for layer in &mut project.layers {
+        for stitch in &mut layer.stitches {
+            if let Some(to) = remap.get(&stitch.color_id) {
+                stitch.color_id = to.clone();
+            }
+        }
+        if let Some(LayerMetadata::Text { color_id, .. }) = layer.metadata.as_mut() {
+            if let Some(to) = remap.get(color_id.as_str()) {
+                *color_id = to.clone();
+            }
+        }
+    }
+}
+
+/// Replace one palette color with another everywhere it is used
+/// `to` may be an existing palette entry, a new color to add, or an updated version of `from` itself.
+pub fn replace_color(project: &mut NdpFile, from_id: &str, to: Color) -> Result<ColorChangeSummary, String> {
+    let colors_before = project.color_palette.len();
+    let position = project
+        .color_palette
+        .iter()
+        .position(|c| c.id == from_id)
+        .ok_or_else(|| format!("Color not found in palette: {}", from_id))?;
+    let stitch_count = stitch_counts(project).get(from_id).copied().unwrap_or(0);
+
+    let from = if to.id == from_id {
+        std::mem::replace(&mut project.color_palette[position], to.clone())
+    } else {
+        let from = project.color_palette.remove(position);
+        if !project.color_palette.iter().any(|c| c.id == to.id) {
+            project.color_palette.insert(position, to.clone());
+        }
+        remap_colors(project, &HashMap::from([(from.id.clone(), to.id.clone())]));
+        from
+    };
+
+    Ok(ColorChangeSummary {
+        replacements: vec![ColorReplacement { from, to_id: to.id, stitch_count }],
+        removed: Vec::new(),
+        stitches_affected: stitch_count,
+        colors_before,
+        colors_after: project.color_palette.len(),
+    })
+}
+
+/// Repeatedly fold the closest pair of palette colors together while `keep_merging` allows it
+/// The less used color of each pair is folded into the more used one, so every remaining
+/// color is still an original thread. `keep_merging` gets the palette size and the pair's distance.
+fn merge_closest(
+    project: &mut NdpFile,
+    algorithm: ColorMatchAlgorithm,
+    mut keep_merging: impl FnMut(usize, f64) -> bool,
+) -> ColorChangeSummary {
+    let palette = &project.color_palette;
+    let colors_before = palette.len();
+    let original_counts = stitch_counts(project);
+    let mut counts: Vec<usize> = palette
+        .iter()
+        .map(|c| original_counts.get(&c.id).copied().unwrap_or(0))
+        .collect();
+
+    let n = palette.len();
+    let mut distances = vec![0.0; n * n];
+    for i in 0..n {
+        for j in (i + 1)..n {
+            let d = color_distance(palette[i].rgb, palette[j].rgb, algorithm);
+            distances[i * n + j] = d;
+            distances[j * n + i] = d;
+        }
+    }
+
+    let mut alive = vec![true; n];
+    let mut merged_into: Vec<Option<usize>> = vec![None; n];
+    let mut remaining = n;
+    while remaining > 1 {
+        let mut closest: Option<(usize, usize, f64)> = None;
+        for i in (0..n).filter(|&i| alive[i]) {
+            for j in ((i + 1)..n).filter(|&j| alive[j]) {
+                let d = distances[i * n + j];
+                if closest.is_none_or(|(_, _, best)| d < best) {
+                    closest = Some((i, j, d));
+                }
+            }
+        }
+
+        let Some((i, j, d)) = closest else { break };
+        if !keep_merging(remaining, d) {
+            break;
+        }
+
+        // Ties keep the color that appears first in the palette
+        let (keep, drop) = if counts[j] > counts[i] { (j, i) } else { (i, j) };
+        counts[keep] += counts[drop];
+        alive[drop] = false;
+        merged_into[drop] = Some(keep);
+        remaining -= 1;
+    }
+
+    // Follow chains of merges to the surviving color
+    let survivor = |mut index: usize| {
+        while let Some(next) = merged_into[index] {
+            index = next;
+        }
+        index
+    };
+
+    let mut remap = HashMap::new();
+    let mut replacements = Vec::new();
+    for index in (0..n).filter(|&i| !alive[i]) {
+        let from = project.color_palette[index].clone();
+        let to_id = project.color_palette[survivor(index)].id.clone();
+        let stitch_count = original_counts.get(&from.id).copied().unwrap_or(0);
+        remap.insert(from.id.clone(), to_id.clone());
+        replacements.push(ColorReplacement { from, to_id, stitch_count });
+    }
+
+    let mut index = 0;
+    project.color_palette.retain(|_| {
+        index += 1;
+        alive[index - 1]
+    });
+    remap_colors(project, &remap);
+
+    ColorChangeSummary {
+        stitches_affected: replacements.iter().map(|r| r.stitch_count).sum(),
+        replacements,
+        removed: Vec::new(),
+        colors_before,
+        colors_after: project.color_palette.len(),
+    }
+}
+
+/// Merge palette colors that are within `threshold` of each other
+pub fn merge_similar_colors(project: &mut NdpFile, threshold: f64, algorithm: ColorMatchAlgorithm) -> ColorChangeSummary {
+    merge_closest(project, algorithm, |_, distance| distance <= threshold)
+}
+
+/// Reduce the palette to at most `max_colors`, merging the closest colors first
+/// Colors no stitch uses are dropped before any used color is merged.
+pub fn reduce_palette(project: &mut NdpFile, max_colors: usize, algorithm: ColorMatchAlgorithm) -> Result<ColorChangeSummary, String> {
+    if max_colors == 0 {
+        return Err("A pattern needs at least one color".to_string());
+    }
+
+    let colors_before = project.color_palette.len();
+    let counts = stitch_counts(project);
+    let mut removed: Vec<Color> = Vec::new();
+    let mut excess = colors_before.saturating_sub(max_colors);
+    project.color_palette.retain(|c| {
+        if excess > 0 && !counts.contains_key(&c.id) {
+            excess -= 1;
+            removed.push(c.clone());
+            return false;
+        }
+        true
+    });
+
+    let mut summary = merge_closest(project, algorithm, |remaining, _| remaining > max_colors);
+    summary.colors_before = colors_before;
+    summary.removed = removed;
+    Ok(summary)
+}
+
+/// How one palette color was matched to a thread in the target brand
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ThreadRematch {
+    pub color_id: String,
+    pub from: Color,
+    pub to: Color,
+    pub distance: f64, // In the units of the chosen algorithm
+    pub delta_e: f64,  // CIEDE2000, comparable across algorithms
+    pub source: ConversionSource,
+}
+
+/// Palette colors that ended up on the same thread
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ThreadCollision {
+    pub thread_code: String, // "first+second" for blends
+    pub color_ids: Vec<String>,
+}
+
+/// Result of rematching a palette to another brand
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RematchResult {
+    pub project: NdpFile,
+    pub brand: ThreadBrand,
+    pub matches: Vec<ThreadRematch>,
+    pub collisions: Vec<ThreadCollision>,
+}
+
+/// Closest thread to `rgb` among `candidates`
+fn closest_thread(rgb: [u8; 3], candidates: &[ThreadColor], algorithm: ColorMatchAlgorithm) -> Option<(&ThreadColor, f64)> {
+    candidates
+        .iter()
+        .map(|t| (t, color_distance(rgb, t.rgb, algorithm)))
+        .min_by(|a, b| a.1.total_cmp(&b.1))
+}
+
+/// Convert a palette color's thread, and its second strand if it is a blend, to `brand`
+/// None if the color has no known thread or a strand can't be converted.
+fn convert_strands(color: &Color, brand: &ThreadBrand, algorithm: ColorMatchAlgorithm) -> Option<(ThreadColor, Option<ThreadColor>, ConversionSource)> {
+    let from_brand = color.thread_brand.as_deref()?.parse::<ThreadBrand>().ok()?;
+    let convert = |code: &str| convert_thread(&from_brand, code, brand, algorithm).ok();
+
+    let first = convert(color.thread_code.as_deref()?)?;
+    let second = match color.blend_thread_code.as_deref() {
+        Some(code) => Some(convert(code)?),
+        None => None,
+    };
+
+    let official = first.source == ConversionSource::Official
+        && second.as_ref().is_none_or(|s| s.source == ConversionSource::Official);
+    let source = if official { ConversionSource::Official } else { ConversionSource::Nearest };
+    Some((first.to, second.map(|s| s.to), source))
+}
+
+/// Map every palette color to a thread of `brand`
+/// Colors with a known thread use its published equivalent when there is one, otherwise
+/// the closest thread. Blends convert strand by strand and stay blends. Colors keep their
+/// ids and symbols, so stitches are untouched. Colors that land on the same thread are
+/// reported as collisions rather than merged.
+pub fn rematch_palette(project: &mut NdpFile, brand: &ThreadBrand, algorithm: ColorMatchAlgorithm) -> Result<(Vec<ThreadRematch>, Vec<ThreadCollision>), String> {
+    let candidates = threads::get_threads_by_brand(brand);
+    let mut matches = Vec::with_capacity(project.color_palette.len());
+    let mut by_code: Vec<(String, Vec<String>)> = Vec::new();
+
+    for color in &mut project.color_palette {
+        let (thread, second, source) = match convert_strands(color, brand, algorithm) {
+            Some(converted) => converted,
+            None => {
+                let (thread, _) = closest_thread(color.rgb, &candidates, algorithm)
+                    .ok_or_else(|| format!("No {} threads available", brand))?;
+                (thread.clone(), None, ConversionSource::Nearest)
+            }
+        };
+
+        // Strands that convert to the same thread are no longer a blend
+        let blend = second
+            .filter(|second| second.code != thread.code)
+            .map(|second| ThreadBlend::new(thread.clone(), second, &Appearance::default()));
+
+        let from = color.clone();
+        color.thread_brand = Some(brand.to_string());
+        color.thread_code = Some(thread.code.clone());
+        let code = match &blend {
+            Some(blend) => {
+                color.name = blend.name();
+                color.rgb = blend.catalog_rgb();
+                color.blend_thread_code = Some(blend.second.code.clone());
+                format!("{}+{}", thread.code, blend.second.code)
+            }
+            None => {
+                color.name = thread.name.clone();
+                color.rgb = thread.rgb;
+                color.blend_thread_code = None;
+                thread.code.clone()
+            }
+        };
+
+        match by_code.iter_mut().find(|(existing, _)| *existing == code) {
+            Some((_, ids)) => ids.push(color.id.clone()),
+            None => by_code.push((code, vec![color.id.clone()])),
+        }
+        matches.push(ThreadRematch {
+            color_id: color.id.clone(),
+            distance: color_distance(from.rgb, color.rgb, algorithm),
+            delta_e: delta_e2000(from.rgb, color.rgb),
+            from,
+            to: color.clone(),
+            source,
+        });
+    }
+
+    let collisions = by_code
+        .into_iter()
+        .filter(|(_, ids)| ids.len() > 1)
+        .map(|(thread_code, color_ids)| ThreadCollision { thread_code, color_ids })
+        .collect();
+
+    Ok((matches, collisions))
+}
+
+/// Tauri commands for pattern color operations
+pub mod commands {
+    use super::*;
+
+    /// Replace a palette color with another color across every layer
+    #[tauri::command]
+    pub fn replace_color(mut project: NdpFile, from_id: String, to: Color) -> Result<ColorOperationResult, String> {
+        let summary = super::replace_color(&mut project, &from_id, to)?;
+        Ok(ColorOperationResult { project, summary })
+    }
+
+    /// Merge palette colors closer than `threshold` (Delta E for the CIE algorithms)
+    #[tauri::command]
+    pub fn merge_similar_colors(
+        mut project: NdpFile,
+        threshold: f64,
+        algorithm: Option<ColorMatchAlgorithm>,
+    ) -> Result<ColorOperationResult, String> {
+        if !threshold.is_finite() || threshold < 0.0 {
+            return Err(format!("Invalid merge threshold: {}", threshold));
+        }
+        let summary = super::merge_similar_colors(&mut project, threshold, algorithm.unwrap_or_default());
+        Ok(ColorOperationResult { project, summary })
+    }
+
+    /// Reduce the pattern to at most `max_colors` colors
+    #[tauri::command]
+    pub fn reduce_palette(
+        mut project: NdpFile,
+        max_colors: usize,
+        algorithm: Option<ColorMatchAlgorithm>,
+    ) -> Result<ColorOperationResult, String> {
+        let summary = super::reduce_palette(&mut project, max_colors, algorithm.unwrap_or_default())?;
+        Ok(ColorOperationResult { project, summary })
+    }
+
+    /// Convert the palette to another thread brand
+    #[tauri::command]
+    pub fn rematch_palette(
+        mut project: NdpFile,
+        brand: ThreadBrand,
+        algorithm: Option<ColorMatchAlgorithm>,
+    ) -> Result<RematchResult, String> {
+        let (matches, collisions) = super::rematch_palette(&mut project, &brand, algorithm.unwrap_or_default())?;
+        Ok(RematchResult { project, brand, matches, collisions })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures;
+    use crate::Stitch;
+
+    fn color(id: &str, rgb: [u8; 3]) -> Color {
+        fixtures::thread_color(id, "DMC", id, rgb)
+    }
+
+    /// A pattern using each color id in `uses` once, on a single row
+    fn project(palette: Vec<Color>, uses: &[&str]) -> NdpFile {
+        let stitches = uses
+            .iter()
+            .enumerate()
+            .map(|(x, id)| Stitch { completed: x == 0, ..fixtures::stitch(x as u32, 0, id) })
+            .collect();
+        fixtures::project(uses.len() as u32, 1, palette, stitches)
+    }
+
+    fn used_ids(project: &NdpFile) -> Vec<&str> {
+        project.layers[0].stitches.iter().map(|s| s.color_id.as_str()).collect()
+    }
+
+    #[test]
+    fn test_replace_color() {
+        let mut pattern = project(vec![color("red", [200, 0, 0]), color("blue", [0, 0, 200])], &["red", "blue", "red"]);
+
+        let summary = replace_color(&mut pattern, "red", color("blue", [0, 0, 200])).unwrap();
+        assert_eq!(summary.stitches_affected, 2);
+        assert_eq!((summary.colors_before, summary.colors_after), (2, 1));
+        assert_eq!(used_ids(&pattern), vec!["blue", "blue", "blue"]);
+        assert!(pattern.layers[0].stitches[0].completed);
+
+        // Replacing with a new color adds it in the old color's place
+        let summary = replace_color(&mut pattern, "blue", color("green", [0, 150, 0])).unwrap();
+        assert_eq!(summary.stitches_affected, 3);
+        assert_eq!(pattern.color_palette[0].id, "green");
+        assert!(replace_color(&mut pattern, "missing", color("x", [0, 0, 0])).is_err());
+    }
+
+    #[test]
+    fn test_merge_similar_keeps_most_used() {
+        let palette = vec![color("red", [200, 0, 0]), color("red2", [202, 2, 0]), color("blue", [0, 0, 200])];
+        let mut pattern = project(palette, &["red2", "red", "red2", "blue"]);
+
+        let summary = merge_similar_colors(&mut pattern, 2.0, ColorMatchAlgorithm::Ciede2000);
+        assert_eq!(summary.replacements.len(), 1);
+        assert_eq!(summary.replacements[0].from.id, "red");
+        assert_eq!(summary.replacements[0].to_id, "red2");
+        assert_eq!(summary.stitches_affected, 1);
+        assert_eq!(used_ids(&pattern), vec!["red2", "red2", "red2", "blue"]);
+        assert_eq!(pattern.color_palette.len(), 2);
+    }
+
+    #[test]
+    fn test_reduce_palette() {
+        let palette = vec![
+            color("red", [200, 0, 0]),
+            color("spare", [50, 50, 50]),
+            color("pink", [220, 60, 60]),
+            color("blue", [0, 0, 200]),
+            color("navy", [0, 0, 120]),
+        ];
+        let mut pattern = project(palette, &["red", "red", "pink", "blue", "navy", "blue"]);
+
+        let summary = reduce_palette(&mut pattern, 2, ColorMatchAlgorithm::Ciede2000).unwrap();
+        assert_eq!((summary.colors_before, summary.colors_after), (5, 2));
+        assert_eq!(summary.removed[0].id, "spare");
+        assert_eq!(summary.replacements.len(), 2);
+        assert_eq!(summary.stitches_affected, 2);
+        assert_eq!(used_ids(&pattern), vec!["red", "red", "red", "blue", "blue", "blue"]);
+
+        assert!(reduce_palette(&mut pattern, 0, ColorMatchAlgorithm::Ciede2000).is_err());
+    }
+
+    #[test]
+    fn test_rematch_palette() {
+        let palette = vec![
+            color("310", [0, 0, 0]),
+            color("3371", [30, 17, 8]),
+            Color { thread_brand: Some("Anchor".to_string()), thread_code: Some("403".to_string()), ..color("a", [0, 0, 0]) },
+        ];
+        let mut pattern = project(palette, &["310", "3371", "a"]);
+
+        let (matches, collisions) = rematch_palette(&mut pattern, &ThreadBrand::Anchor, ColorMatchAlgorithm::Ciede2000).unwrap();
+        assert_eq!(matches.len(), 3);
+        assert!(pattern.color_palette.iter().all(|c| c.thread_brand.as_deref() == Some("Anchor")));
+        assert_eq!(pattern.color_palette[0].id, "310");
+        assert_eq!(used_ids(&pattern), vec!["310", "3371", "a"]);
+
+        // Charted DMC threads use the published equivalent
+        assert_eq!(matches[0].source, ConversionSource::Official);
+
+        // Already an Anchor thread, so it is kept as is
+        assert_eq!(matches[2].to.thread_code.as_deref(), Some("403"));
+        assert_eq!(matches[2].distance, 0.0);
+
+        // Black DMC 310 lands on the same Anchor thread as the existing one
+        let black = matches[0].to.thread_code.clone().unwrap();
+        assert!(collisions.iter().any(|c| c.thread_code == black && c.color_ids.contains(&"310".to_string())));
+    }
+
+    #[test]
+    fn test_rematch_palette_keeps_blends() {
+        let red = threads::find_thread(&ThreadBrand::DMC, "321").unwrap();
+        let dark_red = threads::find_thread(&ThreadBrand::DMC, "498").unwrap();
+        let blended = Color {
+            blend_thread_code: Some("498".to_string()),
+            ..color("321", threads::blend::blend_rgb(red.rgb, dark_red.rgb))
+        };
+        let mut pattern = project(vec![blended, color("498", dark_red.rgb)], &["321", "498"]);
+
+        let (matches, collisions) = rematch_palette(&mut pattern, &ThreadBrand::Anchor, ColorMatchAlgorithm::Ciede2000).unwrap();
+        let convert = |code: &str| convert_thread(&ThreadBrand::DMC, code, &ThreadBrand::Anchor, ColorMatchAlgorithm::Ciede2000).unwrap();
+        let (first, second) = (convert("321"), convert("498"));
+        let official = first.source == ConversionSource::Official && second.source == ConversionSource::Official;
+        let (first, second) = (first.to, second.to);
+        assert_ne!(first.code, second.code);
+
+        // Both strands are converted and the color is the mix of the new pair
+        let to = &matches[0].to;
+        assert_eq!(to.thread_code.as_deref(), Some(first.code.as_str()));
+        assert_eq!(to.blend_thread_code.as_deref(), Some(second.code.as_str()));
+        assert_eq!(to.rgb, threads::blend::blend_rgb(first.rgb, second.rgb));
+        assert_eq!(to.name, format!("{} + {}", first.name, second.name));
+        assert_eq!(matches[0].from.blend_thread_code.as_deref(), Some("498"));
+        assert_eq!(matches[0].source == ConversionSource::Official, official);
+
+        // A blend sharing a strand with a single thread is not a collision
+        assert_eq!(matches[1].to.thread_code.as_deref(), Some(second.code.as_str()));
+        assert!(matches[1].to.blend_thread_code.is_none());
+        assert!(collisions.is_empty());
+    }
+}
@@ -0,0 +1,161 @@
+// Thread Appearance Module
+// How stitched threads look under a given light, including the sheen of metallics
+
+use super::color_matching::{lab_to_rgb, rgb_to_lab, rgb_to_xyz, xyz_to_rgb, Lab, D65_WHITE};
+use super::{ThreadColor, ThreadSpecialty};
+use serde::{Deserialize, Serialize};
+
+/// Share of the light's color cast the eye discounts (1.0 would cancel it completely)
+const ADAPTATION_DEGREE: f64 = 0.7;
+
+/// Bradford cone response matrix and its inverse
+const BRADFORD: [[f64; 3]; 3] = [
+    [0.8951, 0.2664, -0.1614],
+    [-0.7502, 1.7135, 0.0367],
+    [0.0389, -0.0685, 1.0296],
+];
+const BRADFORD_INVERSE: [[f64; 3]; 3] = [
+    [0.9869929, -0.1470543, 0.1599627],
+    [0.4323053, 0.5183603, 0.0492912],
+    [-0.0085287, 0.0400428, 0.9684867],
+];
+
+/// Light the finished canvas is viewed under
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum Illuminant {
+    #[default]
+    D65, // Daylight, the reference for sRGB
+    D50, // Horizon daylight, used for print viewing booths
+    A,   // Incandescent bulb
+}
+
+impl Illuminant {
+    /// XYZ white point (2° observer)
+    pub fn white(self) -> (f64, f64, f64) {
+        match self {
+            Illuminant::D65 => D65_WHITE,
+            Illuminant::D50 => (96.422, 100.000, 82.521),
+            Illuminant::A => (109.850, 100.000, 35.585),
+        }
+    }
+}
+
+/// Lightness and chroma scaling applied to a thread's catalog color
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct SheenAdjustment {
+    pub lightness: f64,
+    pub chroma: f64,
+}
+
+impl SheenAdjustment {
+    /// No adjustment
+    pub const FLAT: SheenAdjustment = SheenAdjustment { lightness: 1.0, chroma: 1.0 };
+
+    /// Metallic catalog colors show the highlight; from a stitching distance the
+    /// thread reads darker and less saturated (bright gold looks like old gold).
+    pub const METALLIC: SheenAdjustment = SheenAdjustment { lightness: 0.8, chroma: 0.75 };
+
+    pub fn apply(&self, rgb: [u8; 3]) -> [u8; 3] {
+        if *self == SheenAdjustment::FLAT {
+            return rgb;
+        }
+        let lab = rgb_to_lab(rgb);
+        lab_to_rgb(Lab {
+            l: (lab.l * self.lightness).clamp(0.0, 100.0),
+            a: lab.a * self.chroma,
+            b: lab.b * self.chroma,
+        })
+    }
+}
+
+fn default_metallic() -> SheenAdjustment {
+    SheenAdjustment::METALLIC
+}
+
+/// Viewing conditions used when matching and previewing
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Appearance {
+    #[serde(default)]
+    pub illuminant: Illuminant,
+    #[serde(default = "default_metallic")]
+    pub metallic: SheenAdjustment,
+}
+
+impl Default for Appearance {
+    fn default() -> Self {
+        Appearance { illuminant: Illuminant::D65, metallic: SheenAdjustment::METALLIC }
+    }
+}
+
+impl Appearance {
+    /// The color a thread shows on the canvas under these conditions
+    pub fn thread_rgb(&self, thread: &ThreadColor) -> [u8; 3] {
+        let rgb = match thread.specialty {
+            Some(ThreadSpecialty::Metallic) => self.metallic.apply(thread.rgb),
+            _ => thread.rgb,
+        };
+        under_illuminant(rgb, self.illuminant)
+    }
+}
+
+fn multiply(m: &[[f64; 3]; 3], v: [f64; 3]) -> [f64; 3] {
+    m.map(|row| row[0] * v[0] + row[1] * v[1] + row[2] * v[2])
+}
+
+/// Bradford chromatic adaptation of an XYZ color from one white point to another
+pub fn adapt(xyz: (f64, f64, f64), from: (f64, f64, f64), to: (f64, f64, f64)) -> (f64, f64, f64) {
+    let cone = multiply(&BRADFORD, [xyz.0, xyz.1, xyz.2]);
+    let from = multiply(&BRADFORD, [from.0, from.1, from.2]);
+    let to = multiply(&BRADFORD, [to.0, to.1, to.2]);
+    let scaled = [0, 1, 2].map(|i| cone[i] * to[i] / from[i]);
+    let [x, y, z] = multiply(&BRADFORD_INVERSE, scaled);
+    (x, y, z)
+}
+
+/// How an sRGB surface color looks under `illuminant` to a viewer who has mostly adapted to it
+pub fn under_illuminant(rgb: [u8; 3], illuminant: Illuminant) -> [u8; 3] {
+    if illuminant == Illuminant::D65 {
+        return rgb;
+    }
+    let target = illuminant.white();
+    let lerp = |d65: f64, light: f64| d65 + (light - d65) * (1.0 - ADAPTATION_DEGREE);
+    let white = (lerp(D65_WHITE.0, target.0), lerp(D65_WHITE.1, target.1), lerp(D65_WHITE.2, target.2));
+    xyz_to_rgb(adapt(rgb_to_xyz(rgb), D65_WHITE, white))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::threads::color_matching::delta_e2000;
+    use crate::threads::{find_thread, ThreadBrand};
+
+    #[test]
+    fn test_thread_appearance() {
+        // Adapting to the same white is a no-op; whites follow the light
+        let xyz = rgb_to_xyz([120, 60, 200]);
+        let same = adapt(xyz, D65_WHITE, D65_WHITE);
+        assert!((same.0 - xyz.0).abs() < 1e-4 && (same.2 - xyz.2).abs() < 1e-4);
+        let a = adapt(D65_WHITE, D65_WHITE, Illuminant::A.white());
+        assert!((a.0 - 109.850).abs() < 0.01 && (a.2 - 35.585).abs() < 0.01);
+
+        assert_eq!(under_illuminant([200, 100, 50], Illuminant::D65), [200, 100, 50]);
+        let warm = under_illuminant([230, 230, 230], Illuminant::A);
+        assert!(warm[0] > warm[2]);
+        let d50 = under_illuminant([230, 230, 230], Illuminant::D50);
+        assert!(d50[0] > d50[2] && d50[0] - d50[2] < warm[0] - warm[2]);
+
+        let gold = find_thread(&ThreadBrand::Kreinik, "002").unwrap();
+        let floss = find_thread(&ThreadBrand::DMC, "310").unwrap();
+        let appearance = Appearance::default();
+        assert_eq!(appearance.thread_rgb(&floss), floss.rgb);
+        let seen = appearance.thread_rgb(&gold);
+        assert!(rgb_to_lab(seen).l < rgb_to_lab(gold.rgb).l);
+        assert!(delta_e2000(seen, gold.rgb) > 5.0);
+
+        let flat = Appearance { metallic: SheenAdjustment::FLAT, ..appearance };
+        assert_eq!(flat.thread_rgb(&gold), gold.rgb);
+        let json: Appearance = serde_json::from_str(r#"{"illuminant": "a"}"#).unwrap();
+        assert_eq!(json, Appearance { illuminant: Illuminant::A, ..appearance });
+    }
+}
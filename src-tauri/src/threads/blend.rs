@@ -0,0 +1,135 @@
+// Thread Blend Module
+// Two threads stitched in one needle ("tweeding"), modeled as their averaged color
+
+use super::appearance::Appearance;
+use super::color_matching::{color_distance, delta_e2000, ColorMatchAlgorithm};
+use super::ThreadColor;
+use serde::{Deserialize, Serialize};
+
+/// Margin a blend must beat the best single thread by when none is given
+/// Measured with the matching algorithm, so it is a Delta E for the perceptual ones.
+pub const DEFAULT_BLEND_MARGIN: f64 = 2.0;
+
+/// Threads farther apart than this (CIEDE2000) read as speckles rather than a blend
+const MAX_COMPONENT_DELTA_E: f64 = 25.0;
+
+/// Two threads sharing a needle
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ThreadBlend {
+    pub first: ThreadColor,
+    pub second: ThreadColor,
+    pub rgb: [u8; 3], // Perceived color of the pair
+}
+
+impl ThreadBlend {
+    pub fn new(first: ThreadColor, second: ThreadColor, appearance: &Appearance) -> Self {
+        let rgb = blend_rgb(appearance.thread_rgb(&first), appearance.thread_rgb(&second));
+        ThreadBlend { first, second, rgb }
+    }
+
+    /// Mix of the two catalog colors, ignoring viewing conditions
+    pub fn catalog_rgb(&self) -> [u8; 3] {
+        blend_rgb(self.first.rgb, self.second.rgb)
+    }
+
+    /// Identifier in the same "{brand}-{code}" form used for single threads
+    pub fn id(&self) -> String {
+        format!("{}-{}+{}", self.first.brand, self.first.code, self.second.code)
+    }
+
+    pub fn name(&self) -> String {
+        format!("{} + {}", self.first.name, self.second.name)
+    }
+}
+
+fn to_linear(c: u8) -> f64 {
+    let c = c as f64 / 255.0;
+    if c > 0.04045 {
+        ((c + 0.055) / 1.055).powf(2.4)
+    } else {
+        c / 12.92
+    }
+}
+
+fn to_srgb(c: f64) -> u8 {
+    let c = if c > 0.0031308 {
+        1.055 * c.powf(1.0 / 2.4) - 0.055
+    } else {
+        12.92 * c
+    };
+    (c * 255.0).round().clamp(0.0, 255.0) as u8
+}
+
+/// Equal mix of two colors, averaged in linear light as the eye does at stitch scale
+pub fn blend_rgb(a: [u8; 3], b: [u8; 3]) -> [u8; 3] {
+    std::array::from_fn(|i| to_srgb((to_linear(a[i]) + to_linear(b[i])) / 2.0))
+}
+
+/// Every pair of distinct threads close enough to blend, as seen under `appearance`
+pub fn blend_candidates(threads: &[ThreadColor], appearance: &Appearance) -> Vec<ThreadBlend> {
+    let seen: Vec<[u8; 3]> = threads.iter().map(|t| appearance.thread_rgb(t)).collect();
+    let mut blends = Vec::new();
+    for (i, first) in threads.iter().enumerate() {
+        for (j, second) in threads.iter().enumerate().skip(i + 1) {
+            if seen[i] != seen[j] && delta_e2000(seen[i], seen[j]) <= MAX_COMPONENT_DELTA_E {
+                blends.push(ThreadBlend::new(first.clone(), second.clone(), appearance));
+            }
+        }
+    }
+    blends
+}
+
+/// Closest blend to `target` under `algorithm`, with its distance
+pub fn find_closest_blend(target: [u8; 3], blends: &[ThreadBlend], algorithm: ColorMatchAlgorithm) -> Option<(&ThreadBlend, f64)> {
+    blends
+        .iter()
+        .map(|blend| (blend, color_distance(target, blend.rgb, algorithm)))
+        .min_by(|a, b| a.1.total_cmp(&b.1))
+}
+
+/// The closest blend, if it beats `single` (the best single thread color) by at least `margin`
+/// Both the pick and the margin use `algorithm`'s distance.
+pub fn better_blend(
+    target: [u8; 3],
+    single: [u8; 3],
+    blends: &[ThreadBlend],
+    algorithm: ColorMatchAlgorithm,
+    margin: f64,
+) -> Option<&ThreadBlend> {
+    let (blend, distance) = find_closest_blend(target, blends, algorithm)?;
+    (distance + margin <= color_distance(target, single, algorithm)).then_some(blend)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::threads::{find_thread, ThreadBrand};
+
+    #[test]
+    fn test_thread_blends() {
+        assert_eq!(blend_rgb([0, 0, 0], [0, 0, 0]), [0, 0, 0]);
+        assert_eq!(blend_rgb([255, 0, 0], [0, 0, 255]), [188, 0, 188]);
+        assert_eq!(blend_rgb([0, 0, 0], [255, 255, 255]), blend_rgb([255, 255, 255], [0, 0, 0]));
+
+        let red = find_thread(&ThreadBrand::DMC, "321").unwrap();
+        let dark_red = find_thread(&ThreadBrand::DMC, "498").unwrap();
+        let white = find_thread(&ThreadBrand::DMC, "White").unwrap();
+        let blends = blend_candidates(&[red.clone(), dark_red.clone(), white.clone()], &Appearance::default());
+        assert_eq!(blends.len(), 1);
+        assert_eq!(blends[0].id(), "DMC-321+498");
+        assert_eq!(blends[0].rgb, blends[0].catalog_rgb());
+
+        // A color halfway between the two reds is matched by the blend, but only with a small enough margin
+        let target = blends[0].rgb;
+        let single = if delta_e2000(target, red.rgb) < delta_e2000(target, dark_red.rgb) { red.rgb } else { dark_red.rgb };
+        assert!(better_blend(target, single, &blends, ColorMatchAlgorithm::Ciede2000, 1.0).is_some());
+        assert!(better_blend(target, single, &blends, ColorMatchAlgorithm::Ciede2000, 50.0).is_none());
+        assert!(better_blend(red.rgb, red.rgb, &blends, ColorMatchAlgorithm::Ciede2000, 0.0).is_none());
+
+        // The margin is in the units of the matching algorithm, not always CIEDE2000
+        let euclidean = ColorMatchAlgorithm::Euclidean;
+        let gap = color_distance(target, single, euclidean) - color_distance(target, blends[0].rgb, euclidean);
+        assert!(better_blend(target, single, &blends, euclidean, gap - 1e-9).is_some());
+        assert!(better_blend(target, single, &blends, euclidean, gap + 0.01).is_none());
+    }
+}
@@ -0,0 +1,184 @@
+// Built-in Thread Data Module
+// Thread libraries embedded from the JSON files in src/data/threads
+
+use super::{ThreadBrand, ThreadColor, ThreadSpecialty};
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::sync::OnceLock;
+
+/// Embedded thread data for every built-in brand
+const THREAD_DATA: [(ThreadBrand, &str); 3] = [
+    (ThreadBrand::DMC, include_str!("../../../src/data/threads/dmc.json")),
+    (ThreadBrand::Anchor, include_str!("../../../src/data/threads/anchor.json")),
+    (ThreadBrand::Kreinik, include_str!("../../../src/data/threads/kreinik.json")),
+];
+
+/// Fiber, weight and skein size per brand and product line
+const LINE_DATA: &str = include_str!("../../../src/data/threads/lines.json");
+
+/// Parsed libraries, built on first use
+static REGISTRY: OnceLock<HashMap<ThreadBrand, BrandLibrary>> = OnceLock::new();
+
+/// One thread as stored in the data files
+#[derive(Deserialize)]
+struct ThreadRecord {
+    code: String,
+    name: String,
+    rgb: [u8; 3],
+    #[serde(default)]
+    category: Option<String>,
+    #[serde(default)]
+    color_family: Option<String>,
+    #[serde(default)]
+    specialty: Option<ThreadSpecialty>,
+    #[serde(default)]
+    discontinued: bool,
+    #[serde(default)]
+    replacement: Option<String>,
+}
+
+/// Physical details shared by a product line
+#[derive(Clone, Default, Deserialize)]
+struct LineInfo {
+    fiber: Option<String>,
+    weight: Option<String>,
+    strands: Option<u8>,
+    skein_length: Option<f64>,
+}
+
+/// Lines keyed by thread category; "*" covers categories without their own entry
+type BrandLines = HashMap<String, LineInfo>;
+
+/// One brand's threads with an index by code
+pub struct BrandLibrary {
+    pub threads: Vec<ThreadColor>,
+    by_code: HashMap<String, usize>, // Lowercase code -> index in threads
+}
+
+impl BrandLibrary {
+    fn parse(brand: &ThreadBrand, json: &str, lines: &BrandLines) -> Result<Self, String> {
+        let records: Vec<ThreadRecord> = serde_json::from_str(json).map_err(|e| e.to_string())?;
+
+        let mut threads = Vec::with_capacity(records.len());
+        let mut by_code = HashMap::with_capacity(records.len());
+        for record in records {
+            if by_code.insert(record.code.to_lowercase(), threads.len()).is_some() {
+                return Err(format!("duplicate code {}", record.code));
+            }
+            let line = record
+                .category
+                .as_ref()
+                .and_then(|c| lines.get(c))
+                .or_else(|| lines.get("*"))
+                .cloned()
+                .unwrap_or_default();
+            threads.push(ThreadColor {
+                code: record.code,
+                name: record.name,
+                rgb: record.rgb,
+                brand: brand.clone(),
+                category: record.category,
+                fiber: line.fiber,
+                skein_length: line.skein_length,
+                weight: line.weight,
+                strands: line.strands,
+                color_family: record.color_family,
+                specialty: record.specialty,
+                discontinued: record.discontinued,
+                replacement: record.replacement,
+            });
+        }
+
+        for thread in threads.iter().filter(|t| t.discontinued) {
+            match &thread.replacement {
+                Some(code) if by_code.contains_key(&code.to_lowercase()) => {}
+                _ => return Err(format!("discontinued {} has no valid replacement", thread.code)),
+            }
+        }
+
+        Ok(BrandLibrary { threads, by_code })
+    }
+
+    pub fn find(&self, code: &str) -> Option<&ThreadColor> {
+        self.by_code.get(&code.trim().to_lowercase()).map(|&i| &self.threads[i])
+    }
+}
+
+fn registry() -> &'static HashMap<ThreadBrand, BrandLibrary> {
+    REGISTRY.get_or_init(|| {
+        // The data is embedded at compile time, so a bad file is a build defect
+        let mut lines: HashMap<String, BrandLines> =
+            serde_json::from_str(LINE_DATA).unwrap_or_else(|e| panic!("Invalid thread line data: {}", e));
+        THREAD_DATA
+            .iter()
+            .map(|(brand, json)| {
+                let brand_lines = lines.remove(brand.as_str()).unwrap_or_default();
+                let library = BrandLibrary::parse(brand, json, &brand_lines)
+                    .unwrap_or_else(|e| panic!("Invalid {} thread data: {}", brand, e));
+                (brand.clone(), library)
+            })
+            .collect()
+    })
+}
+
+/// The built-in library for a brand (None for custom libraries)
+pub fn library(brand: &ThreadBrand) -> Option<&'static BrandLibrary> {
+    registry().get(brand)
+}
+
+/// Threads of a built-in brand (empty for custom libraries)
+pub fn threads(brand: &ThreadBrand) -> &'static [ThreadColor] {
+    library(brand).map(|l| l.threads.as_slice()).unwrap_or(&[])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_embedded_data() {
+        for brand in ThreadBrand::ALL {
+            let library = library(&brand).unwrap();
+            assert!(!library.threads.is_empty(), "{} has no threads", brand);
+            assert_eq!(library.by_code.len(), library.threads.len());
+        }
+
+        let dmc = library(&ThreadBrand::DMC).unwrap();
+        assert_eq!(dmc.find("310").unwrap().name, "Black");
+        assert_eq!(dmc.find("ecru").unwrap().code, "Ecru");
+        assert_eq!(dmc.find("310").unwrap().category.as_deref(), Some("solid"));
+        assert!(dmc.find("no-such-code").is_none());
+        assert_eq!(dmc.find("310").unwrap().color_family.as_deref(), Some("black"));
+        assert_eq!(dmc.find("310").unwrap().strands, Some(6));
+        assert_eq!(dmc.find("E940").unwrap().specialty, Some(ThreadSpecialty::Glow));
+        assert_eq!(dmc.find("E940").unwrap().fiber.as_deref(), Some("polyester"));
+
+        let retired = dmc.find("504").unwrap();
+        assert!(retired.discontinued);
+        assert!(!dmc.find(retired.replacement.as_deref().unwrap()).unwrap().discontinued);
+
+        let kreinik = library(&ThreadBrand::Kreinik).unwrap();
+        assert!(kreinik.threads.iter().all(|t| t.is_specialty() && t.skein_length.is_some()));
+        assert!(library(&ThreadBrand::Custom("Silk & Ivory".to_string())).is_none());
+
+        let lines = BrandLines::new();
+        assert!(BrandLibrary::parse(&ThreadBrand::DMC, r#"[{"code": "1", "name": "A", "rgb": [0, 0, 0]}, {"code": "1", "name": "B", "rgb": [1, 1, 1]}]"#, &lines).is_err());
+        assert!(BrandLibrary::parse(&ThreadBrand::DMC, r#"[{"code": "1", "name": "A", "rgb": [0, 0, 0], "discontinued": true}]"#, &lines).is_err());
+    }
+
+    #[test]
+    fn test_codes_unique_per_brand() {
+        // A repeated code would shadow the earlier thread in the code index
+        for (brand, json) in THREAD_DATA {
+            let records: Vec<ThreadRecord> = serde_json::from_str(json).unwrap();
+            let mut seen = std::collections::HashSet::new();
+            for record in &records {
+                assert!(seen.insert(record.code.to_lowercase()), "{} {} is listed twice", brand, record.code);
+            }
+        }
+
+        // DMC 938 converts to Anchor 381, so 381 keeps the charted dark chocolate
+        let anchor = library(&ThreadBrand::Anchor).unwrap();
+        assert_eq!(anchor.find("381").unwrap().name, "Chocolate Very Dark");
+    }
+}
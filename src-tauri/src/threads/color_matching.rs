@@ -12,9 +12,16 @@ pub enum ColorMatchAlgorithm {
     #[serde(rename = "cie76")]
     Cie76,
     #[serde(rename = "cie94")]
-    Cie94,
+    Cie94, // Textile weights
+    #[serde(rename = "cie94-graphic-arts")]
+    Cie94GraphicArts,
     #[serde(rename = "ciede2000")]
     Ciede2000,
+    #[serde(rename = "ciede2000-textiles")]
+    Ciede2000Textiles, // kL = 2
+    Cmc, // 2:1
+    #[serde(rename = "cmc-1:1")]
+    Cmc11,
 }
 
 impl Default for ColorMatchAlgorithm {
@@ -31,8 +38,14 @@ pub struct Lab {
     pub b: f64,
 }
 
+/// D65 reference white, the white point of sRGB
+pub const D65_WHITE: (f64, f64, f64) = (95.047, 100.000, 108.883);
+
+const EPSILON: f64 = 0.008856;
+const KAPPA: f64 = 903.3;
+
 /// Convert RGB to XYZ color space (D65 illuminant)
-fn rgb_to_xyz(rgb: [u8; 3]) -> (f64, f64, f64) {
+pub fn rgb_to_xyz(rgb: [u8; 3]) -> (f64, f64, f64) {
     // Normalize RGB values
     let mut r = rgb[0] as f64 / 255.0;
     let mut g = rgb[1] as f64 / 255.0;
@@ -68,21 +81,35 @@ fn rgb_to_xyz(rgb: [u8; 3]) -> (f64, f64, f64) {
     (x, y, z)
 }
 
-/// Convert XYZ to LAB color space (D65 illuminant)
-fn xyz_to_lab(xyz: (f64, f64, f64)) -> Lab {
-    // D65 reference white
-    const REF_X: f64 = 95.047;
-    const REF_Y: f64 = 100.000;
-    const REF_Z: f64 = 108.883;
+/// Convert XYZ back to 8-bit sRGB, clipping colors outside the gamut
+pub fn xyz_to_rgb(xyz: (f64, f64, f64)) -> [u8; 3] {
+    let (x, y, z) = (xyz.0 / 100.0, xyz.1 / 100.0, xyz.2 / 100.0);
+
+    // Inverse sRGB matrix (D65)
+    let linear = [
+        x * 3.2404542 - y * 1.5371385 - z * 0.4985314,
+        -x * 0.9692660 + y * 1.8760108 + z * 0.0415560,
+        x * 0.0556434 - y * 0.2040259 + z * 1.0572252,
+    ];
+
+    linear.map(|c| {
+        let c = c.clamp(0.0, 1.0);
+        let c = if c > 0.0031308 {
+            1.055 * c.powf(1.0 / 2.4) - 0.055
+        } else {
+            12.92 * c
+        };
+        (c * 255.0).round() as u8
+    })
+}
 
-    let mut x = xyz.0 / REF_X;
-    let mut y = xyz.1 / REF_Y;
-    let mut z = xyz.2 / REF_Z;
+/// Convert XYZ to LAB color space relative to a reference white
+pub fn xyz_to_lab(xyz: (f64, f64, f64), white: (f64, f64, f64)) -> Lab {
+    let mut x = xyz.0 / white.0;
+    let mut y = xyz.1 / white.1;
+    let mut z = xyz.2 / white.2;
 
     // Apply f function
-    const EPSILON: f64 = 0.008856;
-    const KAPPA: f64 = 903.3;
-
     x = if x > EPSILON {
         x.cbrt()
     } else {
@@ -106,9 +133,31 @@ fn xyz_to_lab(xyz: (f64, f64, f64)) -> Lab {
     }
 }
 
+/// Convert LAB back to XYZ relative to a reference white
+pub fn lab_to_xyz(lab: Lab, white: (f64, f64, f64)) -> (f64, f64, f64) {
+    let fy = (lab.l + 16.0) / 116.0;
+    let fx = fy + lab.a / 500.0;
+    let fz = fy - lab.b / 200.0;
+
+    let inverse = |f: f64| {
+        if f.powi(3) > EPSILON {
+            f.powi(3)
+        } else {
+            (116.0 * f - 16.0) / KAPPA
+        }
+    };
+
+    (inverse(fx) * white.0, inverse(fy) * white.1, inverse(fz) * white.2)
+}
+
 /// Convert RGB to LAB color space
 pub fn rgb_to_lab(rgb: [u8; 3]) -> Lab {
-    xyz_to_lab(rgb_to_xyz(rgb))
+    xyz_to_lab(rgb_to_xyz(rgb), D65_WHITE)
+}
+
+/// Convert LAB (D65) back to 8-bit sRGB
+pub fn lab_to_rgb(lab: Lab) -> [u8; 3] {
+    xyz_to_rgb(lab_to_xyz(lab, D65_WHITE))
 }
 
 /// Simple Euclidean distance in RGB space
@@ -147,12 +196,47 @@ pub fn delta_e76(c1: [u8; 3], c2: [u8; 3]) -> f64 {
     (dl * dl + da * da + db * db).sqrt()
 }
 
-/// CIE94 Delta E - Improved perceptual uniformity
-/// Better than CIE76 for textiles and graphics
-pub fn delta_e94(c1: [u8; 3], c2: [u8; 3]) -> f64 {
-    let lab1 = rgb_to_lab(c1);
-    let lab2 = rgb_to_lab(c2);
+/// CIE94 weighting factors (kC and kH are 1 for both published applications)
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Cie94Weights {
+    pub kl: f64,
+    pub k1: f64,
+    pub k2: f64,
+}
 
+impl Cie94Weights {
+    pub const GRAPHIC_ARTS: Cie94Weights = Cie94Weights { kl: 1.0, k1: 0.045, k2: 0.015 };
+    pub const TEXTILES: Cie94Weights = Cie94Weights { kl: 2.0, k1: 0.048, k2: 0.014 };
+}
+
+/// CIEDE2000 parametric factors for lightness, chroma and hue
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Ciede2000Weights {
+    pub kl: f64,
+    pub kc: f64,
+    pub kh: f64,
+}
+
+impl Ciede2000Weights {
+    pub const GRAPHIC_ARTS: Ciede2000Weights = Ciede2000Weights { kl: 1.0, kc: 1.0, kh: 1.0 };
+    pub const TEXTILES: Ciede2000Weights = Ciede2000Weights { kl: 2.0, kc: 1.0, kh: 1.0 };
+}
+
+/// CMC l:c ratio of lightness to chroma tolerance
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct CmcWeights {
+    pub l: f64,
+    pub c: f64,
+}
+
+impl CmcWeights {
+    pub const ACCEPTABILITY: CmcWeights = CmcWeights { l: 2.0, c: 1.0 }; // 2:1, used for textile pass/fail
+    pub const PERCEPTIBILITY: CmcWeights = CmcWeights { l: 1.0, c: 1.0 };
+}
+
+/// CIE94 Delta E between two LAB colors
+/// SC and SH use the geometric mean chroma, so the result doesn't depend on argument order.
+pub fn cie94(lab1: Lab, lab2: Lab, weights: Cie94Weights) -> f64 {
     let dl = lab1.l - lab2.l;
     let da = lab1.a - lab2.a;
     let db = lab1.b - lab2.b;
@@ -164,28 +248,26 @@ pub fn delta_e94(c1: [u8; 3], c2: [u8; 3]) -> f64 {
     let dh2 = da * da + db * db - dc * dc;
     let dh = if dh2 > 0.0 { dh2.sqrt() } else { 0.0 };
 
-    // Weighting factors for textiles
-    let kl = 2.0;
-    let k1 = 0.048;
-    let k2 = 0.014;
-
-    let sl = 1.0;
-    let sc = 1.0 + k1 * c1_chroma;
-    let sh = 1.0 + k2 * c1_chroma;
+    let c_mean = (c1_chroma * c2_chroma).sqrt();
+    let sl = 1.0; // SL is 1 by definition; lightness is weighted through kL
+    let sc = 1.0 + weights.k1 * c_mean;
+    let sh = 1.0 + weights.k2 * c_mean;
 
-    let term1 = dl / (kl * sl);
+    let term1 = dl / (weights.kl * sl);
     let term2 = dc / sc;
     let term3 = dh / sh;
 
     (term1 * term1 + term2 * term2 + term3 * term3).sqrt()
 }
 
-/// CIEDE2000 Delta E - Most accurate perceptual color difference
-/// Industry standard for color matching applications
-pub fn delta_e2000(c1: [u8; 3], c2: [u8; 3]) -> f64 {
-    let lab1 = rgb_to_lab(c1);
-    let lab2 = rgb_to_lab(c2);
+/// CIE94 Delta E - Improved perceptual uniformity
+/// Uses the textile weights (kL = 2)
+pub fn delta_e94(c1: [u8; 3], c2: [u8; 3]) -> f64 {
+    cie94(rgb_to_lab(c1), rgb_to_lab(c2), Cie94Weights::TEXTILES)
+}
 
+/// CIEDE2000 Delta E between two LAB colors
+pub fn ciede2000(lab1: Lab, lab2: Lab, weights: Ciede2000Weights) -> f64 {
     let l1 = lab1.l;
     let a1 = lab1.a;
     let b1 = lab1.b;
@@ -262,17 +344,58 @@ pub fn delta_e2000(c1: [u8; 3], c2: [u8; 3]) -> f64 {
     let rt = -rc * (2.0 * d_theta).to_radians().sin();
 
     // Calculate final delta E
-    let kl = 1.0;
-    let kc = 1.0;
-    let kh = 1.0;
-
-    let term1 = dl_p / (kl * sl);
-    let term2 = dc_p / (kc * sc);
-    let term3 = dh_p / (kh * sh);
+    let term1 = dl_p / (weights.kl * sl);
+    let term2 = dc_p / (weights.kc * sc);
+    let term3 = dh_p / (weights.kh * sh);
 
     (term1 * term1 + term2 * term2 + term3 * term3 + rt * term2 * term3).sqrt()
 }
 
+/// CIEDE2000 Delta E - Most accurate perceptual color difference
+/// Industry standard for color matching applications (kL = kC = kH = 1)
+pub fn delta_e2000(c1: [u8; 3], c2: [u8; 3]) -> f64 {
+    ciede2000(rgb_to_lab(c1), rgb_to_lab(c2), Ciede2000Weights::GRAPHIC_ARTS)
+}
+
+/// CMC l:c Delta E of `sample` from `reference`
+/// Not symmetric: tolerances are scaled around the reference color.
+pub fn cmc(reference: Lab, sample: Lab, weights: CmcWeights) -> f64 {
+    let c1 = (reference.a * reference.a + reference.b * reference.b).sqrt();
+    let c2 = (sample.a * sample.a + sample.b * sample.b).sqrt();
+    let dl = reference.l - sample.l;
+    let dc = c1 - c2;
+    let da = reference.a - sample.a;
+    let db = reference.b - sample.b;
+    let dh2 = (da * da + db * db - dc * dc).max(0.0);
+
+    let sl = if reference.l < 16.0 {
+        0.511
+    } else {
+        0.040975 * reference.l / (1.0 + 0.01765 * reference.l)
+    };
+    let sc = 0.0638 * c1 / (1.0 + 0.0131 * c1) + 0.638;
+
+    let h1 = reference.b.atan2(reference.a).to_degrees().rem_euclid(360.0);
+    let t = if (164.0..=345.0).contains(&h1) {
+        0.56 + (0.2 * (h1 + 168.0).to_radians().cos()).abs()
+    } else {
+        0.36 + (0.4 * (h1 + 35.0).to_radians().cos()).abs()
+    };
+    let c1_pow4 = c1.powi(4);
+    let f = (c1_pow4 / (c1_pow4 + 1900.0)).sqrt();
+    let sh = sc * (f * t + 1.0 - f);
+
+    let term1 = dl / (weights.l * sl);
+    let term2 = dc / (weights.c * sc);
+
+    (term1 * term1 + term2 * term2 + dh2 / (sh * sh)).sqrt()
+}
+
+/// CMC 2:1 Delta E, with `c1` as the reference (the target color when matching)
+pub fn delta_e_cmc(c1: [u8; 3], c2: [u8; 3]) -> f64 {
+    cmc(rgb_to_lab(c1), rgb_to_lab(c2), CmcWeights::ACCEPTABILITY)
+}
+
 /// Calculate color distance using the specified algorithm
 pub fn color_distance(c1: [u8; 3], c2: [u8; 3], algorithm: ColorMatchAlgorithm) -> f64 {
     match algorithm {
@@ -280,7 +403,11 @@ pub fn color_distance(c1: [u8; 3], c2: [u8; 3], algorithm: ColorMatchAlgorithm)
         ColorMatchAlgorithm::Weighted => weighted_rgb_distance(c1, c2),
         ColorMatchAlgorithm::Cie76 => delta_e76(c1, c2),
         ColorMatchAlgorithm::Cie94 => delta_e94(c1, c2),
+        ColorMatchAlgorithm::Cie94GraphicArts => cie94(rgb_to_lab(c1), rgb_to_lab(c2), Cie94Weights::GRAPHIC_ARTS),
         ColorMatchAlgorithm::Ciede2000 => delta_e2000(c1, c2),
+        ColorMatchAlgorithm::Ciede2000Textiles => ciede2000(rgb_to_lab(c1), rgb_to_lab(c2), Ciede2000Weights::TEXTILES),
+        ColorMatchAlgorithm::Cmc => delta_e_cmc(c1, c2),
+        ColorMatchAlgorithm::Cmc11 => cmc(rgb_to_lab(c1), rgb_to_lab(c2), CmcWeights::PERCEPTIBILITY),
     }
 }
 
@@ -337,6 +464,11 @@ mod tests {
         // Test black
         let lab = rgb_to_lab([0, 0, 0]);
         assert!(lab.l.abs() < 0.1);
+
+        // Round trip
+        for rgb in [[255, 255, 255], [0, 0, 0], [255, 215, 0], [12, 80, 200], [3, 2, 1]] {
+            assert_eq!(lab_to_rgb(rgb_to_lab(rgb)), rgb);
+        }
     }
 
     #[test]
@@ -349,4 +481,81 @@ mod tests {
         let dist = delta_e2000([255, 0, 0], [0, 255, 0]);
         assert!(dist > 50.0);
     }
+
+    /// Sharma, Wu and Dalal (2005), "The CIEDE2000 Color-Difference Formula", Table 1
+    const SHARMA_PAIRS: [([f64; 3], [f64; 3], f64); 34] = [
+        ([50.0000, 2.6772, -79.7751], [50.0000, 0.0000, -82.7485], 2.0425),
+        ([50.0000, 3.1571, -77.2803], [50.0000, 0.0000, -82.7485], 2.8615),
+        ([50.0000, 2.8361, -74.0200], [50.0000, 0.0000, -82.7485], 3.4412),
+        ([50.0000, -1.3802, -84.2814], [50.0000, 0.0000, -82.7485], 1.0000),
+        ([50.0000, -1.1848, -84.8006], [50.0000, 0.0000, -82.7485], 1.0000),
+        ([50.0000, -0.9009, -85.5211], [50.0000, 0.0000, -82.7485], 1.0000),
+        ([50.0000, 0.0000, 0.0000], [50.0000, -1.0000, 2.0000], 2.3669),
+        ([50.0000, -1.0000, 2.0000], [50.0000, 0.0000, 0.0000], 2.3669),
+        ([50.0000, 2.4900, -0.0010], [50.0000, -2.4900, 0.0009], 7.1792),
+        ([50.0000, 2.4900, -0.0010], [50.0000, -2.4900, 0.0010], 7.1792),
+        ([50.0000, 2.4900, -0.0010], [50.0000, -2.4900, 0.0011], 7.2195),
+        ([50.0000, 2.4900, -0.0010], [50.0000, -2.4900, 0.0012], 7.2195),
+        ([50.0000, -0.0010, 2.4900], [50.0000, 0.0009, -2.4900], 4.8045),
+        ([50.0000, -0.0010, 2.4900], [50.0000, 0.0010, -2.4900], 4.8045),
+        ([50.0000, -0.0010, 2.4900], [50.0000, 0.0011, -2.4900], 4.7461),
+        ([50.0000, 2.5000, 0.0000], [50.0000, 0.0000, -2.5000], 4.3065),
+        ([50.0000, 2.5000, 0.0000], [73.0000, 25.0000, -18.0000], 27.1492),
+        ([50.0000, 2.5000, 0.0000], [61.0000, -5.0000, 29.0000], 22.8977),
+        ([50.0000, 2.5000, 0.0000], [56.0000, -27.0000, -3.0000], 31.9030),
+        ([50.0000, 2.5000, 0.0000], [58.0000, 24.0000, 15.0000], 19.4535),
+        ([50.0000, 2.5000, 0.0000], [50.0000, 3.1736, 0.5854], 1.0000),
+        ([50.0000, 2.5000, 0.0000], [50.0000, 3.2972, 0.0000], 1.0000),
+        ([50.0000, 2.5000, 0.0000], [50.0000, 1.8634, 0.5757], 1.0000),
+        ([50.0000, 2.5000, 0.0000], [50.0000, 3.2592, 0.3350], 1.0000),
+        ([60.2574, -34.0099, 36.2677], [60.4626, -34.1751, 39.4387], 1.2644),
+        ([63.0109, -31.0961, -5.8663], [62.8187, -29.7946, -4.0864], 1.2630),
+        ([61.2901, 3.7196, -5.3901], [61.4292, 2.2480, -4.9620], 1.8731),
+        ([35.0831, -44.1164, 3.7933], [35.0232, -40.0716, 1.5901], 1.8645),
+        ([22.7233, 20.0904, -46.6940], [23.0331, 14.9730, -42.5619], 2.0373),
+        ([36.4612, 47.8580, 18.3852], [36.2715, 50.5065, 21.2231], 1.4146),
+        ([90.8027, -2.0831, 1.4410], [91.1528, -1.6435, 0.0447], 1.4441),
+        ([90.9257, -0.5406, -0.9208], [88.6381, -0.8985, -0.7239], 1.5381),
+        ([6.7747, -0.2908, -2.4247], [5.8714, -0.0985, -2.2286], 0.6377),
+        ([2.0776, 0.0795, -1.1350], [0.9033, -0.0636, -0.5514], 0.9082),
+    ];
+
+    fn lab([l, a, b]: [f64; 3]) -> Lab {
+        Lab { l, a, b }
+    }
+
+    #[test]
+    fn test_ciede2000_sharma_data() {
+        for (i, (lab1, lab2, expected)) in SHARMA_PAIRS.iter().enumerate() {
+            let forward = ciede2000(lab(*lab1), lab(*lab2), Ciede2000Weights::GRAPHIC_ARTS);
+            let backward = ciede2000(lab(*lab2), lab(*lab1), Ciede2000Weights::GRAPHIC_ARTS);
+            assert!((forward - expected).abs() < 1e-4, "pair {}: {} != {}", i + 1, forward, expected);
+            assert!((forward - backward).abs() < 1e-9, "pair {} is not symmetric", i + 1);
+        }
+
+        // Textile weighting halves a pure lightness difference's contribution
+        let (dark, light) = (lab([40.0, 0.0, 0.0]), lab([50.0, 0.0, 0.0]));
+        let graphic = ciede2000(dark, light, Ciede2000Weights::GRAPHIC_ARTS);
+        assert!((ciede2000(dark, light, Ciede2000Weights::TEXTILES) * 2.0 - graphic).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_cie94_and_cmc() {
+        let (a, b) = (lab([50.0, 20.0, -10.0]), lab([55.0, 25.0, 5.0]));
+        assert!((cie94(a, b, Cie94Weights::TEXTILES) - cie94(b, a, Cie94Weights::TEXTILES)).abs() < 1e-9);
+        let (dark, light) = (lab([40.0, 0.0, 0.0]), lab([50.0, 0.0, 0.0]));
+        assert!((cie94(dark, light, Cie94Weights::GRAPHIC_ARTS) - 10.0).abs() < 1e-9);
+        assert!((cie94(dark, light, Cie94Weights::TEXTILES) - 5.0).abs() < 1e-9);
+
+        assert_eq!(cmc(a, a, CmcWeights::ACCEPTABILITY), 0.0);
+        assert!(cmc(dark, light, CmcWeights::ACCEPTABILITY) < cmc(dark, light, CmcWeights::PERCEPTIBILITY));
+        assert!((cmc(a, b, CmcWeights::ACCEPTABILITY) - cmc(b, a, CmcWeights::ACCEPTABILITY)).abs() > 1e-3);
+
+        let algorithm: ColorMatchAlgorithm = serde_json::from_str(r#""cmc-1:1""#).unwrap();
+        assert_eq!(algorithm, ColorMatchAlgorithm::Cmc11);
+        assert_eq!(color_distance([10, 20, 30], [10, 20, 30], algorithm), 0.0);
+        let (grey, lighter) = ([100, 100, 100], [120, 120, 120]);
+        assert!(color_distance(grey, lighter, ColorMatchAlgorithm::Ciede2000Textiles) < color_distance(grey, lighter, ColorMatchAlgorithm::Ciede2000));
+        assert!(color_distance(grey, lighter, ColorMatchAlgorithm::Cie94) < color_distance(grey, lighter, ColorMatchAlgorithm::Cie94GraphicArts));
+    }
 }
@@ -0,0 +1,160 @@
+// Thread Conversion Module
+// Converts threads between brands using published equivalence charts with a Delta E fallback
+
+use super::color_matching::{color_distance, delta_e2000, ColorMatchAlgorithm};
+use super::{find_thread, get_threads_by_brand, ThreadBrand, ThreadColor};
+use serde::{Deserialize, Serialize};
+
+/// DMC to Anchor equivalents from the manufacturers' published conversion chart
+/// Limited to threads present in both bundled libraries.
+const DMC_TO_ANCHOR: &[(&str, &str)] = &[
+    ("White", "2"), ("Ecru", "387"), ("B5200", "1"), ("150", "59"), ("153", "95"), ("156", "118"),
+    ("157", "120"), ("158", "178"), ("159", "120"), ("161", "176"), ("162", "159"), ("164", "240"),
+    ("165", "278"), ("166", "280"), ("167", "375"), ("208", "110"), ("209", "109"), ("210", "108"),
+    ("221", "897"), ("307", "289"), ("309", "42"), ("310", "403"), ("311", "148"), ("317", "400"),
+    ("318", "399"), ("326", "59"), ("327", "101"), ("333", "119"), ("335", "40"), ("336", "150"),
+    ("340", "118"), ("341", "117"), ("349", "13"), ("350", "11"), ("351", "10"), ("352", "9"),
+    ("353", "8"), ("356", "5975"), ("407", "914"), ("414", "235"), ("415", "398"), ("420", "374"),
+    ("422", "373"), ("433", "358"), ("434", "310"), ("437", "362"), ("444", "290"), ("445", "288"),
+    ("469", "267"), ("470", "266"), ("471", "265"), ("472", "253"), ("504", "206"), ("535", "401"),
+    ("550", "101"), ("552", "99"), ("553", "98"), ("554", "96"), ("563", "208"), ("564", "206"),
+    ("581", "281"), ("600", "59"), ("602", "57"), ("604", "55"), ("608", "330"), ("611", "898"),
+    ("632", "936"), ("640", "393"), ("642", "392"), ("644", "391"), ("648", "900"), ("666", "46"),
+    ("677", "361"), ("680", "901"), ("700", "228"), ("701", "227"), ("702", "226"), ("703", "238"),
+    ("704", "256"), ("712", "926"), ("718", "88"), ("720", "326"), ("721", "324"), ("722", "323"),
+    ("725", "305"), ("726", "295"), ("727", "293"), ("731", "281"), ("732", "281"), ("733", "280"),
+    ("738", "361"), ("740", "316"), ("741", "304"), ("742", "303"), ("743", "302"), ("744", "301"),
+    ("745", "300"), ("746", "275"), ("747", "158"), ("762", "234"), ("772", "259"), ("775", "128"),
+    ("776", "24"), ("780", "309"), ("781", "308"), ("782", "308"), ("783", "307"), ("791", "178"),
+    ("793", "176"), ("797", "132"), ("798", "131"), ("800", "144"), ("801", "359"), ("803", "148"),
+    ("806", "169"), ("807", "168"), ("809", "130"), ("817", "13"), ("818", "23"), ("822", "390"),
+    ("823", "152"), ("827", "160"), ("838", "380"), ("839", "360"), ("840", "379"), ("842", "376"),
+    ("869", "375"), ("891", "35"), ("892", "33"), ("893", "27"), ("898", "360"), ("899", "52"),
+    ("902", "897"), ("905", "257"), ("906", "256"), ("907", "255"), ("911", "205"), ("913", "204"),
+    ("917", "89"), ("918", "341"), ("937", "268"), ("938", "381"), ("939", "152"), ("946", "332"),
+    ("947", "330"), ("950", "4146"), ("954", "203"), ("955", "206"), ("956", "40"), ("957", "50"),
+    ("966", "240"), ("971", "316"), ("972", "298"), ("976", "1001"), ("977", "1002"), ("987", "244"),
+    ("988", "243"), ("989", "242"), ("3021", "905"), ("3023", "899"), ("3024", "388"), ("3031", "905"),
+    ("3032", "903"), ("3033", "391"), ("3325", "129"), ("3326", "36"), ("3340", "329"), ("3345", "268"),
+    ("3346", "267"), ("3347", "266"), ("3348", "264"), ("3350", "59"), ("3362", "263"), ("3363", "262"),
+    ("3364", "260"), ("3607", "87"), ("3608", "86"), ("3609", "85"), ("3689", "49"), ("3705", "35"),
+    ("3706", "33"), ("3708", "31"), ("3716", "25"), ("3721", "896"), ("3747", "120"), ("3755", "140"),
+    ("3765", "170"), ("3766", "167"), ("3781", "904"), ("3782", "388"), ("3787", "904"), ("3790", "393"),
+    ("3819", "278"), ("3820", "306"), ("3821", "305"), ("3822", "295"), ("3824", "8"), ("3825", "323"),
+    ("3829", "901"), ("3830", "5975"), ("3831", "29"), ("3832", "28"), ("3833", "31"), ("3834", "100"),
+    ("3835", "98"), ("3837", "100"), ("3838", "177"), ("3839", "176"), ("3840", "120"), ("3841", "159"),
+    ("3852", "306"), ("3854", "313"), ("3857", "936"), ("3859", "914"), ("3860", "379"), ("3862", "358"),
+    ("3863", "379"), ("3864", "376"), ("3865", "2"), ("3866", "926"),
+];
+
+/// Where a conversion came from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ConversionSource {
+    /// A published cross-reference chart (or the same thread)
+    Official,
+    /// Closest color in the target brand
+    Nearest,
+}
+
+/// A thread and its equivalent in another brand
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ThreadConversion {
+    pub from: ThreadColor,
+    pub to: ThreadColor,
+    pub source: ConversionSource,
+    pub delta_e: f64, // CIEDE2000 between the two threads
+}
+
+/// Code of the charted equivalent of `thread` in `target`, if the chart lists one
+/// The chart maps several DMC threads to some Anchor threads; going back, the closest
+/// of those DMC threads is used.
+fn official_code(thread: &ThreadColor, target: &ThreadBrand) -> Option<String> {
+    match (&thread.brand, target) {
+        (ThreadBrand::DMC, ThreadBrand::Anchor) => DMC_TO_ANCHOR
+            .iter()
+            .find(|(dmc, _)| dmc.eq_ignore_ascii_case(&thread.code))
+            .map(|(_, anchor)| anchor.to_string()),
+        (ThreadBrand::Anchor, ThreadBrand::DMC) => DMC_TO_ANCHOR
+            .iter()
+            .filter(|(_, anchor)| anchor.eq_ignore_ascii_case(&thread.code))
+            .filter_map(|(dmc, _)| find_thread(target, dmc))
+            .min_by(|a, b| delta_e2000(thread.rgb, a.rgb).total_cmp(&delta_e2000(thread.rgb, b.rgb)))
+            .map(|t| t.code),
+        _ => None,
+    }
+}
+
+/// Convert a thread to its equivalent in `target`
+/// The published chart is preferred; otherwise the closest thread by `algorithm` is used.
+pub fn convert_thread(brand: &ThreadBrand, code: &str, target: &ThreadBrand, algorithm: ColorMatchAlgorithm) -> Result<ThreadConversion, String> {
+    let from = find_thread(brand, code)
+        .ok_or_else(|| format!("Unknown {} thread: {}", brand, code))?;
+
+    if brand == target {
+        return Ok(ThreadConversion { to: from.clone(), from, source: ConversionSource::Official, delta_e: 0.0 });
+    }
+
+    let official = official_code(&from, target).and_then(|c| find_thread(target, &c));
+    let (to, source) = match official {
+        Some(to) => (to, ConversionSource::Official),
+        None => {
+            let to = get_threads_by_brand(target)
+                .iter()
+                .min_by(|a, b| color_distance(from.rgb, a.rgb, algorithm).total_cmp(&color_distance(from.rgb, b.rgb, algorithm)))
+                .cloned()
+                .ok_or_else(|| format!("No {} threads available", target))?;
+            (to, ConversionSource::Nearest)
+        }
+    };
+
+    Ok(ThreadConversion { delta_e: delta_e2000(from.rgb, to.rgb), from, to, source })
+}
+
+/// Tauri commands for thread conversion
+pub mod commands {
+    use super::*;
+
+    /// Find the equivalent of a thread in another brand
+    #[tauri::command]
+    pub fn convert_thread(
+        brand: ThreadBrand,
+        code: String,
+        target_brand: ThreadBrand,
+        algorithm: Option<ColorMatchAlgorithm>,
+    ) -> Result<ThreadConversion, String> {
+        super::convert_thread(&brand, &code, &target_brand, algorithm.unwrap_or_default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_chart_entries_exist() {
+        for (dmc_code, anchor_code) in DMC_TO_ANCHOR {
+            assert!(find_thread(&ThreadBrand::DMC, dmc_code).is_some(), "DMC {} missing", dmc_code);
+            assert!(find_thread(&ThreadBrand::Anchor, anchor_code).is_some(), "Anchor {} missing", anchor_code);
+        }
+    }
+
+    #[test]
+    fn test_convert_prefers_chart() {
+        let black = convert_thread(&ThreadBrand::DMC, "310", &ThreadBrand::Anchor, ColorMatchAlgorithm::Ciede2000).unwrap();
+        assert_eq!(black.to.code, "403");
+        assert_eq!(black.source, ConversionSource::Official);
+
+        // Going back picks the charted DMC thread closest to the Anchor color
+        let back = convert_thread(&ThreadBrand::Anchor, "403", &ThreadBrand::DMC, ColorMatchAlgorithm::Ciede2000).unwrap();
+        assert_eq!(back.to.code, "310");
+        assert_eq!(back.source, ConversionSource::Official);
+
+        // Kreinik has no chart, so the closest color is used
+        let metallic = convert_thread(&ThreadBrand::DMC, "310", &ThreadBrand::Kreinik, ColorMatchAlgorithm::Ciede2000).unwrap();
+        assert_eq!(metallic.source, ConversionSource::Nearest);
+        assert_eq!(metallic.to.brand, ThreadBrand::Kreinik);
+
+        assert!(convert_thread(&ThreadBrand::DMC, "no-such-code", &ThreadBrand::Anchor, ColorMatchAlgorithm::Ciede2000).is_err());
+    }
+}
@@ -0,0 +1,588 @@
+// Custom Thread Library Module
+// User-defined thread libraries imported from JSON or CSV and stored in app data
+
+use super::{ThreadBrand, ThreadColor, ThreadLibraryInfo};
+use crate::paths::{self, PathAccess};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use tauri::Manager;
+
+/// Folder in app data holding one JSON file per custom library
+const LIBRARY_DIR: &str = "thread-libraries";
+
+/// In-memory registry of custom libraries, sorted by name
+static CUSTOM_LIBRARIES: Mutex<Option<Vec<CustomLibrary>>> = Mutex::new(None);
+
+/// One thread in a custom library
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CustomThread {
+    pub code: String,
+    pub name: String,
+    pub rgb: [u8; 3],
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub category: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fiber: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub skein_length: Option<f64>, // Meters per skein
+}
+
+/// A user-defined thread library
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CustomLibrary {
+    pub name: String,
+    #[serde(default)]
+    pub description: String,
+    pub threads: Vec<CustomThread>,
+}
+
+impl CustomLibrary {
+    pub fn brand(&self) -> ThreadBrand {
+        ThreadBrand::Custom(self.name.clone())
+    }
+
+    pub fn thread_colors(&self) -> Vec<ThreadColor> {
+        self.threads
+            .iter()
+            .map(|t| ThreadColor {
+                code: t.code.clone(),
+                name: t.name.clone(),
+                rgb: t.rgb,
+                brand: self.brand(),
+                category: t.category.clone(),
+                fiber: t.fiber.clone(),
+                skein_length: t.skein_length,
+                weight: None,
+                strands: None,
+                color_family: None,
+                specialty: None,
+                discontinued: false,
+                replacement: None,
+            })
+            .collect()
+    }
+
+    pub fn info(&self) -> ThreadLibraryInfo {
+        ThreadLibraryInfo {
+            brand: self.brand(),
+            name: self.name.clone(),
+            description: self.description.clone(),
+            color_count: self.threads.len(),
+        }
+    }
+
+    /// Trim text fields and drop empty optional values
+    fn normalize(&mut self) {
+        self.name = self.name.trim().to_string();
+        self.description = self.description.trim().to_string();
+        for thread in &mut self.threads {
+            thread.code = thread.code.trim().to_string();
+            thread.name = thread.name.trim().to_string();
+            for value in [&mut thread.category, &mut thread.fiber] {
+                *value = value.take().map(|v| v.trim().to_string()).filter(|v| !v.is_empty());
+            }
+        }
+    }
+}
+
+/// Result of checking a library before it is imported or saved
+#[derive(Debug, Clone, Serialize)]
+pub struct LibraryValidation {
+    pub library: CustomLibrary,
+    pub valid: bool,
+    pub errors: Vec<String>,
+    pub warnings: Vec<String>,
+}
+
+// ============================================================================
+// Registry
+// ============================================================================
+
+fn with_libraries<T>(f: impl FnOnce(&[CustomLibrary]) -> T) -> T {
+    let cache = CUSTOM_LIBRARIES.lock().unwrap();
+    f(cache.as_deref().unwrap_or(&[]))
+}
+
+fn find_library<'a>(libraries: &'a [CustomLibrary], name: &str) -> Option<&'a CustomLibrary> {
+    libraries.iter().find(|l| l.name.eq_ignore_ascii_case(name.trim()))
+}
+
+/// Whether a custom library with this name is loaded
+pub fn library_exists(name: &str) -> bool {
+    with_libraries(|libraries| find_library(libraries, name).is_some())
+}
+
+/// Threads of one custom library (empty when it doesn't exist)
+pub fn get_custom_threads(name: &str) -> Vec<ThreadColor> {
+    with_libraries(|libraries| find_library(libraries, name).map(|l| l.thread_colors()).unwrap_or_default())
+}
+
+/// One thread of a custom library by code (case-insensitive)
+pub fn find_custom_thread(name: &str, code: &str) -> Option<ThreadColor> {
+    with_libraries(|libraries| {
+        let library = find_library(libraries, name)?;
+        let index = library.threads.iter().position(|t| t.code.eq_ignore_ascii_case(code.trim()))?;
+        library.thread_colors().into_iter().nth(index)
+    })
+}
+
+/// Library metadata for every custom library
+pub fn get_custom_library_infos() -> Vec<ThreadLibraryInfo> {
+    with_libraries(|libraries| libraries.iter().map(|l| l.info()).collect())
+}
+
+fn set_libraries(mut libraries: Vec<CustomLibrary>) {
+    libraries.sort_by_key(|l| l.name.to_lowercase());
+    *CUSTOM_LIBRARIES.lock().unwrap() = Some(libraries);
+}
+
+// ============================================================================
+// Storage
+// ============================================================================
+
+fn get_library_dir(app: &tauri::AppHandle) -> Result<PathBuf, String> {
+    let app_dir = app
+        .path()
+        .app_data_dir()
+        .map_err(|e| format!("Failed to get app data directory: {}", e))?;
+
+    let dir = app_dir.join(LIBRARY_DIR);
+    fs::create_dir_all(&dir)
+        .map_err(|e| format!("Failed to create thread library directory: {}", e))?;
+
+    Ok(dir)
+}
+
+/// File name stem for a library name ("Silk & Ivory" -> "silk-ivory")
+fn file_stem(name: &str) -> String {
+    let mut stem = String::new();
+    for c in name.trim().chars() {
+        if c.is_alphanumeric() {
+            stem.extend(c.to_lowercase());
+        } else if !stem.is_empty() && !stem.ends_with('-') {
+            stem.push('-');
+        }
+    }
+    stem.trim_end_matches('-').to_string()
+}
+
+/// Load every stored library into the registry
+/// Files that fail to parse are skipped so one bad file doesn't hide the rest.
+pub fn load_libraries(app: &tauri::AppHandle) -> Result<(), String> {
+    let dir = get_library_dir(app)?;
+    let entries = fs::read_dir(&dir).map_err(|e| format!("Failed to read thread library directory: {}", e))?;
+
+    let mut libraries = Vec::new();
+    for path in entries.flatten().map(|e| e.path()) {
+        if path.extension().and_then(|e| e.to_str()) != Some("json") {
+            continue;
+        }
+        let parsed = fs::read_to_string(&path)
+            .map_err(|e| e.to_string())
+            .and_then(|contents| serde_json::from_str::<CustomLibrary>(&contents).map_err(|e| e.to_string()));
+        match parsed {
+            Ok(library) => libraries.push(library),
+            Err(e) => crate::report_error(app, &format!("Failed to load thread library {:?}", path), e),
+        }
+    }
+
+    set_libraries(libraries);
+    Ok(())
+}
+
+fn ensure_loaded(app: &tauri::AppHandle) -> Result<(), String> {
+    if CUSTOM_LIBRARIES.lock().unwrap().is_some() {
+        return Ok(());
+    }
+    load_libraries(app)
+}
+
+fn write_library(dir: &Path, library: &CustomLibrary) -> Result<(), String> {
+    let json = serde_json::to_string_pretty(library)
+        .map_err(|e| format!("Failed to serialize thread library: {}", e))?;
+    fs::write(dir.join(format!("{}.json", file_stem(&library.name))), json)
+        .map_err(|e| format!("Failed to write thread library: {}", e))
+}
+
+// ============================================================================
+// Parsing
+// ============================================================================
+
+/// Split CSV text into records, handling quoted fields and escaped quotes
+pub(crate) fn parse_csv_records(text: &str) -> Vec<Vec<String>> {
+    let mut records = Vec::new();
+    let mut record = Vec::new();
+    let mut field = String::new();
+    let mut in_quotes = false;
+    let mut chars = text.trim_start_matches('\u{feff}').chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if in_quotes && chars.peek() == Some(&'"') => {
+                field.push('"');
+                chars.next();
+            }
+            '"' => in_quotes = !in_quotes,
+            ',' if !in_quotes => record.push(std::mem::take(&mut field)),
+            '\r' if !in_quotes => {}
+            '\n' if !in_quotes => {
+                record.push(std::mem::take(&mut field));
+                records.push(std::mem::take(&mut record));
+            }
+            _ => field.push(c),
+        }
+    }
+    if !field.is_empty() || !record.is_empty() {
+        record.push(field);
+        records.push(record);
+    }
+
+    records.retain(|r| r.iter().any(|f| !f.trim().is_empty()));
+    records
+}
+
+/// Parse "#RRGGBB", "RRGGBB" or three 0-255 numbers ("12 34 56", "12;34;56")
+fn parse_color(value: &str) -> Option<[u8; 3]> {
+    let value = value.trim();
+    let hex = value.trim_start_matches('#');
+    if hex.len() == 6 && hex.chars().all(|c| c.is_ascii_hexdigit()) {
+        let channel = |i: usize| u8::from_str_radix(&hex[i..i + 2], 16).ok();
+        return Some([channel(0)?, channel(2)?, channel(4)?]);
+    }
+
+    let parts: Vec<u8> = value
+        .split(|c: char| !c.is_ascii_digit())
+        .filter(|p| !p.is_empty())
+        .map(|p| p.parse().ok())
+        .collect::<Option<_>>()?;
+    parts.try_into().ok()
+}
+
+/// Parse CSV thread rows; columns are matched by header name
+pub fn parse_csv_threads(text: &str) -> Result<Vec<CustomThread>, String> {
+    let mut records = parse_csv_records(text).into_iter();
+    let header: Vec<String> = records
+        .next()
+        .ok_or("CSV file is empty")?
+        .iter()
+        .map(|h| h.trim().to_lowercase().replace([' ', '-'], "_"))
+        .collect();
+
+    let column = |aliases: &[&str]| header.iter().position(|h| aliases.contains(&h.as_str()));
+    let code_col = column(&["code", "number", "id"]).ok_or("CSV is missing a code column")?;
+    let name_col = column(&["name", "color_name", "colour_name"]).ok_or("CSV is missing a name column")?;
+    let channel_cols = (column(&["r", "red"]), column(&["g", "green"]), column(&["b", "blue"]));
+    let color_col = column(&["hex", "rgb", "color", "colour"]);
+    let category_col = column(&["category", "family"]);
+    let fiber_col = column(&["fiber", "fibre", "material"]);
+    let skein_col = column(&["skein_length", "skein_length_m", "length"]);
+
+    let mut threads = Vec::new();
+    for (index, record) in records.enumerate() {
+        let line = index + 2;
+        let cell = |col: Option<usize>| {
+            col.and_then(|c| record.get(c))
+                .map(|v| v.trim().to_string())
+                .filter(|v| !v.is_empty())
+        };
+
+        let rgb = match (channel_cols, color_col) {
+            ((Some(r), Some(g), Some(b)), _) => {
+                let channel = |col| cell(Some(col)).and_then(|v| v.parse::<u8>().ok());
+                channel(r).zip(channel(g)).zip(channel(b)).map(|((r, g), b)| [r, g, b])
+            }
+            (_, Some(col)) => cell(Some(col)).and_then(|v| parse_color(&v)),
+            _ => return Err("CSV needs r, g, b columns or a hex column".to_string()),
+        }
+        .ok_or_else(|| format!("Line {}: invalid color", line))?;
+
+        let skein_length = match cell(skein_col) {
+            Some(v) => Some(v.parse::<f64>().map_err(|_| format!("Line {}: invalid skein length '{}'", line, v))?),
+            None => None,
+        };
+
+        threads.push(CustomThread {
+            code: cell(Some(code_col)).unwrap_or_default(),
+            name: cell(Some(name_col)).unwrap_or_default(),
+            rgb,
+            category: cell(category_col),
+            fiber: cell(fiber_col),
+            skein_length,
+        });
+    }
+
+    Ok(threads)
+}
+
+/// Parse a library file; JSON may be a full library or a bare array of threads
+/// The library name comes from `name`, then the file, then the file name.
+pub fn parse_library(contents: &str, is_csv: bool, file_name: &str, name: Option<&str>) -> Result<CustomLibrary, String> {
+    let mut library = if is_csv {
+        CustomLibrary { name: String::new(), description: String::new(), threads: parse_csv_threads(contents)? }
+    } else {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum LibraryJson {
+            Library(CustomLibrary),
+            Threads(Vec<CustomThread>),
+        }
+        match serde_json::from_str(contents).map_err(|e| format!("Failed to parse thread library: {}", e))? {
+            LibraryJson::Library(library) => library,
+            LibraryJson::Threads(threads) => CustomLibrary { name: String::new(), description: String::new(), threads },
+        }
+    };
+
+    if let Some(name) = name.filter(|n| !n.trim().is_empty()) {
+        library.name = name.to_string();
+    } else if library.name.trim().is_empty() {
+        library.name = file_name.to_string();
+    }
+    library.normalize();
+    Ok(library)
+}
+
+// ============================================================================
+// Validation
+// ============================================================================
+
+/// Check a library against the libraries already stored
+/// `original` is the stored name of the library being edited, which it may keep.
+pub fn validate_library(library: CustomLibrary, existing: &[CustomLibrary], original: Option<&str>) -> LibraryValidation {
+    let mut errors = Vec::new();
+    let mut warnings = Vec::new();
+
+    if library.name.is_empty() || file_stem(&library.name).is_empty() {
+        errors.push("Library name must contain letters or numbers".to_string());
+    } else if !ThreadBrand::from_name(&library.name).is_custom() {
+        errors.push(format!("'{}' is a built-in thread brand", library.name));
+    } else if existing.iter().any(|l| {
+        file_stem(&l.name) == file_stem(&library.name)
+            && !original.is_some_and(|o| l.name.eq_ignore_ascii_case(o.trim()))
+    }) {
+        errors.push(format!("A thread library named '{}' already exists", library.name));
+    }
+
+    if library.threads.is_empty() {
+        errors.push("Library has no threads".to_string());
+    }
+
+    let mut codes = HashSet::new();
+    let mut names = HashSet::new();
+    let mut colors: HashMap<[u8; 3], &str> = HashMap::new();
+    for (index, thread) in library.threads.iter().enumerate() {
+        let label = if thread.code.is_empty() { format!("Thread {}", index + 1) } else { thread.code.clone() };
+
+        if thread.code.is_empty() {
+            errors.push(format!("{} has no code", label));
+        } else if !codes.insert(thread.code.to_lowercase()) {
+            errors.push(format!("Duplicate code {}", thread.code));
+        }
+        if thread.name.is_empty() {
+            errors.push(format!("{} has no name", label));
+        } else if !names.insert(thread.name.to_lowercase()) {
+            warnings.push(format!("{}: name '{}' is used more than once", label, thread.name));
+        }
+        if let Some(length) = thread.skein_length {
+            if !length.is_finite() || length <= 0.0 {
+                errors.push(format!("{}: skein length must be a positive number of meters", label));
+            }
+        }
+        if let Some(other) = colors.insert(thread.rgb, &thread.code) {
+            warnings.push(format!("{} has the same color as {}", label, other));
+        }
+    }
+
+    LibraryValidation { valid: errors.is_empty(), library, errors, warnings }
+}
+
+fn read_library_file(app: &tauri::AppHandle, path: &str, name: Option<&str>) -> Result<CustomLibrary, String> {
+    let resolved = paths::resolve_path(app, path, PathAccess::ReadThreadLibrary)?;
+    let bytes = resolved.read(app)?;
+    let contents = String::from_utf8(bytes).map_err(|_| "Thread library must be UTF-8 text".to_string())?;
+
+    let (is_csv, file_name) = match resolved.as_path() {
+        Some(file) => (
+            file.extension().and_then(|e| e.to_str()).is_some_and(|e| e.eq_ignore_ascii_case("csv")),
+            file.file_stem().and_then(|s| s.to_str()).unwrap_or_default().to_string(),
+        ),
+        // Content URIs carry no extension; JSON always starts with an object or array
+        None => (!matches!(contents.trim_start().chars().next(), Some('{') | Some('[')), String::new()),
+    };
+
+    parse_library(&contents, is_csv, &file_name, name)
+}
+
+fn invalid_library_error(validation: &LibraryValidation) -> String {
+    format!("Invalid thread library: {}", validation.errors.join("; "))
+}
+
+/// Store a validated library, replacing `original` when it was renamed
+fn store_library(app: &tauri::AppHandle, library: CustomLibrary, original: Option<&str>) -> Result<ThreadLibraryInfo, String> {
+    ensure_loaded(app)?;
+    let mut libraries = with_libraries(|libraries| libraries.to_vec());
+
+    let validation = validate_library(library, &libraries, original);
+    if !validation.valid {
+        return Err(invalid_library_error(&validation));
+    }
+    let library = validation.library;
+
+    let dir = get_library_dir(app)?;
+    write_library(&dir, &library)?;
+
+    if let Some(original) = original {
+        if file_stem(original) != file_stem(&library.name) {
+            let old_path = dir.join(format!("{}.json", file_stem(original)));
+            if old_path.exists() {
+                fs::remove_file(&old_path).map_err(|e| format!("Failed to remove old thread library: {}", e))?;
+            }
+        }
+    }
+
+    let info = library.info();
+    libraries.retain(|l| {
+        !l.name.eq_ignore_ascii_case(&library.name) && !original.is_some_and(|o| l.name.eq_ignore_ascii_case(o.trim()))
+    });
+    libraries.push(library);
+    set_libraries(libraries);
+
+    Ok(info)
+}
+
+// ============================================================================
+// Tauri Commands
+// ============================================================================
+
+pub mod commands {
+    use super::*;
+
+    /// Parse and check a JSON or CSV thread library without importing it
+    #[tauri::command]
+    pub fn validate_thread_library(app: tauri::AppHandle, path: String, name: Option<String>) -> Result<LibraryValidation, String> {
+        ensure_loaded(&app)?;
+        let library = read_library_file(&app, &path, name.as_deref())?;
+        Ok(with_libraries(|libraries| validate_library(library, libraries, None)))
+    }
+
+    /// Import a JSON or CSV thread library into app data
+    #[tauri::command]
+    pub fn import_thread_library(app: tauri::AppHandle, path: String, name: Option<String>) -> Result<ThreadLibraryInfo, String> {
+        let library = read_library_file(&app, &path, name.as_deref())?;
+        store_library(&app, library, None)
+    }
+
+    /// List the custom thread libraries
+    #[tauri::command]
+    pub fn list_custom_thread_libraries(app: tauri::AppHandle) -> Result<Vec<ThreadLibraryInfo>, String> {
+        ensure_loaded(&app)?;
+        Ok(get_custom_library_infos())
+    }
+
+    /// Get a custom thread library for editing
+    #[tauri::command]
+    pub fn get_custom_thread_library(app: tauri::AppHandle, name: String) -> Result<CustomLibrary, String> {
+        ensure_loaded(&app)?;
+        with_libraries(|libraries| find_library(libraries, &name).cloned())
+            .ok_or_else(|| format!("Thread library not found: {}", name))
+    }
+
+    /// Save an edited custom thread library
+    /// Pass `original_name` when editing an existing library so it can be renamed.
+    #[tauri::command]
+    pub fn save_custom_thread_library(
+        app: tauri::AppHandle,
+        mut library: CustomLibrary,
+        original_name: Option<String>,
+    ) -> Result<ThreadLibraryInfo, String> {
+        library.normalize();
+        store_library(&app, library, original_name.as_deref())
+    }
+
+    /// Delete a custom thread library
+    #[tauri::command]
+    pub fn delete_custom_thread_library(app: tauri::AppHandle, name: String) -> Result<(), String> {
+        ensure_loaded(&app)?;
+        let mut libraries = with_libraries(|libraries| libraries.to_vec());
+        let index = libraries
+            .iter()
+            .position(|l| l.name.eq_ignore_ascii_case(name.trim()))
+            .ok_or_else(|| format!("Thread library not found: {}", name))?;
+
+        let path = get_library_dir(&app)?.join(format!("{}.json", file_stem(&libraries[index].name)));
+        if path.exists() {
+            fs::remove_file(&path).map_err(|e| format!("Failed to delete thread library: {}", e))?;
+        }
+
+        libraries.remove(index);
+        set_libraries(libraries);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_csv_library() {
+        let csv = "\u{feff}Code,Name,Hex,Family,Fiber,Skein Length\r\n\
+                   SI-001,\"Ivory, Antique\",#F4EBD0,Neutrals,silk,7.3\r\n\
+                   SI-002,\"Rose \"\"Blush\"\"\",e8a0a8,Pinks,silk,\r\n\r\n";
+        let library = parse_library(csv, true, "silk-and-ivory", None).unwrap();
+
+        assert_eq!(library.name, "silk-and-ivory");
+        assert_eq!(library.threads.len(), 2);
+        assert_eq!(library.threads[0].name, "Ivory, Antique");
+        assert_eq!(library.threads[0].rgb, [0xF4, 0xEB, 0xD0]);
+        assert_eq!(library.threads[0].category.as_deref(), Some("Neutrals"));
+        assert_eq!(library.threads[0].skein_length, Some(7.3));
+        assert_eq!(library.threads[1].name, "Rose \"Blush\"");
+        assert_eq!(library.threads[1].skein_length, None);
+
+        let channels = "code,name,r,g,b\n1,Moss,80,110,60\n";
+        assert_eq!(parse_csv_threads(channels).unwrap()[0].rgb, [80, 110, 60]);
+        assert!(parse_csv_threads("code,name,hex\n1,Moss,not-a-color\n").is_err());
+        assert!(parse_csv_threads("code,name\n1,Moss\n").is_err());
+
+        let json = r#"[{"code": "V1", "name": "Merlot", "rgb": [110, 20, 40], "fiber": "silk"}]"#;
+        let library = parse_library(json, false, "vineyard", Some("Vineyard Silk")).unwrap();
+        assert_eq!(library.name, "Vineyard Silk");
+        assert_eq!(library.thread_colors()[0].brand, ThreadBrand::Custom("Vineyard Silk".to_string()));
+    }
+
+    #[test]
+    fn test_validate_library() {
+        let thread = |code: &str, rgb: [u8; 3]| CustomThread {
+            code: code.to_string(),
+            name: format!("Color {}", code),
+            rgb,
+            category: None,
+            fiber: None,
+            skein_length: Some(8.0),
+        };
+        let library = |name: &str, threads| CustomLibrary { name: name.to_string(), description: String::new(), threads };
+        let existing = vec![library("Planet Earth", vec![thread("1", [0, 0, 0])])];
+
+        let ok = validate_library(library("Silk & Ivory", vec![thread("1", [1, 2, 3])]), &existing, None);
+        assert!(ok.valid, "{:?}", ok.errors);
+        assert_eq!(file_stem("Silk & Ivory"), "silk-ivory");
+
+        assert!(!validate_library(library("dmc", vec![thread("1", [1, 2, 3])]), &existing, None).valid);
+        assert!(!validate_library(library("planet earth", vec![thread("1", [1, 2, 3])]), &existing, None).valid);
+        assert!(validate_library(library("Planet Earth", vec![thread("1", [1, 2, 3])]), &existing, Some("Planet Earth")).valid);
+        assert!(!validate_library(library("Empty", Vec::new()), &existing, None).valid);
+
+        let mut bad_length = thread("2", [4, 5, 6]);
+        bad_length.skein_length = Some(-1.0);
+        let report = validate_library(
+            library("Broken", vec![thread("1", [1, 2, 3]), thread("1", [1, 2, 3]), bad_length]),
+            &existing,
+            None,
+        );
+        assert_eq!(report.errors.len(), 2);
+        assert_eq!(report.warnings.len(), 2);
+    }
+}
@@ -1,4300 +0,0 @@
-// DMC Thread Library - Auto-generated
-// DO NOT EDIT - Generated from TypeScript sources
-
-use super::{ThreadColor, ThreadBrand};
-
-pub fn get_dmc_threads() -> Vec<ThreadColor> {
-    vec![
-        ThreadColor {
-            code: "B5200".to_string(),
-            name: "Snow White".to_string(),
-            rgb: [255, 255, 255],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "White".to_string(),
-            name: "White".to_string(),
-            rgb: [252, 251, 248],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "Ecru".to_string(),
-            name: "Ecru".to_string(),
-            rgb: [240, 234, 218],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "150".to_string(),
-            name: "Dusty Rose Ultra Vy Dk".to_string(),
-            rgb: [171, 2, 73],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "151".to_string(),
-            name: "Dusty Rose Vry Lt".to_string(),
-            rgb: [240, 206, 212],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "152".to_string(),
-            name: "Shell Pink Med Light".to_string(),
-            rgb: [226, 160, 153],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "153".to_string(),
-            name: "Violet Very Light".to_string(),
-            rgb: [230, 204, 217],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "154".to_string(),
-            name: "Grape Very Dark".to_string(),
-            rgb: [87, 36, 51],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "155".to_string(),
-            name: "Blue Violet Med Dk".to_string(),
-            rgb: [152, 145, 182],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "156".to_string(),
-            name: "Blue Violet Med Lt".to_string(),
-            rgb: [163, 174, 209],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "157".to_string(),
-            name: "Cornflower Blue Vy Lt".to_string(),
-            rgb: [187, 195, 217],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "158".to_string(),
-            name: "Cornflower Blu M V D".to_string(),
-            rgb: [76, 82, 110],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "159".to_string(),
-            name: "Blue Gray Light".to_string(),
-            rgb: [199, 202, 215],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "160".to_string(),
-            name: "Blue Gray Medium".to_string(),
-            rgb: [153, 159, 183],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "161".to_string(),
-            name: "Blue Gray".to_string(),
-            rgb: [120, 128, 164],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "162".to_string(),
-            name: "Blue Ultra Very Light".to_string(),
-            rgb: [219, 236, 245],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "163".to_string(),
-            name: "Celadon Green Md".to_string(),
-            rgb: [77, 131, 97],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "164".to_string(),
-            name: "Forest Green Lt".to_string(),
-            rgb: [200, 216, 184],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "165".to_string(),
-            name: "Moss Green Vy Lt".to_string(),
-            rgb: [239, 244, 164],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "166".to_string(),
-            name: "Moss Green Md Lt".to_string(),
-            rgb: [192, 200, 64],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "167".to_string(),
-            name: "Yellow Beige V Dk".to_string(),
-            rgb: [167, 124, 73],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "168".to_string(),
-            name: "Pewter Very Light".to_string(),
-            rgb: [209, 209, 209],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "169".to_string(),
-            name: "Pewter Light".to_string(),
-            rgb: [132, 132, 132],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "208".to_string(),
-            name: "Lavender Very Dark".to_string(),
-            rgb: [131, 91, 139],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "209".to_string(),
-            name: "Lavender Dark".to_string(),
-            rgb: [163, 123, 167],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "210".to_string(),
-            name: "Lavender Medium".to_string(),
-            rgb: [195, 159, 195],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "211".to_string(),
-            name: "Lavender Light".to_string(),
-            rgb: [227, 203, 227],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "221".to_string(),
-            name: "Shell Pink Vy Dk".to_string(),
-            rgb: [136, 62, 67],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "223".to_string(),
-            name: "Shell Pink Light".to_string(),
-            rgb: [204, 132, 124],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "224".to_string(),
-            name: "Shell Pink Very Light".to_string(),
-            rgb: [235, 183, 175],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "225".to_string(),
-            name: "Shell Pink Ult Vy Lt".to_string(),
-            rgb: [255, 223, 213],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "300".to_string(),
-            name: "Mahogany Very Dark".to_string(),
-            rgb: [111, 47, 0],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "301".to_string(),
-            name: "Mahogany Medium".to_string(),
-            rgb: [179, 95, 43],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "304".to_string(),
-            name: "Christmas Red Medium".to_string(),
-            rgb: [183, 31, 51],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "307".to_string(),
-            name: "Lemon".to_string(),
-            rgb: [253, 237, 84],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "309".to_string(),
-            name: "Rose Dark".to_string(),
-            rgb: [186, 74, 74],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "310".to_string(),
-            name: "Black".to_string(),
-            rgb: [0, 0, 0],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "311".to_string(),
-            name: "Navy Blue Medium".to_string(),
-            rgb: [28, 80, 102],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "312".to_string(),
-            name: "Navy Blue Light".to_string(),
-            rgb: [53, 102, 139],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "315".to_string(),
-            name: "Antique Mauve Md Dk".to_string(),
-            rgb: [129, 73, 82],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "316".to_string(),
-            name: "Antique Mauve Med".to_string(),
-            rgb: [183, 115, 127],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "317".to_string(),
-            name: "Pewter Gray".to_string(),
-            rgb: [108, 108, 108],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "318".to_string(),
-            name: "Steel Gray Light".to_string(),
-            rgb: [171, 171, 171],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "319".to_string(),
-            name: "Pistachio Grn Vy Dk".to_string(),
-            rgb: [32, 95, 46],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "320".to_string(),
-            name: "Pistachio Green Med".to_string(),
-            rgb: [105, 136, 90],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "321".to_string(),
-            name: "Christmas Red".to_string(),
-            rgb: [199, 43, 59],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "322".to_string(),
-            name: "Navy Blue Very Light".to_string(),
-            rgb: [90, 143, 184],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "326".to_string(),
-            name: "Rose Very Dark".to_string(),
-            rgb: [179, 59, 75],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "327".to_string(),
-            name: "Violet Dark".to_string(),
-            rgb: [99, 54, 102],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "333".to_string(),
-            name: "Blue Violet Very Dark".to_string(),
-            rgb: [92, 84, 120],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "334".to_string(),
-            name: "Baby Blue Medium".to_string(),
-            rgb: [115, 159, 193],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "335".to_string(),
-            name: "Rose".to_string(),
-            rgb: [238, 84, 110],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "336".to_string(),
-            name: "Navy Blue".to_string(),
-            rgb: [37, 59, 115],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "340".to_string(),
-            name: "Blue Violet Medium".to_string(),
-            rgb: [173, 167, 199],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "341".to_string(),
-            name: "Blue Violet Light".to_string(),
-            rgb: [183, 191, 221],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "347".to_string(),
-            name: "Salmon Very Dark".to_string(),
-            rgb: [191, 45, 45],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "349".to_string(),
-            name: "Coral Dark".to_string(),
-            rgb: [210, 16, 53],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "350".to_string(),
-            name: "Coral Medium".to_string(),
-            rgb: [224, 72, 72],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "351".to_string(),
-            name: "Coral".to_string(),
-            rgb: [233, 106, 103],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "352".to_string(),
-            name: "Coral Light".to_string(),
-            rgb: [253, 156, 151],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "353".to_string(),
-            name: "Peach".to_string(),
-            rgb: [254, 215, 204],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "355".to_string(),
-            name: "Terra Cotta Dark".to_string(),
-            rgb: [152, 68, 54],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "356".to_string(),
-            name: "Terra Cotta Medium".to_string(),
-            rgb: [197, 106, 91],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "367".to_string(),
-            name: "Pistachio Green Dk".to_string(),
-            rgb: [97, 122, 82],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "368".to_string(),
-            name: "Pistachio Green Lt".to_string(),
-            rgb: [166, 194, 152],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "369".to_string(),
-            name: "Pistachio Green Vy Lt".to_string(),
-            rgb: [215, 237, 204],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "370".to_string(),
-            name: "Mustard Medium".to_string(),
-            rgb: [184, 157, 100],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "371".to_string(),
-            name: "Mustard".to_string(),
-            rgb: [191, 166, 113],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "372".to_string(),
-            name: "Mustard Light".to_string(),
-            rgb: [204, 183, 132],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "400".to_string(),
-            name: "Mahogany Dark".to_string(),
-            rgb: [143, 67, 15],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "402".to_string(),
-            name: "Mahogany Very Light".to_string(),
-            rgb: [247, 167, 119],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "407".to_string(),
-            name: "Desert Sand Med".to_string(),
-            rgb: [187, 129, 97],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "413".to_string(),
-            name: "Pewter Gray Dark".to_string(),
-            rgb: [86, 86, 86],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "414".to_string(),
-            name: "Steel Gray Dark".to_string(),
-            rgb: [140, 140, 140],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "415".to_string(),
-            name: "Pearl Gray".to_string(),
-            rgb: [211, 211, 214],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "420".to_string(),
-            name: "Hazelnut Brown Dk".to_string(),
-            rgb: [160, 112, 66],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "422".to_string(),
-            name: "Hazelnut Brown Lt".to_string(),
-            rgb: [198, 159, 123],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "433".to_string(),
-            name: "Brown Medium".to_string(),
-            rgb: [122, 69, 31],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "434".to_string(),
-            name: "Brown Light".to_string(),
-            rgb: [152, 94, 51],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "435".to_string(),
-            name: "Brown Very Light".to_string(),
-            rgb: [184, 119, 72],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "436".to_string(),
-            name: "Tan".to_string(),
-            rgb: [203, 144, 81],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "437".to_string(),
-            name: "Tan Light".to_string(),
-            rgb: [228, 187, 142],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "444".to_string(),
-            name: "Lemon Dark".to_string(),
-            rgb: [255, 214, 0],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "445".to_string(),
-            name: "Lemon Light".to_string(),
-            rgb: [255, 251, 139],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "451".to_string(),
-            name: "Shell Gray Dark".to_string(),
-            rgb: [145, 123, 115],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "452".to_string(),
-            name: "Shell Gray Medium".to_string(),
-            rgb: [192, 179, 174],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "453".to_string(),
-            name: "Shell Gray Light".to_string(),
-            rgb: [215, 206, 203],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "469".to_string(),
-            name: "Avocado Green".to_string(),
-            rgb: [114, 132, 60],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "470".to_string(),
-            name: "Avocado Green Light".to_string(),
-            rgb: [148, 171, 79],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "471".to_string(),
-            name: "Avocado Grn Vy Lt".to_string(),
-            rgb: [174, 191, 121],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "472".to_string(),
-            name: "Avocado Grn Ult Lt".to_string(),
-            rgb: [216, 228, 152],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "498".to_string(),
-            name: "Christmas Red Dark".to_string(),
-            rgb: [167, 19, 43],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "500".to_string(),
-            name: "Blue Green Vy Dk".to_string(),
-            rgb: [4, 77, 51],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "501".to_string(),
-            name: "Blue Green Dark".to_string(),
-            rgb: [57, 111, 82],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "502".to_string(),
-            name: "Blue Green".to_string(),
-            rgb: [91, 144, 113],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "503".to_string(),
-            name: "Blue Green Medium".to_string(),
-            rgb: [123, 172, 148],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "504".to_string(),
-            name: "Blue Green Very Light".to_string(),
-            rgb: [196, 222, 204],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "505".to_string(),
-            name: "Jade Green".to_string(),
-            rgb: [51, 131, 98],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "517".to_string(),
-            name: "Wedgwood Dark".to_string(),
-            rgb: [59, 118, 143],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "518".to_string(),
-            name: "Wedgwood Light".to_string(),
-            rgb: [79, 147, 167],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "519".to_string(),
-            name: "Sky Blue".to_string(),
-            rgb: [126, 177, 200],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "520".to_string(),
-            name: "Fern Green Dark".to_string(),
-            rgb: [102, 109, 79],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "522".to_string(),
-            name: "Fern Green".to_string(),
-            rgb: [150, 158, 126],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "523".to_string(),
-            name: "Fern Green Light".to_string(),
-            rgb: [171, 177, 151],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "524".to_string(),
-            name: "Fern Green Very Light".to_string(),
-            rgb: [196, 205, 172],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "535".to_string(),
-            name: "Ash Gray Very Light".to_string(),
-            rgb: [99, 100, 88],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "543".to_string(),
-            name: "Beige Brown Ult Vy Lt".to_string(),
-            rgb: [242, 227, 206],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "550".to_string(),
-            name: "Violet Very Dark".to_string(),
-            rgb: [92, 24, 78],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "552".to_string(),
-            name: "Violet Medium".to_string(),
-            rgb: [128, 58, 107],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "553".to_string(),
-            name: "Violet".to_string(),
-            rgb: [163, 99, 139],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "554".to_string(),
-            name: "Violet Light".to_string(),
-            rgb: [219, 179, 203],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "561".to_string(),
-            name: "Jade Very Dark".to_string(),
-            rgb: [44, 106, 69],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "562".to_string(),
-            name: "Jade Medium".to_string(),
-            rgb: [83, 151, 106],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "563".to_string(),
-            name: "Jade Light".to_string(),
-            rgb: [143, 192, 152],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "564".to_string(),
-            name: "Jade Very Light".to_string(),
-            rgb: [167, 205, 175],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "580".to_string(),
-            name: "Moss Green Dk".to_string(),
-            rgb: [136, 141, 51],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "581".to_string(),
-            name: "Moss Green".to_string(),
-            rgb: [167, 174, 56],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "597".to_string(),
-            name: "Turquoise".to_string(),
-            rgb: [91, 163, 179],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "598".to_string(),
-            name: "Turquoise Light".to_string(),
-            rgb: [144, 195, 204],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "600".to_string(),
-            name: "Cranberry Very Dark".to_string(),
-            rgb: [205, 47, 99],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "601".to_string(),
-            name: "Cranberry Dark".to_string(),
-            rgb: [209, 40, 106],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "602".to_string(),
-            name: "Cranberry Medium".to_string(),
-            rgb: [226, 72, 116],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "603".to_string(),
-            name: "Cranberry".to_string(),
-            rgb: [255, 115, 140],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "604".to_string(),
-            name: "Cranberry Light".to_string(),
-            rgb: [255, 176, 190],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "605".to_string(),
-            name: "Cranberry Very Light".to_string(),
-            rgb: [255, 192, 205],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "606".to_string(),
-            name: "Bright Orange-Red".to_string(),
-            rgb: [250, 50, 3],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "608".to_string(),
-            name: "Bright Orange".to_string(),
-            rgb: [253, 93, 53],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "610".to_string(),
-            name: "Drab Brown Dk".to_string(),
-            rgb: [121, 96, 71],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "611".to_string(),
-            name: "Drab Brown".to_string(),
-            rgb: [150, 118, 86],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "612".to_string(),
-            name: "Drab Brown Light".to_string(),
-            rgb: [188, 154, 120],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "613".to_string(),
-            name: "Drab Brown Vy Lt".to_string(),
-            rgb: [220, 196, 170],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "632".to_string(),
-            name: "Desert Sand Ult Vy Dk".to_string(),
-            rgb: [135, 85, 57],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "640".to_string(),
-            name: "Beige Gray Vy Dk".to_string(),
-            rgb: [133, 123, 108],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "642".to_string(),
-            name: "Beige Gray Dark".to_string(),
-            rgb: [164, 152, 134],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "644".to_string(),
-            name: "Beige Gray Medium".to_string(),
-            rgb: [221, 216, 203],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "645".to_string(),
-            name: "Beaver Gray Vy Dk".to_string(),
-            rgb: [110, 101, 92],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "646".to_string(),
-            name: "Beaver Gray Dk".to_string(),
-            rgb: [135, 125, 115],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "647".to_string(),
-            name: "Beaver Gray Med".to_string(),
-            rgb: [176, 166, 156],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "648".to_string(),
-            name: "Beaver Gray Light".to_string(),
-            rgb: [188, 180, 172],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "666".to_string(),
-            name: "Christmas Red Bright".to_string(),
-            rgb: [227, 29, 66],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "676".to_string(),
-            name: "Old Gold Light".to_string(),
-            rgb: [229, 206, 151],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "677".to_string(),
-            name: "Old Gold Vy Lt".to_string(),
-            rgb: [245, 236, 203],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "680".to_string(),
-            name: "Old Gold Dark".to_string(),
-            rgb: [188, 141, 14],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "699".to_string(),
-            name: "Christmas Green".to_string(),
-            rgb: [5, 101, 23],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "700".to_string(),
-            name: "Christmas Green Bright".to_string(),
-            rgb: [7, 115, 27],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "701".to_string(),
-            name: "Christmas Green Light".to_string(),
-            rgb: [63, 143, 41],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "702".to_string(),
-            name: "Kelly Green".to_string(),
-            rgb: [71, 167, 47],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "703".to_string(),
-            name: "Chartreuse".to_string(),
-            rgb: [123, 181, 71],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "704".to_string(),
-            name: "Chartreuse Bright".to_string(),
-            rgb: [158, 207, 52],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "712".to_string(),
-            name: "Cream".to_string(),
-            rgb: [255, 251, 239],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "718".to_string(),
-            name: "Plum".to_string(),
-            rgb: [156, 36, 98],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "720".to_string(),
-            name: "Orange Spice Dark".to_string(),
-            rgb: [229, 92, 31],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "721".to_string(),
-            name: "Orange Spice Medium".to_string(),
-            rgb: [242, 120, 66],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "722".to_string(),
-            name: "Orange Spice Light".to_string(),
-            rgb: [247, 151, 111],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "725".to_string(),
-            name: "Topaz".to_string(),
-            rgb: [255, 200, 64],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "726".to_string(),
-            name: "Topaz Light".to_string(),
-            rgb: [253, 215, 85],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "727".to_string(),
-            name: "Topaz Very Light".to_string(),
-            rgb: [255, 241, 175],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "728".to_string(),
-            name: "Topaz Golden".to_string(),
-            rgb: [228, 180, 104],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "729".to_string(),
-            name: "Old Gold Medium".to_string(),
-            rgb: [208, 165, 62],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "730".to_string(),
-            name: "Olive Green Vy Dk".to_string(),
-            rgb: [130, 123, 48],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "731".to_string(),
-            name: "Olive Green Dark".to_string(),
-            rgb: [147, 139, 55],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "732".to_string(),
-            name: "Olive Green".to_string(),
-            rgb: [148, 140, 54],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "733".to_string(),
-            name: "Olive Green Med".to_string(),
-            rgb: [188, 179, 76],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "734".to_string(),
-            name: "Olive Green Light".to_string(),
-            rgb: [199, 192, 119],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "738".to_string(),
-            name: "Tan Very Light".to_string(),
-            rgb: [236, 204, 158],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "739".to_string(),
-            name: "Tan Ultra Vy Lt".to_string(),
-            rgb: [248, 228, 200],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "740".to_string(),
-            name: "Tangerine".to_string(),
-            rgb: [255, 139, 0],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "741".to_string(),
-            name: "Tangerine Medium".to_string(),
-            rgb: [255, 163, 43],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "742".to_string(),
-            name: "Tangerine Light".to_string(),
-            rgb: [255, 191, 87],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "743".to_string(),
-            name: "Yellow Medium".to_string(),
-            rgb: [254, 211, 118],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "744".to_string(),
-            name: "Yellow Pale".to_string(),
-            rgb: [255, 231, 147],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "745".to_string(),
-            name: "Yellow Pale Light".to_string(),
-            rgb: [255, 233, 173],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "746".to_string(),
-            name: "Off White".to_string(),
-            rgb: [252, 252, 238],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "747".to_string(),
-            name: "Sky Blue Very Light".to_string(),
-            rgb: [229, 252, 253],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "754".to_string(),
-            name: "Peach Light".to_string(),
-            rgb: [247, 203, 191],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "758".to_string(),
-            name: "Terra Cotta Vy Lt".to_string(),
-            rgb: [238, 170, 155],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "760".to_string(),
-            name: "Salmon".to_string(),
-            rgb: [245, 173, 173],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "761".to_string(),
-            name: "Salmon Light".to_string(),
-            rgb: [255, 201, 201],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "762".to_string(),
-            name: "Pearl Gray Vy Lt".to_string(),
-            rgb: [236, 236, 236],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "772".to_string(),
-            name: "Yellow Green Vy Lt".to_string(),
-            rgb: [228, 236, 212],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "775".to_string(),
-            name: "Baby Blue Very Light".to_string(),
-            rgb: [217, 235, 241],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "776".to_string(),
-            name: "Pink Medium".to_string(),
-            rgb: [252, 176, 185],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "777".to_string(),
-            name: "Raspberry Very Dark".to_string(),
-            rgb: [145, 53, 70],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "778".to_string(),
-            name: "Antique Mauve Vy Lt".to_string(),
-            rgb: [223, 179, 187],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "779".to_string(),
-            name: "Cocoa Dark".to_string(),
-            rgb: [98, 75, 69],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "780".to_string(),
-            name: "Topaz Ultra Vy Dk".to_string(),
-            rgb: [148, 99, 26],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "781".to_string(),
-            name: "Topaz Very Dark".to_string(),
-            rgb: [162, 109, 32],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "782".to_string(),
-            name: "Topaz Dark".to_string(),
-            rgb: [174, 119, 32],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "783".to_string(),
-            name: "Topaz Medium".to_string(),
-            rgb: [206, 145, 36],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "791".to_string(),
-            name: "Cornflower Blue V D".to_string(),
-            rgb: [70, 69, 99],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "792".to_string(),
-            name: "Cornflower Blue Dark".to_string(),
-            rgb: [85, 91, 123],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "793".to_string(),
-            name: "Cornflower Blue Med".to_string(),
-            rgb: [112, 125, 162],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "794".to_string(),
-            name: "Cornflower Blue Light".to_string(),
-            rgb: [143, 156, 193],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "796".to_string(),
-            name: "Royal Blue Dark".to_string(),
-            rgb: [17, 65, 109],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "797".to_string(),
-            name: "Royal Blue".to_string(),
-            rgb: [19, 71, 125],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "798".to_string(),
-            name: "Delft Blue Dark".to_string(),
-            rgb: [70, 106, 142],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "799".to_string(),
-            name: "Delft Blue Medium".to_string(),
-            rgb: [116, 142, 182],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "800".to_string(),
-            name: "Delft Blue Pale".to_string(),
-            rgb: [192, 204, 222],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "801".to_string(),
-            name: "Coffee Brown Dk".to_string(),
-            rgb: [101, 57, 25],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "803".to_string(),
-            name: "Baby Blue Ult Vy Dk".to_string(),
-            rgb: [44, 89, 124],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "806".to_string(),
-            name: "Peacock Blue Dark".to_string(),
-            rgb: [61, 149, 165],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "807".to_string(),
-            name: "Peacock Blue".to_string(),
-            rgb: [100, 171, 186],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "809".to_string(),
-            name: "Delft Blue".to_string(),
-            rgb: [148, 168, 198],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "813".to_string(),
-            name: "Blue Light".to_string(),
-            rgb: [161, 194, 215],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "814".to_string(),
-            name: "Garnet Dark".to_string(),
-            rgb: [123, 0, 27],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "815".to_string(),
-            name: "Garnet Medium".to_string(),
-            rgb: [135, 7, 31],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "816".to_string(),
-            name: "Garnet".to_string(),
-            rgb: [151, 11, 35],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "817".to_string(),
-            name: "Coral Red Very Dark".to_string(),
-            rgb: [187, 5, 31],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "818".to_string(),
-            name: "Baby Pink".to_string(),
-            rgb: [255, 223, 217],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "819".to_string(),
-            name: "Baby Pink Light".to_string(),
-            rgb: [255, 238, 235],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "820".to_string(),
-            name: "Royal Blue Very Dark".to_string(),
-            rgb: [14, 54, 92],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "822".to_string(),
-            name: "Beige Gray Light".to_string(),
-            rgb: [231, 226, 211],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "823".to_string(),
-            name: "Navy Blue Dark".to_string(),
-            rgb: [33, 48, 99],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "824".to_string(),
-            name: "Blue Very Dark".to_string(),
-            rgb: [57, 105, 135],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "825".to_string(),
-            name: "Blue Dark".to_string(),
-            rgb: [71, 129, 165],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "826".to_string(),
-            name: "Blue Medium".to_string(),
-            rgb: [107, 158, 191],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "827".to_string(),
-            name: "Blue Very Light".to_string(),
-            rgb: [189, 221, 237],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "828".to_string(),
-            name: "Blue Ultra Vy Lt".to_string(),
-            rgb: [197, 232, 237],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "829".to_string(),
-            name: "Golden Olive Vy Dk".to_string(),
-            rgb: [126, 107, 66],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "830".to_string(),
-            name: "Golden Olive Dk".to_string(),
-            rgb: [141, 120, 75],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "831".to_string(),
-            name: "Golden Olive Med".to_string(),
-            rgb: [170, 143, 86],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "832".to_string(),
-            name: "Golden Olive".to_string(),
-            rgb: [189, 155, 81],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "833".to_string(),
-            name: "Golden Olive Light".to_string(),
-            rgb: [200, 171, 108],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "834".to_string(),
-            name: "Golden Olive Vy Lt".to_string(),
-            rgb: [219, 190, 127],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "838".to_string(),
-            name: "Beige Brown Vy Dk".to_string(),
-            rgb: [89, 73, 55],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "839".to_string(),
-            name: "Beige Brown Dark".to_string(),
-            rgb: [103, 85, 65],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "840".to_string(),
-            name: "Beige Brown Med".to_string(),
-            rgb: [154, 124, 92],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "841".to_string(),
-            name: "Beige Brown Light".to_string(),
-            rgb: [182, 155, 126],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "842".to_string(),
-            name: "Beige Brown Vy Lt".to_string(),
-            rgb: [209, 186, 161],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "844".to_string(),
-            name: "Beaver Gray Ult Dk".to_string(),
-            rgb: [72, 72, 72],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "869".to_string(),
-            name: "Hazelnut Brn Vy Dk".to_string(),
-            rgb: [131, 94, 57],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "890".to_string(),
-            name: "Pistachio Grn Ult Dk".to_string(),
-            rgb: [23, 73, 35],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "891".to_string(),
-            name: "Carnation Dark".to_string(),
-            rgb: [255, 87, 115],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "892".to_string(),
-            name: "Carnation Medium".to_string(),
-            rgb: [255, 121, 140],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "893".to_string(),
-            name: "Carnation Light".to_string(),
-            rgb: [252, 144, 162],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "894".to_string(),
-            name: "Carnation Very Light".to_string(),
-            rgb: [255, 178, 187],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "895".to_string(),
-            name: "Hunter Green Vy Dk".to_string(),
-            rgb: [27, 83, 0],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "898".to_string(),
-            name: "Coffee Brown Vy Dk".to_string(),
-            rgb: [73, 42, 19],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "899".to_string(),
-            name: "Rose Medium".to_string(),
-            rgb: [242, 118, 136],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "900".to_string(),
-            name: "Burnt Orange Dark".to_string(),
-            rgb: [209, 88, 7],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "902".to_string(),
-            name: "Garnet Very Dark".to_string(),
-            rgb: [130, 38, 55],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "904".to_string(),
-            name: "Parrot Green Vy Dk".to_string(),
-            rgb: [85, 120, 34],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "905".to_string(),
-            name: "Parrot Green Dark".to_string(),
-            rgb: [98, 138, 40],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "906".to_string(),
-            name: "Parrot Green Med".to_string(),
-            rgb: [127, 179, 53],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "907".to_string(),
-            name: "Parrot Green Light".to_string(),
-            rgb: [199, 230, 102],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "909".to_string(),
-            name: "Emerald Green Vy Dk".to_string(),
-            rgb: [21, 111, 73],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "910".to_string(),
-            name: "Emerald Green Dark".to_string(),
-            rgb: [24, 126, 86],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "911".to_string(),
-            name: "Emerald Green Med".to_string(),
-            rgb: [24, 144, 101],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "912".to_string(),
-            name: "Emerald Green Light".to_string(),
-            rgb: [27, 157, 107],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "913".to_string(),
-            name: "Nile Green Medium".to_string(),
-            rgb: [109, 171, 119],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "915".to_string(),
-            name: "Plum Dark".to_string(),
-            rgb: [130, 0, 67],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "917".to_string(),
-            name: "Plum Medium".to_string(),
-            rgb: [155, 19, 89],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "918".to_string(),
-            name: "Red Copper Dark".to_string(),
-            rgb: [130, 52, 10],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "919".to_string(),
-            name: "Red Copper".to_string(),
-            rgb: [166, 69, 16],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "920".to_string(),
-            name: "Copper Medium".to_string(),
-            rgb: [172, 84, 20],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "921".to_string(),
-            name: "Copper".to_string(),
-            rgb: [198, 98, 24],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "922".to_string(),
-            name: "Copper Light".to_string(),
-            rgb: [226, 115, 35],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "924".to_string(),
-            name: "Gray Green Vy Dark".to_string(),
-            rgb: [86, 106, 106],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "926".to_string(),
-            name: "Gray Green Med".to_string(),
-            rgb: [152, 174, 174],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "927".to_string(),
-            name: "Gray Green Light".to_string(),
-            rgb: [189, 203, 203],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "928".to_string(),
-            name: "Gray Green Vy Lt".to_string(),
-            rgb: [221, 227, 227],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "930".to_string(),
-            name: "Antique Blue Dark".to_string(),
-            rgb: [69, 92, 113],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "931".to_string(),
-            name: "Antique Blue Medium".to_string(),
-            rgb: [106, 133, 158],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "932".to_string(),
-            name: "Antique Blue Light".to_string(),
-            rgb: [162, 181, 198],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "934".to_string(),
-            name: "Avocado Grn Black".to_string(),
-            rgb: [49, 57, 25],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "935".to_string(),
-            name: "Avocado Green Dk".to_string(),
-            rgb: [66, 77, 33],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "936".to_string(),
-            name: "Avocado Grn V Dk".to_string(),
-            rgb: [76, 88, 38],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "937".to_string(),
-            name: "Avocado Green Med".to_string(),
-            rgb: [98, 113, 51],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "938".to_string(),
-            name: "Coffee Brown Ult Dk".to_string(),
-            rgb: [54, 31, 14],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "939".to_string(),
-            name: "Navy Blue Very Dark".to_string(),
-            rgb: [27, 40, 83],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "943".to_string(),
-            name: "Aquamarine Med".to_string(),
-            rgb: [61, 147, 132],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "945".to_string(),
-            name: "Tawny".to_string(),
-            rgb: [251, 213, 187],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "946".to_string(),
-            name: "Burnt Orange Med".to_string(),
-            rgb: [235, 99, 7],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "947".to_string(),
-            name: "Burnt Orange".to_string(),
-            rgb: [255, 123, 77],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "948".to_string(),
-            name: "Peach Very Light".to_string(),
-            rgb: [254, 231, 218],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "950".to_string(),
-            name: "Desert Sand Light".to_string(),
-            rgb: [238, 211, 196],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "951".to_string(),
-            name: "Tawny Light".to_string(),
-            rgb: [255, 226, 207],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "954".to_string(),
-            name: "Nile Green".to_string(),
-            rgb: [136, 186, 145],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "955".to_string(),
-            name: "Nile Green Light".to_string(),
-            rgb: [162, 214, 173],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "956".to_string(),
-            name: "Geranium".to_string(),
-            rgb: [255, 145, 145],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "957".to_string(),
-            name: "Geranium Pale".to_string(),
-            rgb: [253, 181, 181],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "958".to_string(),
-            name: "Sea Green Dark".to_string(),
-            rgb: [62, 182, 161],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "959".to_string(),
-            name: "Sea Green Medium".to_string(),
-            rgb: [89, 199, 180],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "961".to_string(),
-            name: "Dusty Rose Dark".to_string(),
-            rgb: [207, 115, 115],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "962".to_string(),
-            name: "Dusty Rose Medium".to_string(),
-            rgb: [230, 138, 138],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "963".to_string(),
-            name: "Dusty Rose Ult Vy Lt".to_string(),
-            rgb: [255, 215, 215],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "964".to_string(),
-            name: "Sea Green Light".to_string(),
-            rgb: [169, 226, 216],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "966".to_string(),
-            name: "Baby Green Medium".to_string(),
-            rgb: [185, 215, 192],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "967".to_string(),
-            name: "Apricot Very Light".to_string(),
-            rgb: [255, 222, 213],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "970".to_string(),
-            name: "Pumpkin Light".to_string(),
-            rgb: [247, 139, 19],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "971".to_string(),
-            name: "Pumpkin".to_string(),
-            rgb: [246, 127, 0],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "972".to_string(),
-            name: "Canary Deep".to_string(),
-            rgb: [255, 181, 21],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "973".to_string(),
-            name: "Canary Bright".to_string(),
-            rgb: [255, 227, 0],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "975".to_string(),
-            name: "Golden Brown Dk".to_string(),
-            rgb: [145, 79, 18],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "976".to_string(),
-            name: "Golden Brown Med".to_string(),
-            rgb: [194, 129, 66],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "977".to_string(),
-            name: "Golden Brown Light".to_string(),
-            rgb: [220, 156, 86],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "986".to_string(),
-            name: "Forest Green Vy Dk".to_string(),
-            rgb: [64, 82, 48],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "987".to_string(),
-            name: "Forest Green Dark".to_string(),
-            rgb: [88, 113, 65],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "988".to_string(),
-            name: "Forest Green Med".to_string(),
-            rgb: [115, 139, 91],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "989".to_string(),
-            name: "Forest Green".to_string(),
-            rgb: [141, 166, 117],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "991".to_string(),
-            name: "Aquamarine Dark".to_string(),
-            rgb: [71, 123, 110],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "992".to_string(),
-            name: "Aquamarine Light".to_string(),
-            rgb: [111, 174, 159],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "993".to_string(),
-            name: "Aquamarine Vy Lt".to_string(),
-            rgb: [144, 192, 180],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "995".to_string(),
-            name: "Electric Blue Dark".to_string(),
-            rgb: [38, 150, 182],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "996".to_string(),
-            name: "Electric Blue Med".to_string(),
-            rgb: [48, 194, 236],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "3011".to_string(),
-            name: "Khaki Green Dark".to_string(),
-            rgb: [137, 138, 88],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "3012".to_string(),
-            name: "Khaki Green Med".to_string(),
-            rgb: [166, 167, 93],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "3013".to_string(),
-            name: "Khaki Green Light".to_string(),
-            rgb: [185, 185, 130],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "3021".to_string(),
-            name: "Brown Gray Vy Dk".to_string(),
-            rgb: [79, 75, 65],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "3022".to_string(),
-            name: "Brown Gray Med".to_string(),
-            rgb: [142, 144, 120],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "3023".to_string(),
-            name: "Brown Gray Light".to_string(),
-            rgb: [177, 170, 151],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "3024".to_string(),
-            name: "Brown Gray Vy Lt".to_string(),
-            rgb: [235, 234, 231],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "3031".to_string(),
-            name: "Mocha Brown Vy Dk".to_string(),
-            rgb: [75, 60, 42],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "3032".to_string(),
-            name: "Mocha Brown Med".to_string(),
-            rgb: [179, 159, 139],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "3033".to_string(),
-            name: "Mocha Brown Vy Lt".to_string(),
-            rgb: [227, 216, 204],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "3041".to_string(),
-            name: "Antique Violet Med".to_string(),
-            rgb: [149, 111, 124],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "3042".to_string(),
-            name: "Antique Violet Light".to_string(),
-            rgb: [183, 157, 167],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "3045".to_string(),
-            name: "Yellow Beige Dk".to_string(),
-            rgb: [188, 150, 106],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "3046".to_string(),
-            name: "Yellow Beige Med".to_string(),
-            rgb: [216, 188, 154],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "3047".to_string(),
-            name: "Yellow Beige Light".to_string(),
-            rgb: [231, 214, 193],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "3051".to_string(),
-            name: "Green Gray Dark".to_string(),
-            rgb: [95, 102, 72],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "3052".to_string(),
-            name: "Green Gray Med".to_string(),
-            rgb: [136, 146, 104],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "3053".to_string(),
-            name: "Green Gray".to_string(),
-            rgb: [156, 164, 130],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "3064".to_string(),
-            name: "Desert Sand".to_string(),
-            rgb: [196, 142, 112],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "3072".to_string(),
-            name: "Beaver Gray Vy Lt".to_string(),
-            rgb: [230, 232, 232],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "3078".to_string(),
-            name: "Golden Yellow Vy Lt".to_string(),
-            rgb: [253, 249, 205],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "3325".to_string(),
-            name: "Baby Blue Light".to_string(),
-            rgb: [184, 210, 230],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "3326".to_string(),
-            name: "Rose Light".to_string(),
-            rgb: [251, 173, 180],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "3328".to_string(),
-            name: "Salmon Dark".to_string(),
-            rgb: [227, 109, 109],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "3340".to_string(),
-            name: "Apricot Medium".to_string(),
-            rgb: [255, 131, 111],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "3341".to_string(),
-            name: "Apricot".to_string(),
-            rgb: [252, 171, 152],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "3345".to_string(),
-            name: "Hunter Green Dk".to_string(),
-            rgb: [27, 89, 21],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "3346".to_string(),
-            name: "Hunter Green".to_string(),
-            rgb: [64, 106, 58],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "3347".to_string(),
-            name: "Yellow Green Med".to_string(),
-            rgb: [113, 147, 92],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "3348".to_string(),
-            name: "Yellow Green Light".to_string(),
-            rgb: [204, 217, 177],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "3350".to_string(),
-            name: "Dusty Rose Ultra Dk".to_string(),
-            rgb: [188, 67, 101],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "3354".to_string(),
-            name: "Dusty Rose Light".to_string(),
-            rgb: [228, 166, 172],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "3362".to_string(),
-            name: "Pine Green Dark".to_string(),
-            rgb: [94, 107, 71],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "3363".to_string(),
-            name: "Pine Green Med".to_string(),
-            rgb: [114, 130, 86],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "3364".to_string(),
-            name: "Pine Green".to_string(),
-            rgb: [131, 151, 95],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "3607".to_string(),
-            name: "Plum Light".to_string(),
-            rgb: [197, 73, 137],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "3608".to_string(),
-            name: "Plum Very Light".to_string(),
-            rgb: [234, 156, 196],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "3609".to_string(),
-            name: "Plum Ultra Light".to_string(),
-            rgb: [244, 174, 213],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "3685".to_string(),
-            name: "Mauve Very Dark".to_string(),
-            rgb: [136, 21, 49],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "3687".to_string(),
-            name: "Mauve".to_string(),
-            rgb: [201, 107, 112],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "3688".to_string(),
-            name: "Mauve Medium".to_string(),
-            rgb: [231, 169, 172],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "3689".to_string(),
-            name: "Mauve Light".to_string(),
-            rgb: [251, 191, 194],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "3705".to_string(),
-            name: "Melon Dark".to_string(),
-            rgb: [255, 121, 146],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "3706".to_string(),
-            name: "Melon Medium".to_string(),
-            rgb: [255, 173, 188],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "3708".to_string(),
-            name: "Melon Light".to_string(),
-            rgb: [255, 203, 213],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "3712".to_string(),
-            name: "Salmon Medium".to_string(),
-            rgb: [241, 135, 135],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "3713".to_string(),
-            name: "Salmon Very Light".to_string(),
-            rgb: [255, 226, 226],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "3716".to_string(),
-            name: "Dusty Rose Med Vy Lt".to_string(),
-            rgb: [255, 189, 189],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "3721".to_string(),
-            name: "Shell Pink Dark".to_string(),
-            rgb: [161, 75, 81],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "3722".to_string(),
-            name: "Shell Pink Med".to_string(),
-            rgb: [188, 108, 100],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "3726".to_string(),
-            name: "Antique Mauve Dark".to_string(),
-            rgb: [155, 91, 102],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "3727".to_string(),
-            name: "Antique Mauve Light".to_string(),
-            rgb: [219, 169, 178],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "3731".to_string(),
-            name: "Dusty Rose Very Dark".to_string(),
-            rgb: [218, 103, 131],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "3733".to_string(),
-            name: "Dusty Rose".to_string(),
-            rgb: [232, 135, 155],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "3740".to_string(),
-            name: "Antique Violet Dark".to_string(),
-            rgb: [120, 87, 98],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "3743".to_string(),
-            name: "Antique Violet Vy Lt".to_string(),
-            rgb: [215, 203, 211],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "3746".to_string(),
-            name: "Blue Violet Dark".to_string(),
-            rgb: [119, 107, 152],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "3747".to_string(),
-            name: "Blue Violet Vy Lt".to_string(),
-            rgb: [211, 215, 237],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "3750".to_string(),
-            name: "Antique Blue Vy Dk".to_string(),
-            rgb: [56, 76, 94],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "3752".to_string(),
-            name: "Antique Blue Vy Lt".to_string(),
-            rgb: [199, 209, 219],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "3753".to_string(),
-            name: "Antique Blue Ult Vy Lt".to_string(),
-            rgb: [219, 226, 233],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "3755".to_string(),
-            name: "Baby Blue".to_string(),
-            rgb: [147, 180, 206],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "3756".to_string(),
-            name: "Baby Blue Ult Vy Lt".to_string(),
-            rgb: [238, 252, 252],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "3760".to_string(),
-            name: "Wedgwood Med".to_string(),
-            rgb: [62, 133, 162],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "3761".to_string(),
-            name: "Sky Blue Light".to_string(),
-            rgb: [172, 216, 226],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "3765".to_string(),
-            name: "Peacock Blue Vy Dk".to_string(),
-            rgb: [52, 127, 140],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "3766".to_string(),
-            name: "Peacock Blue Light".to_string(),
-            rgb: [153, 207, 217],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "3768".to_string(),
-            name: "Gray Green Dark".to_string(),
-            rgb: [101, 127, 127],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "3770".to_string(),
-            name: "Tawny Vy Light".to_string(),
-            rgb: [255, 238, 227],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "3771".to_string(),
-            name: "Terra Cotta Ult Vy Lt".to_string(),
-            rgb: [244, 187, 169],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "3772".to_string(),
-            name: "Desert Sand Vy Dk".to_string(),
-            rgb: [160, 108, 80],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "3773".to_string(),
-            name: "Desert Sand Med".to_string(),
-            rgb: [182, 117, 82],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "3774".to_string(),
-            name: "Desert Sand Vy Lt".to_string(),
-            rgb: [243, 225, 215],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "3776".to_string(),
-            name: "Mahogany Light".to_string(),
-            rgb: [207, 121, 57],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "3777".to_string(),
-            name: "Terra Cotta Vy Dk".to_string(),
-            rgb: [134, 48, 34],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "3778".to_string(),
-            name: "Terra Cotta Light".to_string(),
-            rgb: [217, 137, 120],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "3779".to_string(),
-            name: "Ter Cotta Ult Vy Lt".to_string(),
-            rgb: [248, 202, 200],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "3781".to_string(),
-            name: "Mocha Brown Dark".to_string(),
-            rgb: [107, 87, 67],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "3782".to_string(),
-            name: "Mocha Brown Light".to_string(),
-            rgb: [210, 188, 166],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "3787".to_string(),
-            name: "Brown Gray Dark".to_string(),
-            rgb: [98, 93, 80],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "3790".to_string(),
-            name: "Beige Gray Ult Dk".to_string(),
-            rgb: [127, 106, 85],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "3799".to_string(),
-            name: "Pewter Gray Vy Dk".to_string(),
-            rgb: [66, 66, 66],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "3801".to_string(),
-            name: "Christmas Red Lt".to_string(),
-            rgb: [231, 73, 103],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "3802".to_string(),
-            name: "Antique Mauve Vy Dk".to_string(),
-            rgb: [113, 65, 73],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "3803".to_string(),
-            name: "Mauve Dark".to_string(),
-            rgb: [171, 51, 87],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "3804".to_string(),
-            name: "Cyclamen Pink Dark".to_string(),
-            rgb: [224, 40, 118],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "3805".to_string(),
-            name: "Cyclamen Pink".to_string(),
-            rgb: [243, 71, 139],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "3806".to_string(),
-            name: "Cyclamen Pink Light".to_string(),
-            rgb: [255, 140, 174],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "3807".to_string(),
-            name: "Cornflower Blue".to_string(),
-            rgb: [96, 103, 140],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "3808".to_string(),
-            name: "Turquoise Ult Vy Dk".to_string(),
-            rgb: [54, 105, 112],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "3809".to_string(),
-            name: "Turquoise Vy Dark".to_string(),
-            rgb: [63, 124, 133],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "3810".to_string(),
-            name: "Turquoise Dark".to_string(),
-            rgb: [72, 142, 154],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "3811".to_string(),
-            name: "Turquoise Very Light".to_string(),
-            rgb: [188, 227, 230],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "3812".to_string(),
-            name: "Sea Green Vy Dk".to_string(),
-            rgb: [47, 140, 132],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "3813".to_string(),
-            name: "Blue Green Lt".to_string(),
-            rgb: [178, 212, 189],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "3814".to_string(),
-            name: "Aquamarine".to_string(),
-            rgb: [80, 139, 125],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "3815".to_string(),
-            name: "Celadon Green Dk".to_string(),
-            rgb: [71, 119, 89],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "3816".to_string(),
-            name: "Celadon Green".to_string(),
-            rgb: [101, 165, 125],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "3817".to_string(),
-            name: "Celadon Green Lt".to_string(),
-            rgb: [153, 195, 170],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "3818".to_string(),
-            name: "Emerald Grn Ult V Dk".to_string(),
-            rgb: [17, 90, 59],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "3819".to_string(),
-            name: "Moss Green Lt".to_string(),
-            rgb: [224, 232, 104],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "3820".to_string(),
-            name: "Straw Dark".to_string(),
-            rgb: [223, 182, 95],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "3821".to_string(),
-            name: "Straw".to_string(),
-            rgb: [243, 206, 117],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "3822".to_string(),
-            name: "Straw Light".to_string(),
-            rgb: [246, 220, 152],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "3823".to_string(),
-            name: "Yellow Ultra Pale".to_string(),
-            rgb: [255, 253, 227],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "3824".to_string(),
-            name: "Apricot Light".to_string(),
-            rgb: [254, 205, 194],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "3825".to_string(),
-            name: "Pumpkin Pale".to_string(),
-            rgb: [253, 189, 150],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "3826".to_string(),
-            name: "Golden Brown".to_string(),
-            rgb: [173, 114, 57],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "3827".to_string(),
-            name: "Golden Brown Pale".to_string(),
-            rgb: [247, 187, 119],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "3828".to_string(),
-            name: "Hazelnut Brown".to_string(),
-            rgb: [183, 139, 97],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "3829".to_string(),
-            name: "Old Gold Very Dark".to_string(),
-            rgb: [169, 130, 4],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "3830".to_string(),
-            name: "Terra Cotta".to_string(),
-            rgb: [185, 85, 68],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "3831".to_string(),
-            name: "Raspberry Dark".to_string(),
-            rgb: [179, 47, 72],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "3832".to_string(),
-            name: "Raspberry Medium".to_string(),
-            rgb: [219, 85, 110],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "3833".to_string(),
-            name: "Raspberry Light".to_string(),
-            rgb: [234, 134, 153],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "3834".to_string(),
-            name: "Grape Dark".to_string(),
-            rgb: [114, 55, 93],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "3835".to_string(),
-            name: "Grape Medium".to_string(),
-            rgb: [148, 96, 131],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "3836".to_string(),
-            name: "Grape Light".to_string(),
-            rgb: [186, 145, 170],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "3837".to_string(),
-            name: "Lavender Ultra Dark".to_string(),
-            rgb: [108, 58, 110],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "3838".to_string(),
-            name: "Lavender Blue Dark".to_string(),
-            rgb: [92, 114, 148],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "3839".to_string(),
-            name: "Lavender Blue Med".to_string(),
-            rgb: [123, 142, 171],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "3840".to_string(),
-            name: "Lavender Blue Light".to_string(),
-            rgb: [176, 192, 218],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "3841".to_string(),
-            name: "Baby Blue Pale".to_string(),
-            rgb: [205, 223, 237],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "3842".to_string(),
-            name: "Wedgwood Vry Dk".to_string(),
-            rgb: [50, 102, 124],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "3843".to_string(),
-            name: "Electric Blue".to_string(),
-            rgb: [20, 170, 208],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "3844".to_string(),
-            name: "Turquoise Bright Dark".to_string(),
-            rgb: [18, 174, 186],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "3845".to_string(),
-            name: "Turquoise Bright Med".to_string(),
-            rgb: [4, 196, 202],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "3846".to_string(),
-            name: "Turquoise Bright Lt".to_string(),
-            rgb: [6, 227, 230],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "3847".to_string(),
-            name: "Teal Green Dark".to_string(),
-            rgb: [52, 125, 117],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "3848".to_string(),
-            name: "Teal Green Med".to_string(),
-            rgb: [85, 147, 146],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "3849".to_string(),
-            name: "Teal Green Light".to_string(),
-            rgb: [82, 179, 164],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "3850".to_string(),
-            name: "Bright Green Dk".to_string(),
-            rgb: [55, 132, 119],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "3851".to_string(),
-            name: "Bright Green Lt".to_string(),
-            rgb: [73, 179, 161],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "3852".to_string(),
-            name: "Straw Very Dark".to_string(),
-            rgb: [205, 157, 55],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "3853".to_string(),
-            name: "Autumn Gold Dk".to_string(),
-            rgb: [242, 151, 70],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "3854".to_string(),
-            name: "Autumn Gold Med".to_string(),
-            rgb: [242, 175, 104],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "3855".to_string(),
-            name: "Autumn Gold Lt".to_string(),
-            rgb: [250, 211, 150],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "3856".to_string(),
-            name: "Mahogany Ult Vy Lt".to_string(),
-            rgb: [255, 211, 181],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "3857".to_string(),
-            name: "Rosewood Dark".to_string(),
-            rgb: [104, 37, 26],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "3858".to_string(),
-            name: "Rosewood Med".to_string(),
-            rgb: [150, 74, 63],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "3859".to_string(),
-            name: "Rosewood Light".to_string(),
-            rgb: [186, 139, 124],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "3860".to_string(),
-            name: "Cocoa".to_string(),
-            rgb: [125, 93, 87],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "3861".to_string(),
-            name: "Cocoa Light".to_string(),
-            rgb: [166, 136, 129],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "3862".to_string(),
-            name: "Mocha Beige Dark".to_string(),
-            rgb: [138, 110, 78],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "3863".to_string(),
-            name: "Mocha Beige Med".to_string(),
-            rgb: [164, 131, 92],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "3864".to_string(),
-            name: "Mocha Beige Light".to_string(),
-            rgb: [203, 182, 156],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "3865".to_string(),
-            name: "Winter White".to_string(),
-            rgb: [249, 247, 241],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "3866".to_string(),
-            name: "Mocha Brn Ult Vy Lt".to_string(),
-            rgb: [250, 246, 240],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "48".to_string(),
-            name: "Variegated Baby Pink".to_string(),
-            rgb: [255, 218, 233],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "51".to_string(),
-            name: "Variegated Burnt Orange".to_string(),
-            rgb: [235, 139, 102],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "52".to_string(),
-            name: "Variegated Violet".to_string(),
-            rgb: [149, 103, 170],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "53".to_string(),
-            name: "Variegated Steel Gray".to_string(),
-            rgb: [148, 162, 172],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "57".to_string(),
-            name: "Variegated Fuchsia".to_string(),
-            rgb: [200, 93, 143],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "61".to_string(),
-            name: "Variegated Pale Blue".to_string(),
-            rgb: [165, 200, 218],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "62".to_string(),
-            name: "Variegated Light Brown".to_string(),
-            rgb: [178, 149, 119],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "67".to_string(),
-            name: "Variegated Baby Yellow".to_string(),
-            rgb: [255, 246, 191],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "69".to_string(),
-            name: "Variegated Terra Cotta".to_string(),
-            rgb: [191, 117, 98],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "75".to_string(),
-            name: "Variegated Baby Pink".to_string(),
-            rgb: [239, 183, 200],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "90".to_string(),
-            name: "Variegated Yellow".to_string(),
-            rgb: [252, 219, 129],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "91".to_string(),
-            name: "Variegated Seafoam".to_string(),
-            rgb: [146, 205, 197],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "92".to_string(),
-            name: "Variegated Avocado".to_string(),
-            rgb: [161, 181, 130],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "93".to_string(),
-            name: "Variegated Cornflower Blue".to_string(),
-            rgb: [126, 153, 191],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "94".to_string(),
-            name: "Variegated Khaki".to_string(),
-            rgb: [162, 158, 122],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "95".to_string(),
-            name: "Variegated Fuchsia".to_string(),
-            rgb: [202, 123, 156],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "99".to_string(),
-            name: "Variegated Mauve".to_string(),
-            rgb: [177, 130, 148],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "101".to_string(),
-            name: "Variegated Blueberry".to_string(),
-            rgb: [94, 128, 168],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "102".to_string(),
-            name: "Variegated Grape".to_string(),
-            rgb: [129, 103, 139],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "103".to_string(),
-            name: "Variegated Plum".to_string(),
-            rgb: [155, 98, 130],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "104".to_string(),
-            name: "Variegated Rose".to_string(),
-            rgb: [194, 131, 142],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "105".to_string(),
-            name: "Variegated Tan Brown".to_string(),
-            rgb: [172, 143, 109],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "106".to_string(),
-            name: "Variegated Coral".to_string(),
-            rgb: [232, 159, 149],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "107".to_string(),
-            name: "Variegated Carnation".to_string(),
-            rgb: [234, 145, 162],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "108".to_string(),
-            name: "Variegated Lavender".to_string(),
-            rgb: [180, 159, 191],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "109".to_string(),
-            name: "Variegated Royal Blue".to_string(),
-            rgb: [79, 107, 163],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "110".to_string(),
-            name: "Variegated Black".to_string(),
-            rgb: [74, 74, 74],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "111".to_string(),
-            name: "Variegated Mustard".to_string(),
-            rgb: [201, 175, 107],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "112".to_string(),
-            name: "Variegated Tangerine".to_string(),
-            rgb: [244, 170, 108],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "113".to_string(),
-            name: "Variegated Purple".to_string(),
-            rgb: [145, 109, 152],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "114".to_string(),
-            name: "Variegated Lemon".to_string(),
-            rgb: [253, 233, 153],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "115".to_string(),
-            name: "Variegated Garnet".to_string(),
-            rgb: [161, 72, 86],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "116".to_string(),
-            name: "Variegated Mocha".to_string(),
-            rgb: [151, 123, 95],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "117".to_string(),
-            name: "Variegated Caribbean".to_string(),
-            rgb: [97, 165, 173],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "118".to_string(),
-            name: "Variegated Delft Blue".to_string(),
-            rgb: [121, 148, 184],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "119".to_string(),
-            name: "Variegated Wedgwood".to_string(),
-            rgb: [91, 138, 162],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "120".to_string(),
-            name: "Variegated Blue".to_string(),
-            rgb: [116, 161, 195],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "121".to_string(),
-            name: "Variegated Teal".to_string(),
-            rgb: [83, 148, 145],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "122".to_string(),
-            name: "Variegated Jade".to_string(),
-            rgb: [100, 164, 137],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "123".to_string(),
-            name: "Variegated Celery".to_string(),
-            rgb: [160, 188, 147],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "124".to_string(),
-            name: "Variegated Spruce".to_string(),
-            rgb: [95, 133, 114],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "125".to_string(),
-            name: "Variegated Seafoam".to_string(),
-            rgb: [124, 176, 169],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "126".to_string(),
-            name: "Variegated Coral Red".to_string(),
-            rgb: [212, 107, 107],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "127".to_string(),
-            name: "Variegated Antique Mauve".to_string(),
-            rgb: [173, 122, 133],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "E130".to_string(),
-            name: "Light Effects Pumpkin".to_string(),
-            rgb: [245, 139, 55],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "E135".to_string(),
-            name: "Light Effects Golden Yellow".to_string(),
-            rgb: [255, 210, 65],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "E168".to_string(),
-            name: "Light Effects Silver".to_string(),
-            rgb: [200, 200, 200],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "E211".to_string(),
-            name: "Light Effects Lilac".to_string(),
-            rgb: [205, 180, 219],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "E301".to_string(),
-            name: "Light Effects Copper".to_string(),
-            rgb: [200, 120, 60],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "E310".to_string(),
-            name: "Light Effects Black".to_string(),
-            rgb: [45, 45, 45],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "E316".to_string(),
-            name: "Light Effects Pink Garnet".to_string(),
-            rgb: [200, 100, 130],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "E317".to_string(),
-            name: "Light Effects Pewter".to_string(),
-            rgb: [140, 140, 145],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "E321".to_string(),
-            name: "Light Effects Red Ruby".to_string(),
-            rgb: [200, 50, 70],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "E334".to_string(),
-            name: "Light Effects Blue Topaz".to_string(),
-            rgb: [100, 160, 200],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "E436".to_string(),
-            name: "Light Effects Tan".to_string(),
-            rgb: [210, 165, 115],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "E677".to_string(),
-            name: "Light Effects White Gold".to_string(),
-            rgb: [245, 235, 200],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "E699".to_string(),
-            name: "Light Effects Green Emerald".to_string(),
-            rgb: [45, 140, 80],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "E703".to_string(),
-            name: "Light Effects Chartreuse".to_string(),
-            rgb: [140, 195, 80],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "E718".to_string(),
-            name: "Light Effects Magenta".to_string(),
-            rgb: [170, 60, 120],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "E746".to_string(),
-            name: "Light Effects Off White".to_string(),
-            rgb: [250, 245, 230],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "E747".to_string(),
-            name: "Light Effects Baby Blue".to_string(),
-            rgb: [200, 230, 245],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "E815".to_string(),
-            name: "Light Effects Garnet".to_string(),
-            rgb: [150, 35, 55],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "E818".to_string(),
-            name: "Light Effects Baby Pink".to_string(),
-            rgb: [255, 220, 220],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "E825".to_string(),
-            name: "Light Effects Blue".to_string(),
-            rgb: [75, 140, 175],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "E898".to_string(),
-            name: "Light Effects Coffee Brown".to_string(),
-            rgb: [95, 60, 40],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "E940".to_string(),
-            name: "Light Effects Glow in Dark".to_string(),
-            rgb: [230, 255, 230],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "E966".to_string(),
-            name: "Light Effects Baby Green".to_string(),
-            rgb: [180, 220, 190],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "E980".to_string(),
-            name: "Light Effects Neon Yellow".to_string(),
-            rgb: [255, 255, 100],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "E990".to_string(),
-            name: "Light Effects Neon Green".to_string(),
-            rgb: [100, 255, 120],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "E3821".to_string(),
-            name: "Light Effects Straw Gold".to_string(),
-            rgb: [240, 210, 130],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "E3837".to_string(),
-            name: "Light Effects Lavender".to_string(),
-            rgb: [140, 100, 160],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "E3843".to_string(),
-            name: "Light Effects Turquoise".to_string(),
-            rgb: [60, 180, 210],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "E3849".to_string(),
-            name: "Light Effects Teal".to_string(),
-            rgb: [90, 180, 165],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "E3852".to_string(),
-            name: "Light Effects Antique Gold".to_string(),
-            rgb: [195, 155, 65],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "E5200".to_string(),
-            name: "Light Effects Pure White".to_string(),
-            rgb: [255, 255, 255],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "S211".to_string(),
-            name: "Satin Lavender Light".to_string(),
-            rgb: [220, 200, 230],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "S310".to_string(),
-            name: "Satin Black".to_string(),
-            rgb: [30, 30, 30],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "S321".to_string(),
-            name: "Satin Red".to_string(),
-            rgb: [200, 50, 65],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "S326".to_string(),
-            name: "Satin Rose Very Dark".to_string(),
-            rgb: [175, 60, 75],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "S336".to_string(),
-            name: "Satin Navy Blue".to_string(),
-            rgb: [45, 65, 115],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "S355".to_string(),
-            name: "Satin Terra Cotta Dark".to_string(),
-            rgb: [155, 75, 60],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "S415".to_string(),
-            name: "Satin Pearl Gray".to_string(),
-            rgb: [205, 205, 210],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "S434".to_string(),
-            name: "Satin Brown Light".to_string(),
-            rgb: [160, 105, 65],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "S436".to_string(),
-            name: "Satin Tan".to_string(),
-            rgb: [205, 155, 95],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "S469".to_string(),
-            name: "Satin Avocado Green".to_string(),
-            rgb: [125, 145, 75],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "S471".to_string(),
-            name: "Satin Avocado Very Light".to_string(),
-            rgb: [180, 200, 135],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "S498".to_string(),
-            name: "Satin Christmas Red".to_string(),
-            rgb: [175, 35, 55],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "S501".to_string(),
-            name: "Satin Blue Green Dark".to_string(),
-            rgb: [65, 120, 95],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "S519".to_string(),
-            name: "Satin Sky Blue".to_string(),
-            rgb: [135, 185, 210],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "S550".to_string(),
-            name: "Satin Violet Very Dark".to_string(),
-            rgb: [100, 40, 90],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "S602".to_string(),
-            name: "Satin Cranberry Medium".to_string(),
-            rgb: [220, 80, 125],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "S666".to_string(),
-            name: "Satin Christmas Red Bright".to_string(),
-            rgb: [225, 40, 75],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "S699".to_string(),
-            name: "Satin Christmas Green".to_string(),
-            rgb: [25, 110, 45],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "S700".to_string(),
-            name: "Satin Christmas Green Bright".to_string(),
-            rgb: [35, 125, 50],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "S725".to_string(),
-            name: "Satin Topaz".to_string(),
-            rgb: [255, 205, 75],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "S738".to_string(),
-            name: "Satin Tan Very Light".to_string(),
-            rgb: [235, 210, 170],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "S741".to_string(),
-            name: "Satin Tangerine Medium".to_string(),
-            rgb: [255, 175, 65],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "S762".to_string(),
-            name: "Satin Pearl Gray Very Light".to_string(),
-            rgb: [235, 235, 235],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "S775".to_string(),
-            name: "Satin Baby Blue Very Light".to_string(),
-            rgb: [215, 235, 245],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "S798".to_string(),
-            name: "Satin Delft Blue Dark".to_string(),
-            rgb: [85, 120, 160],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "S799".to_string(),
-            name: "Satin Delft Blue Medium".to_string(),
-            rgb: [125, 155, 195],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "S800".to_string(),
-            name: "Satin Delft Blue Pale".to_string(),
-            rgb: [195, 210, 230],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "S806".to_string(),
-            name: "Satin Peacock Blue Dark".to_string(),
-            rgb: [75, 160, 175],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "S809".to_string(),
-            name: "Satin Delft Blue".to_string(),
-            rgb: [160, 180, 210],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "S817".to_string(),
-            name: "Satin Coral Red Very Dark".to_string(),
-            rgb: [190, 25, 50],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "S818".to_string(),
-            name: "Satin Baby Pink".to_string(),
-            rgb: [255, 225, 220],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "S838".to_string(),
-            name: "Satin Beige Brown Very Dark".to_string(),
-            rgb: [100, 85, 70],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "S869".to_string(),
-            name: "Satin Hazelnut Brown Very Dark".to_string(),
-            rgb: [145, 110, 75],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "S891".to_string(),
-            name: "Satin Carnation Dark".to_string(),
-            rgb: [255, 100, 130],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "S892".to_string(),
-            name: "Satin Carnation Medium".to_string(),
-            rgb: [255, 135, 155],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "S898".to_string(),
-            name: "Satin Coffee Brown Very Dark".to_string(),
-            rgb: [90, 60, 40],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "S899".to_string(),
-            name: "Satin Rose Medium".to_string(),
-            rgb: [245, 135, 155],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "S911".to_string(),
-            name: "Satin Emerald Green Medium".to_string(),
-            rgb: [45, 160, 120],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "S912".to_string(),
-            name: "Satin Emerald Green Light".to_string(),
-            rgb: [55, 175, 130],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "S943".to_string(),
-            name: "Satin Aquamarine Medium".to_string(),
-            rgb: [80, 165, 155],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "S945".to_string(),
-            name: "Satin Tawny".to_string(),
-            rgb: [250, 220, 200],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "S963".to_string(),
-            name: "Satin Dusty Rose Ultra Very Lt".to_string(),
-            rgb: [255, 220, 220],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "S964".to_string(),
-            name: "Satin Sea Green Light".to_string(),
-            rgb: [180, 230, 225],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "S966".to_string(),
-            name: "Satin Baby Green Medium".to_string(),
-            rgb: [195, 225, 205],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "S973".to_string(),
-            name: "Satin Canary Bright".to_string(),
-            rgb: [255, 235, 55],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "S3607".to_string(),
-            name: "Satin Plum Light".to_string(),
-            rgb: [210, 95, 160],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "S3608".to_string(),
-            name: "Satin Plum Very Light".to_string(),
-            rgb: [240, 175, 215],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "S3609".to_string(),
-            name: "Satin Plum Ultra Light".to_string(),
-            rgb: [250, 195, 230],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "S3756".to_string(),
-            name: "Satin Baby Blue Ultra Very Lt".to_string(),
-            rgb: [245, 255, 255],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "S3821".to_string(),
-            name: "Satin Straw".to_string(),
-            rgb: [250, 220, 140],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "S3823".to_string(),
-            name: "Satin Yellow Ultra Pale".to_string(),
-            rgb: [255, 255, 235],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "S5200".to_string(),
-            name: "Satin Snow White".to_string(),
-            rgb: [255, 255, 255],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "C155".to_string(),
-            name: "Étoile Blue Violet Med Dark".to_string(),
-            rgb: [160, 155, 195],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "C310".to_string(),
-            name: "Étoile Black".to_string(),
-            rgb: [35, 35, 35],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "C321".to_string(),
-            name: "Étoile Red".to_string(),
-            rgb: [205, 60, 80],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "C326".to_string(),
-            name: "Étoile Rose Very Dark".to_string(),
-            rgb: [185, 75, 95],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "C349".to_string(),
-            name: "Étoile Coral Dark".to_string(),
-            rgb: [215, 40, 75],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "C415".to_string(),
-            name: "Étoile Pearl Gray".to_string(),
-            rgb: [215, 215, 220],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "C435".to_string(),
-            name: "Étoile Brown Very Light".to_string(),
-            rgb: [195, 140, 95],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "C498".to_string(),
-            name: "Étoile Christmas Red Dark".to_string(),
-            rgb: [180, 40, 65],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "C517".to_string(),
-            name: "Étoile Wedgwood Dark".to_string(),
-            rgb: [80, 135, 165],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "C550".to_string(),
-            name: "Étoile Violet Very Dark".to_string(),
-            rgb: [110, 50, 100],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "C597".to_string(),
-            name: "Étoile Turquoise".to_string(),
-            rgb: [110, 180, 200],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "C598".to_string(),
-            name: "Étoile Turquoise Light".to_string(),
-            rgb: [160, 210, 220],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "C666".to_string(),
-            name: "Étoile Christmas Red Bright".to_string(),
-            rgb: [235, 55, 90],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "C699".to_string(),
-            name: "Étoile Christmas Green".to_string(),
-            rgb: [35, 120, 55],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "C700".to_string(),
-            name: "Étoile Christmas Green Bright".to_string(),
-            rgb: [45, 135, 60],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "C703".to_string(),
-            name: "Étoile Chartreuse".to_string(),
-            rgb: [140, 200, 95],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "C718".to_string(),
-            name: "Étoile Plum".to_string(),
-            rgb: [175, 60, 125],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "C740".to_string(),
-            name: "Étoile Tangerine".to_string(),
-            rgb: [255, 160, 55],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "C762".to_string(),
-            name: "Étoile Pearl Gray Very Light".to_string(),
-            rgb: [240, 240, 240],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "C796".to_string(),
-            name: "Étoile Royal Blue Dark".to_string(),
-            rgb: [40, 85, 135],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "C798".to_string(),
-            name: "Étoile Delft Blue Dark".to_string(),
-            rgb: [95, 130, 170],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "C820".to_string(),
-            name: "Étoile Royal Blue Very Dark".to_string(),
-            rgb: [35, 75, 120],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "C825".to_string(),
-            name: "Étoile Blue Dark".to_string(),
-            rgb: [90, 150, 185],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "C828".to_string(),
-            name: "Étoile Blue Ultra Very Light".to_string(),
-            rgb: [210, 240, 245],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "C890".to_string(),
-            name: "Étoile Pistachio Green Ult Dk".to_string(),
-            rgb: [45, 95, 60],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "C891".to_string(),
-            name: "Étoile Carnation Dark".to_string(),
-            rgb: [255, 110, 140],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "C894".to_string(),
-            name: "Étoile Carnation Very Light".to_string(),
-            rgb: [255, 195, 205],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "C906".to_string(),
-            name: "Étoile Parrot Green Medium".to_string(),
-            rgb: [145, 200, 85],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "C912".to_string(),
-            name: "Étoile Emerald Green Light".to_string(),
-            rgb: [60, 180, 135],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "C943".to_string(),
-            name: "Étoile Aquamarine Medium".to_string(),
-            rgb: [85, 170, 160],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "C3607".to_string(),
-            name: "Étoile Plum Light".to_string(),
-            rgb: [220, 105, 170],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "C3756".to_string(),
-            name: "Étoile Baby Blue Ultra Very Lt".to_string(),
-            rgb: [250, 255, 255],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-        ThreadColor {
-            code: "C5200".to_string(),
-            name: "Étoile Snow White".to_string(),
-            rgb: [255, 255, 255],
-            brand: ThreadBrand::DMC,
-            category: None,
-        },
-    ]
-}
@@ -2,9 +2,12 @@
 // Provides thread color data and color matching algorithms
 
 pub mod color_matching;
-pub mod dmc;
-pub mod anchor;
-pub mod kreinik;
+pub mod appearance;
+pub mod blend;
+pub mod builtin;
+pub mod conversion;
+pub mod custom;
+pub mod search;
 
 use serde::{Deserialize, Serialize};
 
@@ -17,26 +20,106 @@ pub struct ThreadColor {
     pub brand: ThreadBrand,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub category: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fiber: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub skein_length: Option<f64>, // Meters per skein
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub weight: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub strands: Option<u8>, // Strands in a length as sold
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub color_family: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub specialty: Option<ThreadSpecialty>,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub discontinued: bool,
+    /// Code the manufacturer recommends instead of a discontinued thread
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub replacement: Option<String>,
+}
+
+impl ThreadColor {
+    pub fn is_specialty(&self) -> bool {
+        self.specialty.is_some()
+    }
+}
+
+/// Threads that don't behave like plain floss when stitched
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ThreadSpecialty {
+    Variegated,
+    Metallic,
+    Glow,
+    Fluorescent,
+    Satin,
+    Sparkle,
 }
 
 /// Supported thread brands
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+/// Serialized as the brand name; names that are not built in refer to a custom library.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum ThreadBrand {
     DMC,
     Anchor,
     Kreinik,
+    Custom(String),
 }
 
 impl ThreadBrand {
-    pub fn as_str(&self) -> &'static str {
+    /// Every built-in brand
+    pub const ALL: [ThreadBrand; 3] = [ThreadBrand::DMC, ThreadBrand::Anchor, ThreadBrand::Kreinik];
+
+    pub fn as_str(&self) -> &str {
         match self {
             ThreadBrand::DMC => "DMC",
             ThreadBrand::Anchor => "Anchor",
             ThreadBrand::Kreinik => "Kreinik",
+            ThreadBrand::Custom(name) => name,
+        }
+    }
+
+    /// Built-in brand with this name (case-insensitive), otherwise a custom library
+    pub fn from_name(name: &str) -> ThreadBrand {
+        let name = name.trim();
+        ThreadBrand::ALL
+            .into_iter()
+            .find(|brand| brand.as_str().eq_ignore_ascii_case(name))
+            .unwrap_or_else(|| ThreadBrand::Custom(name.to_string()))
+    }
+
+    pub fn is_custom(&self) -> bool {
+        matches!(self, ThreadBrand::Custom(_))
+    }
+}
+
+impl std::str::FromStr for ThreadBrand {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let brand = ThreadBrand::from_name(s);
+        match &brand {
+            ThreadBrand::Custom(name) if name.is_empty() => Err("Thread brand is empty".to_string()),
+            ThreadBrand::Custom(name) if !custom::library_exists(name) => Err(format!("Unknown thread brand: {}", s)),
+            _ => Ok(brand),
         }
     }
 }
 
+impl Serialize for ThreadBrand {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for ThreadBrand {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let name = String::deserialize(deserializer)?;
+        Ok(ThreadBrand::from_name(&name))
+    }
+}
+
 impl std::fmt::Display for ThreadBrand {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.as_str())
@@ -44,20 +127,30 @@ impl std::fmt::Display for ThreadBrand {
 }
 
 /// Get all threads for a specific brand
-pub fn get_threads_by_brand(brand: ThreadBrand) -> Vec<ThreadColor> {
+pub fn get_threads_by_brand(brand: &ThreadBrand) -> Vec<ThreadColor> {
     match brand {
-        ThreadBrand::DMC => dmc::get_dmc_threads(),
-        ThreadBrand::Anchor => anchor::get_anchor_threads(),
-        ThreadBrand::Kreinik => kreinik::get_kreinik_threads(),
+        ThreadBrand::Custom(name) => custom::get_custom_threads(name),
+        _ => builtin::threads(brand).to_vec(),
+    }
+}
+
+/// Look up a thread by brand and code (case-insensitive)
+pub fn find_thread(brand: &ThreadBrand, code: &str) -> Option<ThreadColor> {
+    match brand {
+        ThreadBrand::Custom(name) => custom::find_custom_thread(name, code),
+        _ => builtin::library(brand).and_then(|l| l.find(code)).cloned(),
     }
 }
 
 /// Get all available threads from all brands
 pub fn get_all_threads() -> Vec<ThreadColor> {
     let mut threads = Vec::new();
-    threads.extend(dmc::get_dmc_threads());
-    threads.extend(anchor::get_anchor_threads());
-    threads.extend(kreinik::get_kreinik_threads());
+    for brand in ThreadBrand::ALL {
+        threads.extend_from_slice(builtin::threads(&brand));
+    }
+    for library in custom::get_custom_library_infos() {
+        threads.extend(custom::get_custom_threads(library.brand.as_str()));
+    }
     threads
 }
 
@@ -72,24 +165,55 @@ pub struct ThreadLibraryInfo {
 
 /// Get available thread libraries
 pub fn get_thread_libraries() -> Vec<ThreadLibraryInfo> {
-    vec![
+    let mut libraries = vec![
         ThreadLibraryInfo {
             brand: ThreadBrand::DMC,
             name: "DMC".to_string(),
             description: "DMC Cotton Embroidery Floss - Industry standard".to_string(),
-            color_count: dmc::get_dmc_threads().len(),
+            color_count: builtin::threads(&ThreadBrand::DMC).len(),
         },
         ThreadLibraryInfo {
             brand: ThreadBrand::Anchor,
             name: "Anchor Stranded".to_string(),
             description: "Anchor Stranded Cotton - Popular alternative".to_string(),
-            color_count: anchor::get_anchor_threads().len(),
+            color_count: builtin::threads(&ThreadBrand::Anchor).len(),
         },
         ThreadLibraryInfo {
             brand: ThreadBrand::Kreinik,
             name: "Kreinik Metallics".to_string(),
             description: "Kreinik Metallic Threads - Premium metallics".to_string(),
-            color_count: kreinik::get_kreinik_threads().len(),
+            color_count: builtin::threads(&ThreadBrand::Kreinik).len(),
         },
-    ]
+    ];
+    libraries.extend(custom::get_custom_library_infos());
+    libraries
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_brand_libraries() {
+        for brand in ThreadBrand::ALL {
+            let threads = get_threads_by_brand(&brand);
+            assert!(!threads.is_empty(), "{} has no threads", brand);
+            assert!(threads.iter().all(|t| t.brand == brand));
+            assert_eq!(brand.as_str().to_lowercase().parse::<ThreadBrand>(), Ok(brand));
+        }
+
+        let libraries = get_thread_libraries();
+        assert_eq!(libraries.len(), ThreadBrand::ALL.len() + custom::get_custom_library_infos().len());
+        assert_eq!(get_all_threads().len(), libraries.iter().map(|l| l.color_count).sum::<usize>());
+    }
+
+    #[test]
+    fn test_brand_serializes_as_name() {
+        assert_eq!(serde_json::to_string(&ThreadBrand::DMC).unwrap(), "\"DMC\"");
+        let custom: ThreadBrand = serde_json::from_str("\"Silk & Ivory\"").unwrap();
+        assert_eq!(custom, ThreadBrand::Custom("Silk & Ivory".to_string()));
+        assert_eq!(serde_json::to_string(&custom).unwrap(), "\"Silk & Ivory\"");
+        assert!("".parse::<ThreadBrand>().is_err());
+        assert!("No Such Brand".parse::<ThreadBrand>().is_err());
+    }
 }
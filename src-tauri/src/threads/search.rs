@@ -0,0 +1,251 @@
+// Thread Search Module
+// Paged thread library queries by text, code, category and color proximity
+
+use super::color_matching::{color_distance, delta_e2000, ColorMatchAlgorithm};
+use super::{find_thread, get_thread_libraries, get_threads_by_brand, ThreadBrand, ThreadColor, ThreadLibraryInfo};
+use serde::{Deserialize, Serialize};
+
+/// Page size used when a query doesn't set one
+const DEFAULT_PAGE_SIZE: usize = 100;
+
+/// Matches returned by find_nearest_threads when `k` isn't set
+const DEFAULT_NEAREST_COUNT: usize = 10;
+
+/// Thread query; empty fields match everything
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct ThreadQuery {
+    /// Brands to search (all libraries when empty)
+    #[serde(default)]
+    pub brands: Vec<ThreadBrand>,
+    /// Every whitespace-separated term must appear in the code or name
+    #[serde(default)]
+    pub text: Option<String>,
+    /// Exact code (case-insensitive)
+    #[serde(default)]
+    pub code: Option<String>,
+    #[serde(default)]
+    pub category: Option<String>,
+    /// Sort by distance to this color instead of library order
+    #[serde(default)]
+    pub near: Option<[u8; 3]>,
+    /// Drop threads farther than this from `near`
+    #[serde(default)]
+    pub max_distance: Option<f64>,
+    #[serde(default)]
+    pub algorithm: Option<ColorMatchAlgorithm>,
+    #[serde(default)]
+    pub offset: usize,
+    #[serde(default)]
+    pub limit: Option<usize>,
+}
+
+/// A matching thread and its distance from the query color
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ThreadSearchHit {
+    pub thread: ThreadColor,
+    pub distance: Option<f64>,
+}
+
+/// One page of search results
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ThreadSearchPage {
+    pub threads: Vec<ThreadSearchHit>,
+    pub total: usize, // Matches before paging
+    pub offset: usize,
+}
+
+/// A thread ranked by closeness to a target color
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NearestThread {
+    pub thread: ThreadColor,
+    pub distance: f64, // Under the requested algorithm, used for ranking
+    pub delta_e: f64,  // CIEDE2000
+}
+
+impl ThreadQuery {
+    fn matches(&self, thread: &ThreadColor) -> bool {
+        if let Some(text) = self.text.as_deref().filter(|t| !t.trim().is_empty()) {
+            let code = thread.code.to_lowercase();
+            let name = thread.name.to_lowercase();
+            if !text
+                .split_whitespace()
+                .map(|term| term.to_lowercase())
+                .all(|term| code.contains(&term) || name.contains(&term))
+            {
+                return false;
+            }
+        }
+
+        self.code
+            .as_deref()
+            .filter(|c| !c.trim().is_empty())
+            .is_none_or(|c| thread.code.eq_ignore_ascii_case(c.trim()))
+            && self
+                .category
+                .as_deref()
+                .filter(|c| !c.trim().is_empty())
+                .is_none_or(|c| thread.category.as_deref().is_some_and(|tc| tc.eq_ignore_ascii_case(c.trim())))
+    }
+}
+
+/// Run a query against the thread libraries
+pub fn search_threads(query: &ThreadQuery) -> ThreadSearchPage {
+    let brands: Vec<ThreadBrand> = if query.brands.is_empty() {
+        get_thread_libraries().into_iter().map(|l| l.brand).collect()
+    } else {
+        query.brands.clone()
+    };
+    let algorithm = query.algorithm.unwrap_or_default();
+
+    let mut hits: Vec<ThreadSearchHit> = brands
+        .iter()
+        .flat_map(get_threads_by_brand)
+        .filter(|thread| query.matches(thread))
+        .map(|thread| ThreadSearchHit {
+            distance: query.near.map(|rgb| color_distance(rgb, thread.rgb, algorithm)),
+            thread,
+        })
+        .filter(|hit| match (hit.distance, query.max_distance) {
+            (Some(distance), Some(max)) => distance <= max,
+            _ => true,
+        })
+        .collect();
+
+    if query.near.is_some() {
+        hits.sort_by(|a, b| a.distance.unwrap_or(0.0).total_cmp(&b.distance.unwrap_or(0.0)));
+    }
+
+    let total = hits.len();
+    let threads = hits
+        .into_iter()
+        .skip(query.offset)
+        .take(query.limit.unwrap_or(DEFAULT_PAGE_SIZE))
+        .collect();
+
+    ThreadSearchPage { threads, total, offset: query.offset }
+}
+
+/// The `k` threads closest to `rgb` across `brands` (all libraries when empty)
+/// Threads more than `max_delta_e` (CIEDE2000) away are left out.
+pub fn find_nearest_threads(
+    rgb: [u8; 3],
+    brands: &[ThreadBrand],
+    algorithm: ColorMatchAlgorithm,
+    k: usize,
+    max_delta_e: Option<f64>,
+) -> Vec<NearestThread> {
+    let brands: Vec<ThreadBrand> = if brands.is_empty() {
+        get_thread_libraries().into_iter().map(|l| l.brand).collect()
+    } else {
+        brands.to_vec()
+    };
+
+    let mut nearest: Vec<NearestThread> = brands
+        .iter()
+        .flat_map(get_threads_by_brand)
+        .map(|thread| NearestThread {
+            distance: color_distance(rgb, thread.rgb, algorithm),
+            delta_e: delta_e2000(rgb, thread.rgb),
+            thread,
+        })
+        .filter(|m| max_delta_e.is_none_or(|max| m.delta_e <= max))
+        .collect();
+
+    nearest.sort_by(|a, b| a.distance.total_cmp(&b.distance).then(a.delta_e.total_cmp(&b.delta_e)));
+    nearest.truncate(k);
+    nearest
+}
+
+/// Tauri commands for browsing the thread libraries
+pub mod commands {
+    use super::*;
+
+    /// List the built-in and custom thread libraries
+    #[tauri::command]
+    pub fn get_thread_libraries() -> Vec<ThreadLibraryInfo> {
+        super::get_thread_libraries()
+    }
+
+    /// Search threads by text, code, category or color
+    #[tauri::command]
+    pub fn search_threads(query: ThreadQuery) -> ThreadSearchPage {
+        super::search_threads(&query)
+    }
+
+    /// Rank the threads closest to a color, e.g. one picked with the eyedropper
+    #[tauri::command]
+    pub fn find_nearest_threads(
+        rgb: [u8; 3],
+        brands: Option<Vec<ThreadBrand>>,
+        algorithm: Option<ColorMatchAlgorithm>,
+        k: Option<usize>,
+        max_delta_e: Option<f64>,
+    ) -> Vec<NearestThread> {
+        super::find_nearest_threads(
+            rgb,
+            &brands.unwrap_or_default(),
+            algorithm.unwrap_or_default(),
+            k.unwrap_or(DEFAULT_NEAREST_COUNT),
+            max_delta_e,
+        )
+    }
+
+    /// Get one thread by brand and code
+    #[tauri::command]
+    pub fn get_thread(brand: ThreadBrand, code: String) -> Result<ThreadColor, String> {
+        find_thread(&brand, &code).ok_or_else(|| format!("Unknown {} thread: {}", brand, code))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_search_threads() {
+        let query = ThreadQuery { brands: vec![ThreadBrand::DMC], text: Some("black".to_string()), ..Default::default() };
+        let page = search_threads(&query);
+        assert!(page.threads.iter().any(|hit| hit.thread.code == "310"));
+        assert!(page.threads.iter().all(|hit| hit.thread.name.to_lowercase().contains("black") && hit.distance.is_none()));
+
+        // The same number names different threads in each brand
+        let query = ThreadQuery { code: Some("300".to_string()), ..Default::default() };
+        let page = search_threads(&query);
+        assert_eq!(page.total, 2);
+        assert_eq!(page.threads[0].thread.name, "Mahogany Very Dark");
+        assert_eq!(page.threads[1].thread.brand, ThreadBrand::Anchor);
+
+        let query = ThreadQuery {
+            brands: vec![ThreadBrand::DMC],
+            category: Some("satin".to_string()),
+            near: Some([0, 0, 0]),
+            limit: Some(3),
+            ..Default::default()
+        };
+        let page = search_threads(&query);
+        assert_eq!(page.threads.len(), 3);
+        assert!(page.total > 3);
+        assert!(page.threads.iter().all(|hit| hit.thread.category.as_deref() == Some("satin")));
+        assert!(page.threads.windows(2).all(|w| w[0].distance <= w[1].distance));
+
+        let next = search_threads(&ThreadQuery { offset: 3, ..query });
+        assert_eq!(next.offset, 3);
+        assert!(next.threads[0].distance >= page.threads[2].distance);
+    }
+
+    #[test]
+    fn test_find_nearest_threads() {
+        let brands = [ThreadBrand::DMC, ThreadBrand::Anchor];
+        let nearest = find_nearest_threads([0, 0, 0], &brands, ColorMatchAlgorithm::Ciede2000, 5, None);
+        assert_eq!(nearest.len(), 5);
+        assert_eq!(nearest[0].distance, 0.0);
+        assert!(nearest.iter().take(2).any(|m| m.thread.brand == ThreadBrand::Anchor && m.thread.code == "403"));
+        assert!(nearest.windows(2).all(|w| w[0].distance <= w[1].distance));
+
+        let close = find_nearest_threads([0, 0, 0], &brands, ColorMatchAlgorithm::Weighted, 50, Some(3.0));
+        assert!(!close.is_empty() && close.len() < 50);
+        assert!(close.iter().all(|m| m.delta_e <= 3.0));
+
+        assert!(find_nearest_threads([0, 0, 0], &brands, ColorMatchAlgorithm::Cie76, 0, None).is_empty());
+    }
+}
@@ -0,0 +1,495 @@
+// Thumbnail Module
+// Renders home screen previews directly from project contents
+
+use crate::export::{self, ImageExportOptions};
+use crate::NdpFile;
+use base64::{engine::general_purpose::STANDARD, Engine};
+use image::{imageops::FilterType, DynamicImage, ImageFormat, RgbaImage};
+use std::fs;
+use std::io::Cursor;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+use tauri::Manager;
+
+/// Maximum thumbnail width/height in pixels (matches the frontend preview)
+pub const THUMBNAIL_SIZE: u32 = 200;
+
+/// Largest cell size used when a small pattern is scaled up
+const THUMBNAIL_MAX_CELL: f64 = 8.0;
+
+/// FNV-1a hasher whose output is stable across Rust versions and platforms
+/// (std's DefaultHasher makes no such guarantee, so it can't be persisted)
+pub struct StableHasher(u64);
+
+impl StableHasher {
+    const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+    const PRIME: u64 = 0x0000_0100_0000_01b3;
+
+    pub fn new() -> Self {
+        StableHasher(Self::OFFSET_BASIS)
+    }
+
+    pub fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.0 ^= *byte as u64;
+            self.0 = self.0.wrapping_mul(Self::PRIME);
+        }
+    }
+
+    pub fn write_u32(&mut self, value: u32) {
+        self.write(&value.to_le_bytes());
+    }
+
+    pub fn write_u64(&mut self, value: u64) {
+        self.write(&value.to_le_bytes());
+    }
+
+    /// Length-prefixed so that adjacent strings can't run together
+    pub fn write_str(&mut self, value: &str) {
+        self.write_u64(value.len() as u64);
+        self.write(value.as_bytes());
+    }
+
+    pub fn finish(&self) -> u64 {
+        self.0
+    }
+
+    pub fn finish_hex(&self) -> String {
+        format!("{:016x}", self.finish())
+    }
+}
+
+impl Default for StableHasher {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Add one stitch's visible fields to a layer digest
+pub fn write_stitch(hasher: &mut StableHasher, x: u32, y: u32, color_id: &str, stitch_type: Option<&str>, position: Option<&str>) {
+    hasher.write_u32(x);
+    hasher.write_u32(y);
+    hasher.write_str(color_id);
+    hasher.write_str(stitch_type.unwrap_or(""));
+    hasher.write_str(position.unwrap_or(""));
+}
+
+/// Add one palette color to a palette digest
+pub fn write_color(hasher: &mut StableHasher, id: &str, rgb: [u8; 3]) {
+    hasher.write_str(id);
+    hasher.write(&rgb);
+}
+
+/// Combine the canvas size, palette digest and visible layer digests into a fingerprint
+/// Layers are digested separately so the header reader can stream them in any key order
+pub fn combine_fingerprint<'a>(width: u32, height: u32, palette: u64, visible_layers: impl IntoIterator<Item = (&'a str, u64)>) -> String {
+    let mut hasher = StableHasher::new();
+    hasher.write_u32(width);
+    hasher.write_u32(height);
+    hasher.write_u64(palette);
+    for (id, digest) in visible_layers {
+        hasher.write_str(id);
+        hasher.write_u64(digest);
+    }
+    hasher.finish_hex()
+}
+
+/// Fingerprint of everything visible in a thumbnail
+/// Stored next to the thumbnail so a stale preview can be detected on load
+pub fn content_fingerprint(project: &NdpFile) -> String {
+    let mut palette = StableHasher::new();
+    for color in &project.color_palette {
+        write_color(&mut palette, &color.id, color.rgb);
+    }
+
+    let layers: Vec<(&str, u64)> = project
+        .layers
+        .iter()
+        .filter(|l| l.visible)
+        .map(|layer| {
+            let mut hasher = StableHasher::new();
+            for stitch in &layer.stitches {
+                write_stitch(&mut hasher, stitch.x, stitch.y, &stitch.color_id, stitch.stitch_type.as_deref(), stitch.position.as_deref());
+            }
+            hasher.write_u64(layer.stitches.len() as u64);
+            (layer.id.as_str(), hasher.finish())
+        })
+        .collect();
+
+    combine_fingerprint(project.canvas.width, project.canvas.height, palette.finish(), layers)
+}
+
+/// Size of the thumbnail for a canvas, fitting within THUMBNAIL_SIZE
+fn thumbnail_dimensions(width: u32, height: u32) -> (u32, u32) {
+    let cell_size = (THUMBNAIL_SIZE as f64 / width as f64)
+        .min(THUMBNAIL_SIZE as f64 / height as f64)
+        .min(THUMBNAIL_MAX_CELL);
+
+    (
+        ((width as f64 * cell_size).round() as u32).max(1),
+        ((height as f64 * cell_size).round() as u32).max(1),
+    )
+}
+
+/// Render a thumbnail image from the project's visible layers
+pub fn render_thumbnail(project: &NdpFile) -> Result<RgbaImage, String> {
+    let (width, height) = thumbnail_dimensions(project.canvas.width.max(1), project.canvas.height.max(1));
+
+    // Render one pixel per stitch, then scale to the thumbnail size
+    let options = ImageExportOptions {
+        pixels_per_stitch: 1,
+        background: Some([255, 255, 255]),
+        ..Default::default()
+    };
+    let full = export::render_pattern(project, &options)?;
+
+    let filter = if width >= full.width() {
+        FilterType::Nearest
+    } else {
+        FilterType::Triangle
+    };
+
+    Ok(image::imageops::resize(&full, width, height, filter))
+}
+
+/// Render a thumbnail as a PNG data URL
+pub fn render_thumbnail_data_url(project: &NdpFile) -> Result<String, String> {
+    let img = render_thumbnail(project)?;
+    crate::image_to_base64(&DynamicImage::ImageRgba8(img))
+}
+
+/// Whether the embedded thumbnail exists and matches the project contents
+pub fn is_thumbnail_current(project: &NdpFile) -> bool {
+    project.thumbnail.is_some()
+        && project.thumbnail_fingerprint.as_deref() == Some(content_fingerprint(project).as_str())
+}
+
+/// Regenerate the embedded thumbnail if it is missing or stale
+/// Returns true if the thumbnail was replaced
+pub fn refresh_thumbnail(project: &mut NdpFile) -> Result<bool, String> {
+    let fingerprint = content_fingerprint(project);
+    if project.thumbnail.is_some() && project.thumbnail_fingerprint.as_deref() == Some(fingerprint.as_str()) {
+        return Ok(false);
+    }
+
+    project.thumbnail = Some(render_thumbnail_data_url(project)?);
+    project.thumbnail_fingerprint = Some(fingerprint);
+    Ok(true)
+}
+
+/// Get an up-to-date thumbnail for a project, rendering one if needed
+pub fn current_thumbnail(project: &NdpFile) -> Option<String> {
+    if is_thumbnail_current(project) {
+        return project.thumbnail.clone();
+    }
+
+    render_thumbnail_data_url(project)
+        .ok()
+        .or_else(|| project.thumbnail.clone())
+}
+
+// ============================================================================
+// Thumbnail Cache
+// ============================================================================
+//
+// Entries are PNG files named `{path hash}-{version hash}.png`, where the
+// version hash covers the project file's size and modification time. Editing a
+// project therefore misses the cache instead of serving a stale preview. Entry
+// mtimes record last use, and the least recently used entries are evicted once
+// the cache grows past THUMBNAIL_CACHE_MAX_BYTES.
+
+/// Upper bound on the total size of cached thumbnails
+pub const THUMBNAIL_CACHE_MAX_BYTES: u64 = 32 * 1024 * 1024;
+
+/// Get the thumbnail cache directory path
+fn get_thumbnail_cache_dir(app: &tauri::AppHandle) -> Result<PathBuf, String> {
+    let cache_dir = app.path()
+        .app_cache_dir()
+        .map_err(|e| format!("Failed to get cache directory: {}", e))?;
+    Ok(cache_dir.join("thumbnails"))
+}
+
+/// Cache key prefix identifying a project file by path
+fn path_key(file_path: &Path) -> String {
+    let mut hasher = StableHasher::new();
+    hasher.write_str(&file_path.to_string_lossy());
+    hasher.finish_hex()
+}
+
+/// Cache key suffix identifying one version of a project file
+fn version_key(metadata: &fs::Metadata) -> String {
+    let modified = metadata
+        .modified()
+        .ok()
+        .and_then(|t| t.duration_since(UNIX_EPOCH).ok())
+        .map(|d| d.as_nanos() as u64)
+        .unwrap_or(0);
+
+    let mut hasher = StableHasher::new();
+    hasher.write_u64(modified);
+    hasher.write_u64(metadata.len());
+    hasher.finish_hex()
+}
+
+/// Cache file for the current version of a project file
+fn cache_entry_path(cache_dir: &Path, file_path: &Path) -> Option<PathBuf> {
+    let metadata = fs::metadata(file_path).ok()?;
+    Some(cache_dir.join(format!("{}-{}.png", path_key(file_path), version_key(&metadata))))
+}
+
+/// Decode a thumbnail data URL into PNG bytes
+fn data_url_to_png(data_url: &str) -> Result<Vec<u8>, String> {
+    let base64_data = data_url.split_once(',').map(|(_, data)| data).unwrap_or(data_url);
+    let bytes = STANDARD
+        .decode(base64_data)
+        .map_err(|e| format!("Failed to decode thumbnail: {}", e))?;
+
+    if image::guess_format(&bytes).ok() == Some(ImageFormat::Png) {
+        return Ok(bytes);
+    }
+
+    // Re-encode other formats so the cache only ever holds PNG
+    let img = image::load_from_memory(&bytes)
+        .map_err(|e| format!("Failed to load thumbnail: {}", e))?;
+    let mut png: Vec<u8> = Vec::new();
+    img.write_to(&mut Cursor::new(&mut png), ImageFormat::Png)
+        .map_err(|e| format!("Failed to encode thumbnail: {}", e))?;
+    Ok(png)
+}
+
+/// Save a thumbnail to the cache for the current version of a file
+pub fn cache_thumbnail(app: &tauri::AppHandle, file_path: &Path, thumbnail_data: &str) -> Result<(), String> {
+    let cache_dir = get_thumbnail_cache_dir(app)?;
+    store_thumbnail(&cache_dir, file_path, thumbnail_data, THUMBNAIL_CACHE_MAX_BYTES)
+}
+
+fn store_thumbnail(cache_dir: &Path, file_path: &Path, thumbnail_data: &str, max_bytes: u64) -> Result<(), String> {
+    fs::create_dir_all(cache_dir)
+        .map_err(|e| format!("Failed to create cache directory: {}", e))?;
+
+    let cache_file = cache_entry_path(cache_dir, file_path)
+        .ok_or_else(|| format!("Failed to read file metadata: {:?}", file_path))?;
+    let png = data_url_to_png(thumbnail_data)?;
+
+    fs::write(&cache_file, png)
+        .map_err(|e| format!("Failed to write cache file: {}", e))?;
+
+    // Drop entries for older versions of the same file
+    remove_entries(cache_dir, file_path, Some(&cache_file));
+
+    enforce_cache_limit(cache_dir, max_bytes)
+}
+
+/// Remove every cached version of a file, except `keep`
+fn remove_entries(cache_dir: &Path, file_path: &Path, keep: Option<&Path>) {
+    let prefix = format!("{}-", path_key(file_path));
+    if let Ok(entries) = fs::read_dir(cache_dir) {
+        for entry in entries.flatten() {
+            let path = entry.path();
+            let is_sibling = entry.file_name().to_string_lossy().starts_with(&prefix);
+            if is_sibling && Some(path.as_path()) != keep {
+                let _ = fs::remove_file(&path);
+            }
+        }
+    }
+}
+
+/// Drop cached thumbnails for a file that was deleted or moved
+pub fn forget_thumbnail(app: &tauri::AppHandle, file_path: &Path) {
+    if let Ok(cache_dir) = get_thumbnail_cache_dir(app) {
+        remove_entries(&cache_dir, file_path, None);
+    }
+}
+
+/// Load a thumbnail from the cache if the file hasn't changed since it was cached
+pub fn get_cached_thumbnail(app: &tauri::AppHandle, file_path: &Path) -> Option<String> {
+    let cache_dir = get_thumbnail_cache_dir(app).ok()?;
+    load_thumbnail(&cache_dir, file_path)
+}
+
+fn load_thumbnail(cache_dir: &Path, file_path: &Path) -> Option<String> {
+    let cache_file = cache_entry_path(cache_dir, file_path)?;
+    let bytes = fs::read(&cache_file).ok()?;
+
+    // Record the access for LRU eviction
+    if let Ok(file) = fs::File::options().write(true).open(&cache_file) {
+        let _ = file.set_modified(SystemTime::now());
+    }
+
+    Some(format!("data:image/png;base64,{}", STANDARD.encode(&bytes)))
+}
+
+/// Evict least recently used entries until the cache fits within max_bytes
+/// Also removes entries left behind by the old text-based cache format
+fn enforce_cache_limit(cache_dir: &Path, max_bytes: u64) -> Result<(), String> {
+    let entries = fs::read_dir(cache_dir)
+        .map_err(|e| format!("Failed to read cache directory: {}", e))?;
+
+    let mut files: Vec<(PathBuf, SystemTime, u64)> = Vec::new();
+    for entry in entries.flatten() {
+        let path = entry.path();
+        let Ok(metadata) = entry.metadata() else {
+            continue;
+        };
+        if !metadata.is_file() {
+            continue;
+        }
+        if path.extension().and_then(|e| e.to_str()) != Some("png") {
+            let _ = fs::remove_file(&path);
+            continue;
+        }
+        let last_used = metadata.modified().unwrap_or(UNIX_EPOCH);
+        files.push((path, last_used, metadata.len()));
+    }
+
+    let mut total: u64 = files.iter().map(|(_, _, size)| size).sum();
+    if total <= max_bytes {
+        return Ok(());
+    }
+
+    // Oldest first
+    files.sort_by_key(|(_, last_used, _)| *last_used);
+    for (path, _, size) in files {
+        if total <= max_bytes {
+            break;
+        }
+        if fs::remove_file(&path).is_ok() {
+            total = total.saturating_sub(size);
+        }
+    }
+
+    Ok(())
+}
+
+/// Delete every cached thumbnail, returning the number of bytes freed
+pub fn clear_thumbnail_cache(app: &tauri::AppHandle) -> Result<u64, String> {
+    let cache_dir = get_thumbnail_cache_dir(app)?;
+    clear_cache_dir(&cache_dir)
+}
+
+fn clear_cache_dir(cache_dir: &Path) -> Result<u64, String> {
+    if !cache_dir.exists() {
+        return Ok(0);
+    }
+
+    let mut freed: u64 = 0;
+    let entries = fs::read_dir(cache_dir)
+        .map_err(|e| format!("Failed to read cache directory: {}", e))?;
+    for entry in entries.flatten() {
+        let size = entry.metadata().map(|m| m.len()).unwrap_or(0);
+        if entry.path().is_file() && fs::remove_file(entry.path()).is_ok() {
+            freed += size;
+        }
+    }
+
+    Ok(freed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures;
+
+    fn test_project(width: u32, height: u32) -> NdpFile {
+        let mut project = fixtures::project(width, height, vec![fixtures::color("c1", [0, 0, 0])], vec![fixtures::stitch(0, 0, "c1")]);
+        project.canvas.mesh_count = 18;
+        project
+    }
+
+    #[test]
+    fn test_thumbnail_dimensions() {
+        // Small patterns are capped at 8px per cell
+        assert_eq!(thumbnail_dimensions(10, 5), (80, 40));
+        // Large patterns fit within the thumbnail bounds
+        assert_eq!(thumbnail_dimensions(400, 100), (200, 50));
+    }
+
+    #[test]
+    fn test_render_thumbnail() {
+        let project = test_project(10, 10);
+        let img = render_thumbnail(&project).unwrap();
+        assert_eq!(img.dimensions(), (80, 80));
+        assert_eq!(img.get_pixel(3, 3).0, [0, 0, 0, 255]);
+        assert_eq!(img.get_pixel(40, 40).0, [255, 255, 255, 255]);
+    }
+
+    #[test]
+    fn test_refresh_thumbnail_detects_stale_content() {
+        let mut project = test_project(10, 10);
+        assert!(refresh_thumbnail(&mut project).unwrap());
+        assert!(is_thumbnail_current(&project));
+        assert!(!refresh_thumbnail(&mut project).unwrap());
+
+        project.layers[0].stitches[0].x = 5;
+        assert!(!is_thumbnail_current(&project));
+        assert!(refresh_thumbnail(&mut project).unwrap());
+    }
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("stitchalot-thumb-test-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn test_thumbnail_cache_invalidates_on_change() {
+        let dir = temp_dir("invalidate");
+        let cache_dir = dir.join("cache");
+        let project_path = dir.join("design.stitchalot");
+        fs::write(&project_path, "{}").unwrap();
+
+        let thumb = render_thumbnail_data_url(&test_project(4, 4)).unwrap();
+        store_thumbnail(&cache_dir, &project_path, &thumb, THUMBNAIL_CACHE_MAX_BYTES).unwrap();
+        assert_eq!(load_thumbnail(&cache_dir, &project_path), Some(thumb.clone()));
+
+        // A different size changes the version key, so the old entry is a miss
+        fs::write(&project_path, "{\"changed\": true}").unwrap();
+        assert_eq!(load_thumbnail(&cache_dir, &project_path), None);
+
+        // Storing the new version replaces the old entry
+        store_thumbnail(&cache_dir, &project_path, &thumb, THUMBNAIL_CACHE_MAX_BYTES).unwrap();
+        assert_eq!(fs::read_dir(&cache_dir).unwrap().count(), 1);
+
+        assert!(clear_cache_dir(&cache_dir).unwrap() > 0);
+        assert_eq!(fs::read_dir(&cache_dir).unwrap().count(), 0);
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_thumbnail_cache_evicts_least_recently_used() {
+        let dir = temp_dir("evict");
+        let cache_dir = dir.join("cache");
+        fs::create_dir_all(&cache_dir).unwrap();
+        fs::write(cache_dir.join("legacy.txt"), "data:image/png;base64,").unwrap();
+
+        let thumb = render_thumbnail_data_url(&test_project(4, 4)).unwrap();
+        let entry_size = data_url_to_png(&thumb).unwrap().len() as u64;
+
+        let paths: Vec<PathBuf> = (0..3).map(|i| dir.join(format!("p{}.stitchalot", i))).collect();
+        for (i, path) in paths.iter().enumerate() {
+            fs::write(path, "{}").unwrap();
+            store_thumbnail(&cache_dir, path, &thumb, u64::MAX).unwrap();
+            // Give each entry a distinct last-used time
+            let entry = cache_entry_path(&cache_dir, path).unwrap();
+            let file = fs::File::options().write(true).open(entry).unwrap();
+            file.set_modified(UNIX_EPOCH + std::time::Duration::from_secs(1000 + i as u64)).unwrap();
+        }
+
+        enforce_cache_limit(&cache_dir, entry_size * 2).unwrap();
+
+        assert!(!cache_dir.join("legacy.txt").exists());
+        assert_eq!(load_thumbnail(&cache_dir, &paths[0]), None);
+        assert!(load_thumbnail(&cache_dir, &paths[1]).is_some());
+        assert!(load_thumbnail(&cache_dir, &paths[2]).is_some());
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_stable_hasher_is_deterministic() {
+        let mut hasher = StableHasher::new();
+        hasher.write(b"a");
+        // Published FNV-1a 64-bit test vector for "a"
+        assert_eq!(hasher.finish(), 0xaf63dc4c8601ec8c);
+    }
+}
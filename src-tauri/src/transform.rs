@@ -0,0 +1,459 @@
+// Project Transform Module
+// Rotates, flips, crops and resamples whole projects
+
+use crate::compose::{scale_physical, shift_project, MAX_CANVAS_SIDE};
+use crate::{NdpFile, OverlayImage, Stitch};
+use base64::{engine::general_purpose::STANDARD, Engine};
+use image::codecs::jpeg::JpegEncoder;
+use image::{DynamicImage, ImageFormat};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// A geometric change applied to every layer, overlay and the canvas
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ProjectTransform {
+    /// Clockwise rotation by 90, 180 or 270 degrees
+    Rotate { degrees: u32 },
+    FlipHorizontal,
+    FlipVertical,
+    /// Keep only the given rectangle of cells
+    Crop { x: u32, y: u32, width: u32, height: u32 },
+    /// Resample to a new stitch count
+    Resize { width: u32, height: u32 },
+}
+
+/// Rotations and mirrorings of the canvas
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Orientation {
+    Clockwise,
+    HalfTurn,
+    CounterClockwise,
+    FlipHorizontal,
+    FlipVertical,
+}
+
+impl Orientation {
+    fn swaps_axes(self) -> bool {
+        matches!(self, Orientation::Clockwise | Orientation::CounterClockwise)
+    }
+
+    /// Map a point in a `w` x `h` box to the transformed box
+    fn map(self, x: i64, y: i64, w: i64, h: i64) -> (i64, i64) {
+        match self {
+            Orientation::Clockwise => (h - y, x),
+            Orientation::HalfTurn => (w - x, h - y),
+            Orientation::CounterClockwise => (y, w - x),
+            Orientation::FlipHorizontal => (w - x, y),
+            Orientation::FlipVertical => (x, h - y),
+        }
+    }
+
+    /// Map a rectangle, returning its new top-left corner and size
+    fn map_rect(self, x: i64, y: i64, width: i64, height: i64, w: i64, h: i64) -> (i64, i64, i64, i64) {
+        let (ax, ay) = self.map(x, y, w, h);
+        let (bx, by) = self.map(x + width, y + height, w, h);
+        (ax.min(bx), ay.min(by), (ax - bx).abs(), (ay - by).abs())
+    }
+}
+
+/// Points within a cell on a 3x3 grid (0 = left/top, 1 = middle, 2 = right/bottom)
+const POSITIONS: [(&str, (i64, i64)); 9] = [
+    ("top-left", (0, 0)),
+    ("top-center", (1, 0)),
+    ("top-right", (2, 0)),
+    ("middle-left", (0, 1)),
+    ("center", (1, 1)),
+    ("middle-right", (2, 1)),
+    ("bottom-left", (0, 2)),
+    ("bottom-center", (1, 2)),
+    ("bottom-right", (2, 2)),
+];
+const CORNERS: [(&str, (i64, i64)); 4] = [("tl", (0, 0)), ("tr", (2, 0)), ("bl", (0, 2)), ("br", (2, 2))];
+const SIDES: [(&str, (i64, i64)); 4] = [("top", (1, 0)), ("bottom", (1, 2)), ("left", (0, 1)), ("right", (2, 1))];
+
+/// Transformed name of a point within a cell, if `name` is in `table`
+fn remap_named(table: &[(&'static str, (i64, i64))], name: &str, orientation: Orientation) -> Option<&'static str> {
+    let (x, y) = table.iter().find(|(n, _)| *n == name)?.1;
+    let mapped = orientation.map(x, y, 2, 2);
+    table.iter().find(|(_, p)| *p == mapped).map(|(n, _)| *n)
+}
+
+/// Stitch type after the transform, for types that point somewhere within the cell
+fn transform_stitch_type(stitch_type: &str, orientation: Orientation) -> Option<String> {
+    let (family, part) = stitch_type.split_once('-')?;
+    match family {
+        "half" | "quarter" | "border" => {
+            let mapped = remap_named(&CORNERS, part, orientation).or_else(|| remap_named(&SIDES, part, orientation))?;
+            Some(format!("{}-{}", family, mapped))
+        }
+        "cross" => {
+            // A diagonal keeps its direction when its start corner lands on either end of the same diagonal
+            let start = if part == "tlbr" { "tl" } else { "tr" };
+            let mapped = remap_named(&CORNERS, start, orientation)?;
+            let same = matches!((part, mapped), ("tlbr", "tl" | "br") | ("trbl", "tr" | "bl"));
+            let flipped = if part == "tlbr" { "trbl" } else { "tlbr" };
+            Some(format!("cross-{}", if same { part } else { flipped }))
+        }
+        _ => None,
+    }
+}
+
+fn orient_stitch(stitch: &mut Stitch, orientation: Orientation, width: i64, height: i64) {
+    let (x, y, _, _) = orientation.map_rect(stitch.x as i64, stitch.y as i64, 1, 1, width, height);
+    stitch.x = x as u32;
+    stitch.y = y as u32;
+
+    if let Some(stitch_type) = stitch.stitch_type.as_deref() {
+        if let Some(mapped) = transform_stitch_type(stitch_type, orientation) {
+            stitch.stitch_type = Some(mapped);
+        }
+    }
+    if let Some(position) = stitch.position.as_deref() {
+        if let Some(mapped) = remap_named(&POSITIONS, position, orientation) {
+            stitch.position = Some(mapped.to_string());
+        }
+    }
+}
+
+/// JPEG quality for re-encoded overlays, high enough that repeated turns don't visibly degrade them
+const OVERLAY_JPEG_QUALITY: u8 = 92;
+
+/// Encode an image as a data URL in the format named by `mime`
+/// Formats the image crate can't write fall back to PNG.
+fn encode_data_url(img: &DynamicImage, mime: &str) -> Result<String, String> {
+    let format = ImageFormat::from_mime_type(mime).filter(|f| f.writing_enabled());
+    let mut bytes: Vec<u8> = Vec::new();
+    let encoded = match format {
+        Some(ImageFormat::Jpeg) => img.write_with_encoder(JpegEncoder::new_with_quality(&mut bytes, OVERLAY_JPEG_QUALITY)),
+        Some(format) => img.write_to(&mut std::io::Cursor::new(&mut bytes), format),
+        None => return crate::image_to_base64(img),
+    };
+    encoded.map_err(|e| format!("Failed to encode image: {}", e))?;
+    Ok(format!("data:{};base64,{}", mime, STANDARD.encode(&bytes)))
+}
+
+/// Rotate or flip an overlay's image data to match its new placement
+/// The image is re-encoded in its original format so JPEG photos stay JPEG.
+/// Images that can't be decoded (remote URLs, unsupported formats) are left as they are.
+fn orient_overlay_image(overlay: &mut OverlayImage, orientation: Orientation) {
+    let Some((mime, data)) = overlay
+        .data_url
+        .strip_prefix("data:")
+        .and_then(|rest| rest.split_once(";base64,"))
+    else {
+        return;
+    };
+    let Some(img) = STANDARD.decode(data).ok().and_then(|bytes| image::load_from_memory(&bytes).ok()) else {
+        return;
+    };
+
+    let oriented = match orientation {
+        Orientation::Clockwise => img.rotate90(),
+        Orientation::HalfTurn => img.rotate180(),
+        Orientation::CounterClockwise => img.rotate270(),
+        Orientation::FlipHorizontal => img.fliph(),
+        Orientation::FlipVertical => img.flipv(),
+    };
+    if let Ok(data_url) = encode_data_url(&oriented, mime) {
+        overlay.data_url = data_url;
+    }
+}
+
+fn orient_project(project: &mut NdpFile, orientation: Orientation) {
+    let (width, height) = (project.canvas.width as i64, project.canvas.height as i64);
+
+    for layer in &mut project.layers {
+        for stitch in &mut layer.stitches {
+            orient_stitch(stitch, orientation, width, height);
+        }
+    }
+
+    for overlay in project.overlays.iter_mut().flatten() {
+        let (x, y, w, h) = orientation.map_rect(
+            overlay.x as i64,
+            overlay.y as i64,
+            overlay.width as i64,
+            overlay.height as i64,
+            width,
+            height,
+        );
+        overlay.x = x as i32;
+        overlay.y = y as i32;
+        overlay.width = w as i32;
+        overlay.height = h as i32;
+        if orientation.swaps_axes() {
+            std::mem::swap(&mut overlay.natural_width, &mut overlay.natural_height);
+        }
+        orient_overlay_image(overlay, orientation);
+    }
+
+    if orientation.swaps_axes() {
+        let canvas = &mut project.canvas;
+        std::mem::swap(&mut canvas.width, &mut canvas.height);
+        std::mem::swap(&mut canvas.physical_width, &mut canvas.physical_height);
+    }
+}
+
+fn crop_project(project: &mut NdpFile, x: u32, y: u32, width: u32, height: u32) -> Result<(), String> {
+    let canvas = &project.canvas;
+    if width == 0 || height == 0 || x as u64 + width as u64 > canvas.width as u64 || y as u64 + height as u64 > canvas.height as u64 {
+        return Err(format!(
+            "Crop {}x{} at ({}, {}) does not fit a {}x{} canvas",
+            width, height, x, y, canvas.width, canvas.height
+        ));
+    }
+
+    for layer in &mut project.layers {
+        layer
+            .stitches
+            .retain(|s| s.x >= x && s.x < x + width && s.y >= y && s.y < y + height);
+    }
+    if let Some(overlays) = project.overlays.as_mut() {
+        overlays.retain(|o| {
+            let (left, top) = (o.x as i64, o.y as i64);
+            left < (x + width) as i64
+                && left + o.width as i64 > x as i64
+                && top < (y + height) as i64
+                && top + o.height as i64 > y as i64
+        });
+    }
+    shift_project(project, -(x as i64), -(y as i64));
+
+    let canvas = &mut project.canvas;
+    canvas.physical_width = scale_physical(canvas.physical_width, canvas.width, width);
+    canvas.physical_height = scale_physical(canvas.physical_height, canvas.height, height);
+    canvas.width = width;
+    canvas.height = height;
+    Ok(())
+}
+
+/// Source cell sampled for target cell `index` (nearest cell center)
+fn sample_index(index: u32, from: u32, to: u32) -> u32 {
+    ((2 * index as u64 + 1) * from as u64 / (2 * to as u64)) as u32
+}
+
+/// Resample every layer to a new stitch count using nearest-neighbour sampling
+fn resize_project(project: &mut NdpFile, width: u32, height: u32) -> Result<(), String> {
+    if width == 0 || height == 0 || width > MAX_CANVAS_SIDE || height > MAX_CANVAS_SIDE {
+        return Err(format!(
+            "Canvas size must be between 1x1 and {}x{}",
+            MAX_CANVAS_SIDE, MAX_CANVAS_SIDE
+        ));
+    }
+
+    let (from_width, from_height) = (project.canvas.width, project.canvas.height);
+    if from_width == 0 || from_height == 0 {
+        return Err("Cannot resize an empty canvas".to_string());
+    }
+
+    for layer in &mut project.layers {
+        let mut cells: HashMap<(u32, u32), Vec<Stitch>> = HashMap::new();
+        for stitch in layer.stitches.drain(..) {
+            cells.entry((stitch.x, stitch.y)).or_default().push(stitch);
+        }
+
+        for ty in 0..height {
+            let sy = sample_index(ty, from_height, height);
+            for tx in 0..width {
+                let sx = sample_index(tx, from_width, width);
+                for stitch in cells.get(&(sx, sy)).into_iter().flatten() {
+                    layer.stitches.push(Stitch { x: tx, y: ty, ..stitch.clone() });
+                }
+            }
+        }
+    }
+
+    let scale = |value: i32, to: u32, from: u32| (value as i64 * to as i64 / from as i64) as i32;
+    for overlay in project.overlays.iter_mut().flatten() {
+        overlay.x = scale(overlay.x, width, from_width);
+        overlay.y = scale(overlay.y, height, from_height);
+        overlay.width = scale(overlay.width, width, from_width).max(1);
+        overlay.height = scale(overlay.height, height, from_height).max(1);
+    }
+
+    let canvas = &mut project.canvas;
+    canvas.physical_width = scale_physical(canvas.physical_width, from_width, width);
+    canvas.physical_height = scale_physical(canvas.physical_height, from_height, height);
+    canvas.width = width;
+    canvas.height = height;
+    Ok(())
+}
+
+/// Apply a transform to a whole project
+/// Stitch progress is carried along with each stitch.
+pub fn transform_project(project: &mut NdpFile, transform: ProjectTransform) -> Result<(), String> {
+    match transform {
+        ProjectTransform::Rotate { degrees } => {
+            let orientation = match degrees % 360 {
+                0 => return Ok(()),
+                90 => Orientation::Clockwise,
+                180 => Orientation::HalfTurn,
+                270 => Orientation::CounterClockwise,
+                _ => return Err(format!("Rotation must be a multiple of 90 degrees (got {})", degrees)),
+            };
+            orient_project(project, orientation);
+        }
+        ProjectTransform::FlipHorizontal => orient_project(project, Orientation::FlipHorizontal),
+        ProjectTransform::FlipVertical => orient_project(project, Orientation::FlipVertical),
+        ProjectTransform::Crop { x, y, width, height } => crop_project(project, x, y, width, height)?,
+        ProjectTransform::Resize { width, height } => resize_project(project, width, height)?,
+    }
+
+    // The stored thumbnail no longer matches the stitches
+    project.thumbnail = None;
+    project.thumbnail_fingerprint = None;
+    Ok(())
+}
+
+/// Tauri commands for project transforms
+pub mod commands {
+    use super::*;
+
+    /// Rotate, flip, crop or resample a project, returning the transformed copy
+    #[tauri::command]
+    pub fn transform_project(mut project: NdpFile, transform: ProjectTransform) -> Result<NdpFile, String> {
+        super::transform_project(&mut project, transform)?;
+        Ok(project)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures;
+
+    fn stitch(x: u32, y: u32, stitch_type: Option<&str>, position: Option<&str>) -> Stitch {
+        Stitch {
+            completed: x == 0,
+            stitch_type: stitch_type.map(str::to_string),
+            position: position.map(str::to_string),
+            ..fixtures::stitch(x, y, "c1")
+        }
+    }
+
+    fn project(width: u32, height: u32, stitches: Vec<Stitch>) -> NdpFile {
+        let mut project = fixtures::project(width, height, vec![], stitches);
+        project.canvas.physical_width = Some(4.0);
+        project.canvas.physical_height = Some(2.0);
+        project.overlays = Some(vec![OverlayImage {
+            id: "o1".to_string(),
+            name: "Photo".to_string(),
+            data_url: "https://example.com/photo.png".to_string(),
+            opacity: 50,
+            visible: true,
+            locked: false,
+            x: 1,
+            y: 0,
+            width: 2,
+            height: 1,
+            natural_width: 200,
+            natural_height: 100,
+        }]);
+        project.thumbnail = Some("data:image/png;base64,".to_string());
+        project
+    }
+
+    type Cell<'a> = (u32, u32, Option<&'a str>, Option<&'a str>, bool);
+
+    fn cells(project: &NdpFile) -> Vec<Cell<'_>> {
+        project.layers[0]
+            .stitches
+            .iter()
+            .map(|s| (s.x, s.y, s.stitch_type.as_deref(), s.position.as_deref(), s.completed))
+            .collect()
+    }
+
+    #[test]
+    fn test_rotate_and_flip() {
+        let stitches = vec![
+            stitch(0, 0, Some("half-tl"), None),
+            stitch(3, 1, Some("circle"), Some("top-center")),
+            stitch(1, 1, Some("cross-tlbr"), None),
+        ];
+        let mut rotated = project(4, 2, stitches.clone());
+        transform_project(&mut rotated, ProjectTransform::Rotate { degrees: 90 }).unwrap();
+
+        assert_eq!((rotated.canvas.width, rotated.canvas.height), (2, 4));
+        assert_eq!((rotated.canvas.physical_width, rotated.canvas.physical_height), (Some(2.0), Some(4.0)));
+        assert_eq!(
+            cells(&rotated),
+            vec![
+                (1, 0, Some("half-tr"), None, true),
+                (0, 3, Some("circle"), Some("middle-right"), false),
+                (0, 1, Some("cross-trbl"), None, false),
+            ]
+        );
+        let overlay = &rotated.overlays.as_ref().unwrap()[0];
+        assert_eq!((overlay.x, overlay.y, overlay.width, overlay.height), (1, 1, 1, 2));
+        assert_eq!((overlay.natural_width, overlay.natural_height), (100, 200));
+        assert!(rotated.thumbnail.is_none());
+
+        // Four quarter turns return to the start
+        for _ in 0..3 {
+            transform_project(&mut rotated, ProjectTransform::Rotate { degrees: 90 }).unwrap();
+        }
+        assert_eq!(cells(&rotated), cells(&project(4, 2, stitches.clone())));
+
+        let mut flipped = project(4, 2, stitches);
+        transform_project(&mut flipped, ProjectTransform::FlipHorizontal).unwrap();
+        assert_eq!(
+            cells(&flipped),
+            vec![
+                (3, 0, Some("half-tr"), None, true),
+                (0, 1, Some("circle"), Some("top-center"), false),
+                (2, 1, Some("cross-trbl"), None, false),
+            ]
+        );
+
+        assert!(transform_project(&mut flipped, ProjectTransform::Rotate { degrees: 45 }).is_err());
+    }
+
+    #[test]
+    fn test_overlay_keeps_its_format() {
+        let photo = DynamicImage::ImageRgb8(image::RgbImage::from_fn(4, 2, |x, _| image::Rgb([x as u8 * 60, 0, 0])));
+        let mut project = project(4, 2, vec![]);
+        let overlays = project.overlays.as_mut().unwrap();
+        overlays[0].data_url = encode_data_url(&photo, "image/jpeg").unwrap();
+        overlays.push(OverlayImage {
+            id: "o2".to_string(),
+            data_url: crate::image_to_base64(&photo).unwrap(),
+            ..overlays[0].clone()
+        });
+
+        transform_project(&mut project, ProjectTransform::Rotate { degrees: 90 }).unwrap();
+        let overlays = project.overlays.as_ref().unwrap();
+        for (overlay, (mime, format)) in overlays.iter().zip([("image/jpeg", ImageFormat::Jpeg), ("image/png", ImageFormat::Png)]) {
+            let data = overlay.data_url.strip_prefix(&format!("data:{};base64,", mime)).unwrap();
+            let bytes = STANDARD.decode(data).unwrap();
+            assert_eq!(image::guess_format(&bytes).unwrap(), format);
+            let img = image::load_from_memory(&bytes).unwrap();
+            assert_eq!((img.width(), img.height()), (2, 4));
+        }
+    }
+
+    #[test]
+    fn test_crop_and_resize() {
+        let stitches = vec![stitch(0, 0, None, None), stitch(2, 1, None, None), stitch(3, 1, None, None)];
+
+        let mut cropped = project(4, 2, stitches.clone());
+        transform_project(&mut cropped, ProjectTransform::Crop { x: 2, y: 1, width: 2, height: 1 }).unwrap();
+        assert_eq!((cropped.canvas.width, cropped.canvas.height), (2, 1));
+        assert_eq!(cropped.canvas.physical_width, Some(2.0));
+        assert_eq!(cells(&cropped), vec![(0, 0, None, None, false), (1, 0, None, None, false)]);
+        assert!(cropped.overlays.as_ref().unwrap().is_empty());
+        assert!(transform_project(&mut cropped, ProjectTransform::Crop { x: 1, y: 0, width: 2, height: 1 }).is_err());
+
+        let mut enlarged = project(4, 2, stitches.clone());
+        transform_project(&mut enlarged, ProjectTransform::Resize { width: 8, height: 4 }).unwrap();
+        assert_eq!(enlarged.layers[0].stitches.len(), 12);
+        assert_eq!(enlarged.layers[0].stitches.iter().filter(|s| s.completed).count(), 4);
+        let overlay = &enlarged.overlays.as_ref().unwrap()[0];
+        assert_eq!((overlay.x, overlay.width, overlay.height), (2, 4, 2));
+
+        let mut reduced = project(4, 2, stitches);
+        transform_project(&mut reduced, ProjectTransform::Resize { width: 2, height: 1 }).unwrap();
+        assert_eq!(cells(&reduced), vec![(1, 0, None, None, false)]);
+        assert!(transform_project(&mut reduced, ProjectTransform::Resize { width: 0, height: 1 }).is_err());
+    }
+}
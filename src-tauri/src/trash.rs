@@ -0,0 +1,384 @@
+// Trash Module
+// Moves deleted projects into an app-managed trash so they can be restored
+
+use crate::{header, library};
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use tauri::Manager;
+
+/// Trash folder inside app data
+const TRASH_DIR: &str = "trash";
+
+/// Manifest of trashed files, stored inside the trash folder
+const TRASH_INDEX_FILE: &str = "trash-index.json";
+
+/// Days a file stays in the trash before it is purged
+pub const DEFAULT_RETENTION_DAYS: u32 = 30;
+
+/// Held for every read and read-modify-write of the trash manifest
+static TRASH_INDEX_LOCK: Mutex<()> = Mutex::new(());
+
+/// A project file in the trash
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TrashEntry {
+    pub id: String,
+    pub original_path: String,
+    pub name: String,
+    pub deleted_at: DateTime<Utc>,
+    pub size: u64,
+    stored_name: String, // File name inside the trash folder
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct TrashIndex {
+    #[serde(default = "default_retention_days")]
+    retention_days: u32, // 0 keeps files until the trash is emptied
+    #[serde(default)]
+    entries: Vec<TrashEntry>,
+}
+
+fn default_retention_days() -> u32 {
+    DEFAULT_RETENTION_DAYS
+}
+
+impl Default for TrashIndex {
+    fn default() -> Self {
+        TrashIndex {
+            retention_days: DEFAULT_RETENTION_DAYS,
+            entries: Vec::new(),
+        }
+    }
+}
+
+/// Get the trash directory, creating it if needed
+fn get_trash_dir(app: &tauri::AppHandle) -> Result<PathBuf, String> {
+    let trash_dir = app
+        .path()
+        .app_data_dir()
+        .map_err(|e| format!("Failed to get app data directory: {}", e))?
+        .join(TRASH_DIR);
+
+    fs::create_dir_all(&trash_dir)
+        .map_err(|e| format!("Failed to create trash directory: {}", e))?;
+
+    Ok(trash_dir)
+}
+
+fn load_index(trash_dir: &Path) -> Result<TrashIndex, String> {
+    let path = trash_dir.join(TRASH_INDEX_FILE);
+    if !path.exists() {
+        return Ok(TrashIndex::default());
+    }
+
+    let contents = fs::read_to_string(&path)
+        .map_err(|e| format!("Failed to read trash index: {}", e))?;
+    serde_json::from_str(&contents)
+        .map_err(|e| format!("Failed to parse trash index: {}", e))
+}
+
+fn save_index(trash_dir: &Path, index: &TrashIndex) -> Result<(), String> {
+    let json = serde_json::to_string_pretty(index)
+        .map_err(|e| format!("Failed to serialize trash index: {}", e))?;
+    fs::write(trash_dir.join(TRASH_INDEX_FILE), json)
+        .map_err(|e| format!("Failed to write trash index: {}", e))
+}
+
+/// Read the manifest without racing a concurrent update
+fn read_index(trash_dir: &Path) -> Result<TrashIndex, String> {
+    let _guard = TRASH_INDEX_LOCK.lock().unwrap();
+    load_index(trash_dir)
+}
+
+/// Apply a change to the manifest (and the trashed files it lists) and save it,
+/// holding the lock throughout so concurrent deletes and restores can't lose entries
+fn update_index<T>(trash_dir: &Path, update: impl FnOnce(&mut TrashIndex) -> Result<T, String>) -> Result<T, String> {
+    let _guard = TRASH_INDEX_LOCK.lock().unwrap();
+    let mut index = load_index(trash_dir)?;
+    let result = update(&mut index)?;
+    save_index(trash_dir, &index)?;
+    Ok(result)
+}
+
+/// Move a file, falling back to copy + delete across filesystems
+fn move_file(from: &Path, to: &Path) -> Result<(), String> {
+    if fs::rename(from, to).is_ok() {
+        return Ok(());
+    }
+
+    fs::copy(from, to).map_err(|e| format!("Failed to move file: {} (path: {:?})", e, from))?;
+    fs::remove_file(from).map_err(|e| {
+        let _ = fs::remove_file(to);
+        format!("Failed to move file: {} (path: {:?})", e, from)
+    })
+}
+
+/// First free path of the form "name (n).ext" next to `path`
+fn unique_path(path: &Path) -> PathBuf {
+    if !path.exists() {
+        return path.to_path_buf();
+    }
+
+    let stem = path.file_stem().map(|s| s.to_string_lossy().to_string()).unwrap_or_default();
+    let ext = path.extension().map(|e| format!(".{}", e.to_string_lossy())).unwrap_or_default();
+    let parent = path.parent().unwrap_or_else(|| Path::new(""));
+
+    (1..)
+        .map(|n| parent.join(format!("{} ({}){}", stem, n, ext)))
+        .find(|candidate| !candidate.exists())
+        .unwrap()
+}
+
+fn trash_file(trash_dir: &Path, path: &Path, now: DateTime<Utc>) -> Result<TrashEntry, String> {
+    if !crate::is_project_path(path) {
+        return Err(format!("Refusing to delete a file that is not a project: {:?}", path));
+    }
+
+    let metadata = fs::metadata(path)
+        .map_err(|e| format!("Failed to delete file: {} (path: {:?})", e, path))?;
+    if !metadata.is_file() {
+        return Err(format!("Refusing to delete a file that is not a project: {:?}", path));
+    }
+
+    let id = uuid::Uuid::new_v4().to_string();
+    let ext = path.extension().map(|e| e.to_string_lossy().to_string()).unwrap_or_default();
+    let stored_name = format!("{}.{}", id, ext);
+
+    update_index(trash_dir, |index| {
+        move_file(path, &trash_dir.join(&stored_name))?;
+
+        let entry = TrashEntry {
+            id,
+            original_path: path.to_string_lossy().to_string(),
+            name: path.file_name().map(|n| n.to_string_lossy().to_string()).unwrap_or_default(),
+            deleted_at: now,
+            size: metadata.len(),
+            stored_name,
+        };
+        index.entries.push(entry.clone());
+        Ok(entry)
+    })
+}
+
+/// Put a trashed file back at its original path (or beside it if that name is taken)
+fn restore_file(trash_dir: &Path, id: &str) -> Result<PathBuf, String> {
+    update_index(trash_dir, |index| {
+        let position = index
+            .entries
+            .iter()
+            .position(|e| e.id == id)
+            .ok_or_else(|| format!("Item not found in trash: {}", id))?;
+
+        let entry = &index.entries[position];
+        let original = PathBuf::from(&entry.original_path);
+        if let Some(parent) = original.parent() {
+            fs::create_dir_all(parent)
+                .map_err(|e| format!("Failed to create directory: {} (path: {:?})", e, parent))?;
+        }
+
+        let target = unique_path(&original);
+        move_file(&trash_dir.join(&entry.stored_name), &target)?;
+
+        index.entries.remove(position);
+        Ok(target)
+    })
+}
+
+/// Permanently delete trashed files matching `expired`, returning how many were removed
+fn purge(trash_dir: &Path, expired: impl Fn(&TrashEntry) -> bool) -> Result<usize, String> {
+    update_index(trash_dir, |index| Ok(purge_entries(trash_dir, index, expired)))
+}
+
+/// Delete the files of manifest entries matching `expired` and drop their entries
+fn purge_entries(trash_dir: &Path, index: &mut TrashIndex, expired: impl Fn(&TrashEntry) -> bool) -> usize {
+    let before = index.entries.len();
+
+    index.entries.retain(|entry| {
+        let stored = trash_dir.join(&entry.stored_name);
+        if !stored.exists() {
+            return false;
+        }
+        if expired(entry) {
+            return fs::remove_file(&stored).is_err();
+        }
+        true
+    });
+
+    before - index.entries.len()
+}
+
+/// Purge files older than the retention period
+fn purge_expired(trash_dir: &Path, now: DateTime<Utc>) -> Result<usize, String> {
+    update_index(trash_dir, |index| Ok(purge_expired_entries(trash_dir, index, now)))
+}
+
+fn purge_expired_entries(trash_dir: &Path, index: &mut TrashIndex, now: DateTime<Utc>) -> usize {
+    if index.retention_days == 0 {
+        return purge_entries(trash_dir, index, |_| false);
+    }
+
+    let cutoff = now - Duration::days(index.retention_days as i64);
+    purge_entries(trash_dir, index, |entry| entry.deleted_at < cutoff)
+}
+
+/// Move a project file to the trash
+pub fn move_to_trash(app: &tauri::AppHandle, path: &Path) -> Result<TrashEntry, String> {
+    let trash_dir = get_trash_dir(app)?;
+    let _ = purge_expired(&trash_dir, Utc::now());
+    trash_file(&trash_dir, path, Utc::now())
+}
+
+/// Tauri commands for the trash
+pub mod commands {
+    use super::*;
+
+    /// List trashed files, newest first
+    #[tauri::command]
+    pub fn list_trash(app: tauri::AppHandle) -> Result<Vec<TrashEntry>, String> {
+        let trash_dir = get_trash_dir(&app)?;
+        purge_expired(&trash_dir, Utc::now())?;
+
+        let mut entries = read_index(&trash_dir)?.entries;
+        entries.sort_by_key(|e| std::cmp::Reverse(e.deleted_at));
+        Ok(entries)
+    }
+
+    /// Restore a trashed file, returning the path it was restored to
+    #[tauri::command]
+    pub fn restore_from_trash(app: tauri::AppHandle, id: String) -> Result<String, String> {
+        let trash_dir = get_trash_dir(&app)?;
+        let restored = restore_file(&trash_dir, &id)?;
+        let restored_str = restored.to_string_lossy().to_string();
+
+        if let Ok(project_header) = header::read_header(&restored) {
+            if let Err(e) = library::record_project_header(&app, &restored_str, &project_header) {
+                crate::report_error(&app, "Failed to update project library", e);
+            }
+        }
+
+        Ok(restored_str)
+    }
+
+    /// Permanently delete everything in the trash
+    /// Returns the number of files removed
+    #[tauri::command]
+    pub fn empty_trash(app: tauri::AppHandle) -> Result<usize, String> {
+        let trash_dir = get_trash_dir(&app)?;
+        purge(&trash_dir, |_| true)
+    }
+
+    #[tauri::command]
+    pub fn get_trash_retention_days(app: tauri::AppHandle) -> Result<u32, String> {
+        let trash_dir = get_trash_dir(&app)?;
+        Ok(read_index(&trash_dir)?.retention_days)
+    }
+
+    /// Set how many days files are kept in the trash (0 = until emptied)
+    #[tauri::command]
+    pub fn set_trash_retention_days(app: tauri::AppHandle, days: u32) -> Result<(), String> {
+        let trash_dir = get_trash_dir(&app)?;
+        update_index(&trash_dir, |index| {
+            index.retention_days = days;
+            purge_expired_entries(&trash_dir, index, Utc::now());
+            Ok(())
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("stitchalot-trash-test-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(dir.join("trash")).unwrap();
+        fs::create_dir_all(dir.join("projects")).unwrap();
+        dir
+    }
+
+    #[test]
+    fn test_trash_and_restore() {
+        let dir = temp_dir("restore");
+        let trash_dir = dir.join("trash");
+        let project = dir.join("projects").join("roses.stitchalot");
+        fs::write(&project, b"{}").unwrap();
+
+        let entry = trash_file(&trash_dir, &project, Utc::now()).unwrap();
+        assert!(!project.exists());
+        assert_eq!(entry.name, "roses.stitchalot");
+        assert_eq!(load_index(&trash_dir).unwrap().entries.len(), 1);
+
+        // The original name was reused in the meantime
+        fs::write(&project, b"new").unwrap();
+        let restored = restore_file(&trash_dir, &entry.id).unwrap();
+        assert_eq!(restored, dir.join("projects").join("roses (1).stitchalot"));
+        assert_eq!(fs::read(&restored).unwrap(), b"{}");
+        assert!(load_index(&trash_dir).unwrap().entries.is_empty());
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_refuses_non_project_files() {
+        let dir = temp_dir("guard");
+        let other = dir.join("projects").join("notes.txt");
+        fs::write(&other, b"keep me").unwrap();
+
+        assert!(trash_file(&dir.join("trash"), &other, Utc::now()).is_err());
+        assert!(trash_file(&dir.join("trash"), &dir.join("projects"), Utc::now()).is_err());
+        assert!(other.exists());
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_concurrent_trashing_keeps_every_entry() {
+        let dir = temp_dir("concurrent");
+        let trash_dir = dir.join("trash");
+
+        let handles: Vec<_> = (0..8)
+            .map(|i| {
+                let path = dir.join("projects").join(format!("p{}.stitchalot", i));
+                fs::write(&path, b"{}").unwrap();
+                let trash_dir = trash_dir.clone();
+                std::thread::spawn(move || trash_file(&trash_dir, &path, Utc::now()).unwrap())
+            })
+            .collect();
+        for handle in handles {
+            handle.join().unwrap();
+        }
+
+        assert_eq!(read_index(&trash_dir).unwrap().entries.len(), 8);
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_purge_expired() {
+        let dir = temp_dir("purge");
+        let trash_dir = dir.join("trash");
+        let now = Utc::now();
+
+        for (name, age_days) in [("old.stitchalot", 45), ("recent.stitchalot", 2)] {
+            let path = dir.join("projects").join(name);
+            fs::write(&path, b"{}").unwrap();
+            trash_file(&trash_dir, &path, now - Duration::days(age_days)).unwrap();
+        }
+
+        assert_eq!(purge_expired(&trash_dir, now).unwrap(), 1);
+        let entries = load_index(&trash_dir).unwrap().entries;
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].name, "recent.stitchalot");
+
+        // Retention of 0 keeps files indefinitely
+        let mut index = load_index(&trash_dir).unwrap();
+        index.retention_days = 0;
+        save_index(&trash_dir, &index).unwrap();
+        assert_eq!(purge_expired(&trash_dir, now + Duration::days(365)).unwrap(), 0);
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+}
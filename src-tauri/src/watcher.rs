@@ -0,0 +1,389 @@
+// Project Folder Watcher Module
+// Watches project directories and notifies the frontend when project files change
+
+use crate::header::{self, ProjectHeader};
+use crate::paths::{self, PathAccess};
+use crate::{library, thumbnail};
+use notify::event::{ModifyKind, RenameMode};
+use notify::{Event, EventKind, RecommendedWatcher, RecursiveMode, Watcher};
+use serde::Serialize;
+use std::collections::HashSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError};
+use std::sync::Mutex;
+use std::time::{Duration, UNIX_EPOCH};
+use tauri::Emitter;
+
+/// Event emitted to the frontend for every project file change
+pub const PROJECT_FILE_EVENT: &str = "project-file-changed";
+
+/// Quiet period before a burst of filesystem events is processed
+/// Sync clients and atomic saves usually touch a file several times in a row.
+const DEBOUNCE: Duration = Duration::from_millis(500);
+
+/// The running watcher and the directories it covers
+/// Dropping the watcher closes its event channel, which ends the worker thread.
+struct ActiveWatcher {
+    _watcher: RecommendedWatcher,
+    directories: Vec<PathBuf>,
+}
+
+static ACTIVE_WATCHER: Mutex<Option<ActiveWatcher>> = Mutex::new(None);
+
+/// What happened to a project file
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ChangeKind {
+    Added,
+    Removed,
+    Renamed,
+    Modified,
+}
+
+/// Payload of PROJECT_FILE_EVENT
+#[derive(Debug, Clone, Serialize)]
+pub struct ProjectFileEvent {
+    pub kind: ChangeKind,
+    pub path: String,
+    pub old_path: Option<String>,
+    pub modified: u64,
+    pub header: Option<ProjectHeader>,
+    pub thumbnail: Option<String>,
+}
+
+/// A change after a burst of raw events has been coalesced
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct FileChange {
+    kind: ChangeKind,
+    path: PathBuf,
+    old_path: Option<PathBuf>,
+}
+
+/// Raw events collected during one debounce window
+#[derive(Debug, Default)]
+struct PendingChanges {
+    touched: Vec<PathBuf>,
+    renames: Vec<(PathBuf, PathBuf)>,
+}
+
+impl PendingChanges {
+    fn touch(&mut self, path: &Path) {
+        if crate::is_project_path(path) && !self.touched.iter().any(|p| p == path) {
+            self.touched.push(path.to_path_buf());
+        }
+    }
+
+    fn add_event(&mut self, event: &Event) {
+        if matches!(event.kind, EventKind::Access(_)) {
+            return;
+        }
+
+        if let EventKind::Modify(ModifyKind::Name(RenameMode::Both)) = event.kind {
+            if let [from, to] = event.paths.as_slice() {
+                if crate::is_project_path(from) && crate::is_project_path(to) {
+                    self.renames.push((from.clone(), to.clone()));
+                }
+            }
+        }
+
+        for path in &event.paths {
+            self.touch(path);
+        }
+    }
+}
+
+/// Classify the touched paths by comparing what is on disk now with the set of
+/// project files known before the burst. Classifying by end state (rather than
+/// by raw event kind) folds temp-file-and-rename saves into a single change.
+fn resolve_changes(
+    known: &mut HashSet<PathBuf>,
+    pending: PendingChanges,
+    exists: impl Fn(&Path) -> bool,
+) -> Vec<FileChange> {
+    let mut changes = Vec::new();
+    let mut handled: HashSet<PathBuf> = HashSet::new();
+
+    for (from, to) in pending.renames {
+        if known.contains(&from) && !exists(&from) && !known.contains(&to) && exists(&to) {
+            known.remove(&from);
+            known.insert(to.clone());
+            handled.insert(from.clone());
+            handled.insert(to.clone());
+            changes.push(FileChange {
+                kind: ChangeKind::Renamed,
+                path: to,
+                old_path: Some(from),
+            });
+        }
+    }
+
+    for path in pending.touched {
+        if handled.contains(&path) {
+            continue;
+        }
+
+        let kind = match (known.contains(&path), exists(&path)) {
+            (false, true) => ChangeKind::Added,
+            (true, false) => ChangeKind::Removed,
+            (true, true) => ChangeKind::Modified,
+            (false, false) => continue,
+        };
+
+        if kind == ChangeKind::Removed {
+            known.remove(&path);
+        } else {
+            known.insert(path.clone());
+        }
+
+        changes.push(FileChange {
+            kind,
+            path,
+            old_path: None,
+        });
+    }
+
+    changes
+}
+
+/// Project files currently in the watched directories
+fn scan_known_files(directories: &[PathBuf]) -> HashSet<PathBuf> {
+    let mut known = HashSet::new();
+    for directory in directories {
+        if let Ok(entries) = fs::read_dir(directory) {
+            for entry in entries.flatten() {
+                let path = entry.path();
+                if crate::is_project_path(&path) && path.is_file() {
+                    known.insert(path);
+                }
+            }
+        }
+    }
+    known
+}
+
+fn file_modified(path: &Path) -> u64 {
+    fs::metadata(path)
+        .ok()
+        .and_then(|m| m.modified().ok())
+        .and_then(|t| t.duration_since(UNIX_EPOCH).ok())
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+/// Update caches for one change and tell the frontend about it
+fn handle_change(app: &tauri::AppHandle, change: FileChange) {
+    let path_str = change.path.to_string_lossy().to_string();
+    let old_path_str = change.old_path.as_ref().map(|p| p.to_string_lossy().to_string());
+
+    let mut event = ProjectFileEvent {
+        kind: change.kind,
+        path: path_str.clone(),
+        old_path: old_path_str.clone(),
+        modified: 0,
+        header: None,
+        thumbnail: None,
+    };
+
+    if change.kind == ChangeKind::Removed {
+        thumbnail::forget_thumbnail(app, &change.path);
+        if let Err(e) = library::forget_project(app, &path_str) {
+            crate::report_error(app, "Failed to update library index", e);
+        }
+    } else {
+        if let (Some(old_path), Some(old_path_str)) = (&change.old_path, &old_path_str) {
+            thumbnail::forget_thumbnail(app, old_path);
+            if let Err(e) = library::rename_project(app, old_path_str, &path_str) {
+                crate::report_error(app, "Failed to update library index", e);
+            }
+        }
+
+        event.modified = file_modified(&change.path);
+
+        // A file may be seen mid-write; the next event for it will catch up
+        if let Ok(mut project_header) = header::read_header(&change.path) {
+            if let Err(e) = library::record_project_header(app, &path_str, &project_header) {
+                crate::report_error(app, "Failed to update library index", e);
+            }
+            project_header.thumbnail = None;
+            event.header = Some(project_header);
+
+            event.thumbnail = thumbnail::get_cached_thumbnail(app, &change.path)
+                .or_else(|| crate::load_and_cache_thumbnail(app, &change.path));
+        }
+    }
+
+    let _ = app.emit(PROJECT_FILE_EVENT, event);
+}
+
+/// Collect events into debounced bursts until the watcher is dropped
+fn run_event_loop(app: tauri::AppHandle, rx: Receiver<notify::Result<Event>>, mut known: HashSet<PathBuf>) {
+    let collect = |pending: &mut PendingChanges, result: notify::Result<Event>| match result {
+        Ok(event) => pending.add_event(&event),
+        Err(e) => crate::report_error(&app, "Project watcher error", e),
+    };
+
+    while let Ok(first) = rx.recv() {
+        let mut pending = PendingChanges::default();
+        collect(&mut pending, first);
+
+        loop {
+            match rx.recv_timeout(DEBOUNCE) {
+                Ok(result) => collect(&mut pending, result),
+                Err(RecvTimeoutError::Timeout) => break,
+                Err(RecvTimeoutError::Disconnected) => return,
+            }
+        }
+
+        for change in resolve_changes(&mut known, pending, |p| p.is_file()) {
+            handle_change(&app, change);
+        }
+    }
+}
+
+/// Tauri commands for watching project folders
+pub mod commands {
+    use super::*;
+
+    /// Watch the given project directories, replacing any previous set
+    /// Returns the directories that are now being watched. If the new watcher
+    /// can't be set up, the previous one keeps running.
+    #[tauri::command]
+    pub fn watch_project_directories(app: tauri::AppHandle, directories: Vec<String>) -> Result<Vec<String>, String> {
+        let (tx, rx) = mpsc::channel();
+        let mut watcher = notify::recommended_watcher(move |result| {
+            let _ = tx.send(result);
+        })
+        .map_err(|e| format!("Failed to create file watcher: {}", e))?;
+
+        let mut watched: Vec<PathBuf> = Vec::new();
+        for directory in &directories {
+            let dir_path = paths::resolve_file(&app, directory, PathAccess::ScanDirectory)?;
+            if !dir_path.is_dir() || watched.contains(&dir_path) {
+                continue;
+            }
+            match watcher.watch(&dir_path, RecursiveMode::NonRecursive) {
+                Ok(()) => watched.push(dir_path),
+                Err(e) => crate::report_error(&app, &format!("Failed to watch {:?}", dir_path), e),
+            }
+        }
+
+        if watched.is_empty() {
+            *ACTIVE_WATCHER.lock().unwrap() = None;
+            return Ok(Vec::new());
+        }
+
+        let known = scan_known_files(&watched);
+        std::thread::spawn(move || run_event_loop(app, rx, known));
+
+        // Dropping the previous watcher ends its event loop
+        let result = watched.iter().map(|p| p.to_string_lossy().to_string()).collect();
+        *ACTIVE_WATCHER.lock().unwrap() = Some(ActiveWatcher {
+            _watcher: watcher,
+            directories: watched,
+        });
+
+        Ok(result)
+    }
+
+    /// Stop watching all project directories
+    #[tauri::command]
+    pub fn stop_watching_projects() {
+        *ACTIVE_WATCHER.lock().unwrap() = None;
+    }
+
+    /// Directories currently being watched
+    #[tauri::command]
+    pub fn get_watched_directories() -> Vec<String> {
+        ACTIVE_WATCHER
+            .lock()
+            .unwrap()
+            .as_ref()
+            .map(|active| {
+                active
+                    .directories
+                    .iter()
+                    .map(|p| p.to_string_lossy().to_string())
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use notify::event::{CreateKind, DataChange, RemoveKind};
+
+    fn event(kind: EventKind, paths: &[&str]) -> Event {
+        let mut event = Event::new(kind);
+        for path in paths {
+            event = event.add_path(PathBuf::from(path));
+        }
+        event
+    }
+
+    fn known(paths: &[&str]) -> HashSet<PathBuf> {
+        paths.iter().map(PathBuf::from).collect()
+    }
+
+    #[test]
+    fn test_ignores_non_project_files() {
+        let mut pending = PendingChanges::default();
+        pending.add_event(&event(EventKind::Create(CreateKind::File), &["/p/notes.txt", "/p/a.NDP"]));
+        pending.add_event(&event(EventKind::Create(CreateKind::File), &["/p/b.stitchalot"]));
+        assert_eq!(pending.touched, vec![PathBuf::from("/p/a.NDP"), PathBuf::from("/p/b.stitchalot")]);
+    }
+
+    #[test]
+    fn test_resolve_by_end_state() {
+        let mut pending = PendingChanges::default();
+        pending.add_event(&event(EventKind::Create(CreateKind::File), &["/p/new.stitchalot"]));
+        pending.add_event(&event(EventKind::Modify(ModifyKind::Data(DataChange::Content)), &["/p/old.stitchalot"]));
+        pending.add_event(&event(EventKind::Remove(RemoveKind::File), &["/p/gone.stitchalot"]));
+        // Created and deleted within the same burst
+        pending.add_event(&event(EventKind::Create(CreateKind::File), &["/p/temp.stitchalot"]));
+        pending.add_event(&event(EventKind::Remove(RemoveKind::File), &["/p/temp.stitchalot"]));
+
+        let mut files = known(&["/p/old.stitchalot", "/p/gone.stitchalot"]);
+        let on_disk = known(&["/p/new.stitchalot", "/p/old.stitchalot"]);
+        let changes = resolve_changes(&mut files, pending, |p| on_disk.contains(p));
+
+        let kinds: Vec<(ChangeKind, &str)> = changes
+            .iter()
+            .map(|c| (c.kind, c.path.to_str().unwrap()))
+            .collect();
+        assert_eq!(
+            kinds,
+            vec![
+                (ChangeKind::Added, "/p/new.stitchalot"),
+                (ChangeKind::Modified, "/p/old.stitchalot"),
+                (ChangeKind::Removed, "/p/gone.stitchalot"),
+            ]
+        );
+        assert_eq!(files, on_disk);
+    }
+
+    #[test]
+    fn test_resolve_rename() {
+        let mut pending = PendingChanges::default();
+        pending.add_event(&event(
+            EventKind::Modify(ModifyKind::Name(RenameMode::Both)),
+            &["/p/a.stitchalot", "/p/b.stitchalot"],
+        ));
+
+        let mut files = known(&["/p/a.stitchalot"]);
+        let on_disk = known(&["/p/b.stitchalot"]);
+        let changes = resolve_changes(&mut files, pending, |p| on_disk.contains(p));
+
+        assert_eq!(
+            changes,
+            vec![FileChange {
+                kind: ChangeKind::Renamed,
+                path: PathBuf::from("/p/b.stitchalot"),
+                old_path: Some(PathBuf::from("/p/a.stitchalot")),
+            }]
+        );
+        assert_eq!(files, on_disk);
+    }
+}
@@ -75,6 +75,7 @@ interface NdpFile {
     rgb: [number, number, number];
     thread_brand: string | null;
     thread_code: string | null;
+    blend_thread_code?: string | null;
     symbol: string | null;
   }>;
   layers: Array<{
@@ -110,7 +111,7 @@ const RECENT_FILES_KEY = 'needlepoint-recent-files';
 const MAX_RECENT_FILES = 50;
 const PREFERENCES_KEY = 'needlepoint-preferences';
 
-// Type for files returned from scan_directory command
+// Type for files returned from scan_directory and list_ndp_file_headers
 interface DirectoryFile {
   path: string;
   modified: number; // Unix timestamp in seconds
@@ -674,9 +675,11 @@ function App() {
   // Fetch existing NDP files from documents directory
   const refreshExistingFiles = useCallback(async () => {
     try {
-      const files = await invoke<string[]>('list_ndp_files');
+      const files = await invoke<DirectoryFile[]>('list_ndp_file_headers');
       // Decode URI components in case of encoded characters
-      const decodedFiles = files.map(f => decodeURIComponent(f));
+      const decodedFiles = files.map(f =>
+        decodeURIComponent((f.path.split(/[/\\]/).pop() || '').replace(/\.(stitchalot|ndp)$/i, ''))
+      );
       setExistingFileNames(decodedFiles);
     } catch (error) {
       console.error('Failed to list NDP files:', error);
@@ -938,6 +941,7 @@ function App() {
         rgb: c.rgb,
         thread_brand: c.threadBrand ?? null,
         thread_code: c.threadCode ?? null,
+        blend_thread_code: c.blendThreadCode,
         symbol: c.symbol ?? null,
       })),
       layers: p.layers.map((l) => ({
@@ -1000,6 +1004,7 @@ function App() {
         rgb: c.rgb,
         threadBrand: c.thread_brand ?? undefined,
         threadCode: c.thread_code ?? undefined,
+        blendThreadCode: c.blend_thread_code ?? undefined,
         symbol: c.symbol ?? undefined,
       })),
       layers: ndp.layers.map((l) => ({
@@ -2441,7 +2446,7 @@ function App() {
                             e.stopPropagation();
                             try {
                               // Refresh file list first to get current state
-                              const currentFiles = await invoke<string[]>('list_ndp_files');
+                              const currentFiles = (await invoke<DirectoryFile[]>('list_ndp_file_headers')).map(f => f.path);
                               // Load the file
                               const ndpFile = await invoke<NdpFile>('open_project', { path: filePath });
                               // Get directory and original filename from path
@@ -1,6 +1,8 @@
-import { useState, useMemo, useCallback } from 'react';
+import { useState, useMemo, useCallback, useEffect } from 'react';
 import { usePatternStore, Color } from '../stores/patternStore';
+import { useThreadLibraryStore } from '../stores/threadLibraryStore';
 import {
+  ColorMatch,
   ColorMatchAlgorithm,
   findClosestColors,
   getColorDifferenceCategory,
@@ -11,9 +13,7 @@ import {
 import { EyedropperButton } from './EyedropperButton';
 import {
   ThreadBrand,
-  getThreadLibraries,
-  getThreadsByBrand,
-  threadsToPalette,
+  findNearestThreads,
 } from '../data/threadLibrary';
 
 interface ColorMatchDialogProps {
@@ -46,6 +46,8 @@ export function ColorMatchDialog({ isOpen, onClose, initialColor }: ColorMatchDi
   // Thread library selection
   const [matchSource, setMatchSource] = useState<'palette' | 'library'>('palette');
   const [selectedThreadBrand, setSelectedThreadBrand] = useState<ThreadBrand>('DMC');
+  const [libraryMatches, setLibraryMatches] = useState<ColorMatch[]>([]);
+  const { libraries: threadLibraries, loadLibraries } = useThreadLibraryStore();
 
   // Notification state
   const [notification, setNotification] = useState<string | null>(null);
@@ -68,22 +70,39 @@ export function ColorMatchDialog({ isOpen, onClose, initialColor }: ColorMatchDi
     }
   }, [inputMode, pickerColor, rgbInput, labInput]);
 
+  useEffect(() => {
+    if (isOpen) loadLibraries();
+  }, [isOpen, loadLibraries]);
+
+  // Match against the selected thread library in the backend
+  useEffect(() => {
+    if (!isOpen || matchSource !== 'library') return;
+    let cancelled = false;
+    findNearestThreads(targetColor, [selectedThreadBrand], algorithm, matchCount)
+      .then(nearest => {
+        if (cancelled) return;
+        setLibraryMatches(nearest.map(({ thread, distance }) => ({
+          color: thread.rgb,
+          colorId: `${thread.brand}-${thread.code}`,
+          distance,
+          name: `${thread.brand} ${thread.code} - ${thread.name}`,
+        })));
+      })
+      .catch(error => console.error('Failed to match threads:', error));
+    return () => {
+      cancelled = true;
+    };
+  }, [isOpen, targetColor, matchCount, algorithm, matchSource, selectedThreadBrand]);
+
   // Find matching colors from the current palette or thread library
   const matches = useMemo(() => {
-    let palette: Array<{ id: string; rgb: [number, number, number]; name: string }>;
-
-    if (matchSource === 'library') {
-      // Match against selected thread library
-      const threads = getThreadsByBrand(selectedThreadBrand);
-      palette = threadsToPalette(threads);
-    } else {
-      // Match against current pattern palette
-      if (!pattern) return [];
-      palette = pattern.colorPalette.map(c => ({ id: c.id, rgb: c.rgb, name: c.name }));
-    }
+    if (matchSource === 'library') return libraryMatches;
 
+    // Match against current pattern palette
+    if (!pattern) return [];
+    const palette = pattern.colorPalette.map(c => ({ id: c.id, rgb: c.rgb, name: c.name }));
     return findClosestColors(targetColor, palette, matchCount, algorithm);
-  }, [targetColor, pattern, matchCount, algorithm, matchSource, selectedThreadBrand]);
+  }, [targetColor, pattern, matchCount, algorithm, matchSource, libraryMatches]);
 
   // Handle hex color change
   const handleHexChange = useCallback((e: React.ChangeEvent<HTMLInputElement>) => {
@@ -342,7 +361,7 @@ export function ColorMatchDialog({ isOpen, onClose, initialColor }: ColorMatchDi
                 onChange={(e) => setSelectedThreadBrand(e.target.value as ThreadBrand)}
                 className="px-2 py-1 border border-gray-300 rounded text-sm"
               >
-                {getThreadLibraries().map(lib => (
+                {threadLibraries.map(lib => (
                   <option key={lib.brand} value={lib.brand}>
                     {lib.name} ({lib.colorCount})
                   </option>
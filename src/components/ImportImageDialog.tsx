@@ -3,12 +3,11 @@ import { invoke } from '@tauri-apps/api/core';
 import { open } from '@tauri-apps/plugin-dialog';
 import { usePatternStore, Color, Stitch } from '../stores/patternStore';
 import { useConfigStore } from '../stores/configStore';
-import { ColorMatchAlgorithm, findClosestColor } from '../utils/colorMatching';
+import { useThreadLibraryStore } from '../stores/threadLibraryStore';
+import { ColorMatchAlgorithm } from '../utils/colorMatching';
 import {
   ThreadBrand,
-  getThreadLibraries,
-  getThreadsByBrand,
-  threadsToPalette,
+  findNearestThreads,
 } from '../data/threadLibrary';
 
 interface ImportImageDialogProps {
@@ -44,6 +43,7 @@ interface ProcessedImageWithThreads {
     rgb: [number, number, number];
     thread_brand?: string;
     thread_code?: string;
+    blend_thread_code?: string;
   }>;
   pixels: string[][];
   preview_base64: string;
@@ -57,6 +57,11 @@ type DimensionUnit = 'stitches' | 'inches' | 'mm';
 export function ImportImageDialog({ isOpen, onClose }: ImportImageDialogProps) {
   const { pattern, importPattern, importAsLayer, mergeIntoActiveLayer, fitToScreen, setTool, selectLayerForTransform } = usePatternStore();
   const { autoGeneratePreview, setAutoGeneratePreview } = useConfigStore();
+  const { libraries: threadLibraries, loadLibraries } = useThreadLibraryStore();
+
+  useEffect(() => {
+    if (isOpen) loadLibraries();
+  }, [isOpen, loadLibraries]);
 
   // Detect mobile/touch devices for layout - check multiple signals
   // Use state to ensure it's evaluated client-side
@@ -481,6 +486,7 @@ export function ImportImageDialog({ isOpen, onClose }: ImportImageDialogProps) {
                 // Store thread info for later use
                 threadBrand: c.thread_brand,
                 threadCode: c.thread_code,
+                blendThreadCode: c.blend_thread_code,
               })) as ProcessedImage['colors'],
               pixels: result.pixels,
               preview_base64: result.preview_base64,
@@ -551,31 +557,32 @@ export function ImportImageDialog({ isOpen, onClose }: ImportImageDialogProps) {
         rgb: c.rgb,
         threadBrand: (c as { threadBrand?: string }).threadBrand,
         threadCode: (c as { threadCode?: string }).threadCode,
+        blendThreadCode: (c as { blendThreadCode?: string }).blendThreadCode,
       }));
       finalColorIdMap = new Map(colors.map(c => [c.id, c.id]));
     } else if (matchToThreads) {
-      // Need to do JavaScript-side thread matching (fallback for old process_image command)
-      console.log('Using JavaScript-side thread matching');
+      // Match each color to its nearest thread (fallback for old process_image command)
+      console.log('Using per-color thread matching');
       const colorIdMap = new Map<string, string>();
-      const threadLibrary = getThreadsByBrand(selectedThreadBrand);
-      const threadPalette = threadsToPalette(threadLibrary);
-
-      colors = processedImage.colors.map(c => {
-        const match = findClosestColor(c.rgb, threadPalette, colorMatchAlgorithm);
-        if (match) {
-          const matchedThread = threadLibrary.find(t => `${t.brand}-${t.code}` === match.colorId);
-
-          if (matchedThread) {
-            const newId = `${matchedThread.brand.toLowerCase()}-${matchedThread.code}-${c.id}`;
-            colorIdMap.set(c.id, newId);
-            return {
-              id: newId,
-              name: matchedThread.name,
-              rgb: matchedThread.rgb,
-              threadBrand: matchedThread.brand,
-              threadCode: matchedThread.code,
-            };
-          }
+      const nearest = await Promise.all(processedImage.colors.map(c =>
+        findNearestThreads(c.rgb, [selectedThreadBrand], colorMatchAlgorithm, 1).catch(error => {
+          console.error('Failed to match thread:', error);
+          return [];
+        })
+      ));
+
+      colors = processedImage.colors.map((c, i) => {
+        const matchedThread = nearest[i][0]?.thread;
+        if (matchedThread) {
+          const newId = `${matchedThread.brand.toLowerCase()}-${matchedThread.code}-${c.id}`;
+          colorIdMap.set(c.id, newId);
+          return {
+            id: newId,
+            name: matchedThread.name,
+            rgb: matchedThread.rgb,
+            threadBrand: matchedThread.brand,
+            threadCode: matchedThread.code,
+          };
         }
 
         // Fallback: use original color
@@ -987,7 +994,7 @@ export function ImportImageDialog({ isOpen, onClose }: ImportImageDialogProps) {
                       onChange={(e) => setSelectedThreadBrand(e.target.value as ThreadBrand)}
                       className="w-full px-2 py-1 text-sm border border-gray-300 rounded-md focus:outline-none focus:ring-1 focus:ring-blue-500"
                     >
-                      {getThreadLibraries().map(lib => (
+                      {threadLibraries.map(lib => (
                         <option key={lib.brand} value={lib.brand}>
                           {lib.name} ({lib.colorCount} colors)
                         </option>
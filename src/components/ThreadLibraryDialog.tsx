@@ -1,11 +1,11 @@
 import { useState, useMemo, useEffect } from 'react';
-import { dmcThreads, ThreadCategory, categoryNames, getThreadsByCategory } from '../data/dmcThreads';
 import { usePatternStore, Color } from '../stores/patternStore';
+import { useThreadLibraryStore } from '../stores/threadLibraryStore';
 import {
   ThreadBrand,
+  ThreadCategory,
   UnifiedThreadColor,
-  getThreadsByBrand,
-  getThreadLibraries,
+  categoryNames,
 } from '../data/threadLibrary';
 
 interface ThreadLibraryDialogProps {
@@ -80,7 +80,14 @@ export function ThreadLibraryDialog({ isOpen, onClose, initialBrand = 'DMC', sho
   const [categoryFilter, setCategoryFilter] = useState<CategoryFilter>('all');
   const [selectedBrand, setSelectedBrand] = useState<ThreadBrand>(initialBrand);
 
-  const threadLibraries = getThreadLibraries();
+  const { libraries: threadLibraries, threadsByBrand, loadLibraries, loadThreads } = useThreadLibraryStore();
+
+  // Load the library list and the selected brand's threads from the backend
+  useEffect(() => {
+    if (!isOpen) return;
+    loadLibraries();
+    loadThreads(selectedBrand);
+  }, [isOpen, selectedBrand, loadLibraries, loadThreads]);
 
   // Update brand when initialBrand prop changes
   useEffect(() => {
@@ -95,22 +102,23 @@ export function ThreadLibraryDialog({ isOpen, onClose, initialBrand = 'DMC', sho
 
   // Get all threads for the selected brand
   const allBrandThreads = useMemo(() => {
-    return getThreadsByBrand(selectedBrand);
-  }, [selectedBrand]);
+    return threadsByBrand[selectedBrand] ?? [];
+  }, [threadsByBrand, selectedBrand]);
 
   // Get thread counts per category (only for DMC)
   const categoryCounts = useMemo(() => {
     if (selectedBrand !== 'DMC') return null;
+    const countOf = (category: ThreadCategory) => allBrandThreads.filter(t => t.category === category).length;
     const counts: Record<CategoryFilter, number> = {
-      'all': dmcThreads.length,
-      'solid': getThreadsByCategory('solid').length,
-      'variegated': getThreadsByCategory('variegated').length,
-      'light-effects': getThreadsByCategory('light-effects').length,
-      'satin': getThreadsByCategory('satin').length,
-      'etoile': getThreadsByCategory('etoile').length,
+      'all': allBrandThreads.length,
+      'solid': countOf('solid'),
+      'variegated': countOf('variegated'),
+      'light-effects': countOf('light-effects'),
+      'satin': countOf('satin'),
+      'etoile': countOf('etoile'),
     };
     return counts;
-  }, [selectedBrand]);
+  }, [selectedBrand, allBrandThreads]);
 
   // Filter and sort threads
   const filteredThreads = useMemo(() => {
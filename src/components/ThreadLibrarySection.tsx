@@ -1,10 +1,10 @@
-import { useState, useRef } from 'react';
+import { useState, useRef, useEffect } from 'react';
 import { usePatternStore, Color } from '../stores/patternStore';
+import { useThreadLibraryStore } from '../stores/threadLibraryStore';
 import { ThreadLibraryDialog } from './ThreadLibraryDialog';
 import {
   ThreadBrand,
-  getThreadsByBrand,
-  getThreadLibraries
+  getThreadByCode
 } from '../data/threadLibrary';
 
 interface ThreadLibrarySectionProps {
@@ -21,7 +21,11 @@ export function ThreadLibrarySection({ showSymbols = true, defaultCollapsed = fa
   const [threadCodeError, setThreadCodeError] = useState('');
   const colorInputRef = useRef<HTMLInputElement>(null);
 
-  const threadLibraries = getThreadLibraries();
+  const { libraries: threadLibraries, loadLibraries } = useThreadLibraryStore();
+
+  useEffect(() => {
+    loadLibraries();
+  }, [loadLibraries]);
 
   if (!pattern) {
     return null;
@@ -46,12 +50,11 @@ export function ThreadLibrarySection({ showSymbols = true, defaultCollapsed = fa
     selectColor(newColor.id);
   };
 
-  const handleAddThreadByCode = () => {
+  const handleAddThreadByCode = async () => {
     const code = threadCodeInput.trim().toUpperCase();
     if (!code) return;
 
-    const threads = getThreadsByBrand(selectedBrand);
-    const thread = threads.find(t => t.code.toUpperCase() === code);
+    const thread = await getThreadByCode(code, selectedBrand);
     if (thread) {
       const newColor: Color = {
         id: `${selectedBrand.toLowerCase()}-${thread.code}-${Date.now()}`,
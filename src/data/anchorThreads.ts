@@ -1,254 +0,0 @@
-// Anchor Stranded Cotton Embroidery Thread Library
-// Comprehensive collection of Anchor thread colors
-
-export interface AnchorThreadColor {
-  code: string;
-  name: string;
-  rgb: [number, number, number];
-  brand: 'Anchor';
-}
-
-export const anchorThreads: AnchorThreadColor[] = [
-  // Whites and Neutrals
-  { code: '1', name: 'White', rgb: [255, 255, 255], brand: 'Anchor' },
-  { code: '2', name: 'Tin White', rgb: [252, 251, 248], brand: 'Anchor' },
-  { code: '275', name: 'Off White', rgb: [248, 246, 240], brand: 'Anchor' },
-  { code: '926', name: 'Ecru', rgb: [240, 234, 218], brand: 'Anchor' },
-  { code: '830', name: 'Cream', rgb: [253, 249, 227], brand: 'Anchor' },
-  { code: '885', name: 'Cream Light', rgb: [255, 251, 233], brand: 'Anchor' },
-  { code: '886', name: 'Cream Very Light', rgb: [255, 253, 241], brand: 'Anchor' },
-
-  // Blacks and Grays
-  { code: '403', name: 'Black', rgb: [0, 0, 0], brand: 'Anchor' },
-  { code: '400', name: 'Steel Gray Dark', rgb: [52, 52, 52], brand: 'Anchor' },
-  { code: '401', name: 'Steel Gray Medium', rgb: [105, 105, 105], brand: 'Anchor' },
-  { code: '398', name: 'Pearl Gray Dark', rgb: [130, 130, 130], brand: 'Anchor' },
-  { code: '399', name: 'Pearl Gray Medium', rgb: [155, 155, 155], brand: 'Anchor' },
-  { code: '397', name: 'Pearl Gray Light', rgb: [180, 180, 180], brand: 'Anchor' },
-  { code: '234', name: 'Pearl Gray Very Light', rgb: [210, 210, 210], brand: 'Anchor' },
-  { code: '235', name: 'Pearl Gray Ultra Light', rgb: [230, 230, 230], brand: 'Anchor' },
-
-  // Reds
-  { code: '13', name: 'Coral Red Light', rgb: [255, 140, 140], brand: 'Anchor' },
-  { code: '11', name: 'Coral Red', rgb: [255, 120, 120], brand: 'Anchor' },
-  { code: '10', name: 'Coral Red Medium', rgb: [255, 95, 95], brand: 'Anchor' },
-  { code: '9', name: 'Coral Red Dark', rgb: [255, 70, 70], brand: 'Anchor' },
-  { code: '46', name: 'Christmas Red', rgb: [205, 0, 0], brand: 'Anchor' },
-  { code: '47', name: 'Christmas Red Bright', rgb: [227, 0, 0], brand: 'Anchor' },
-  { code: '19', name: 'Christmas Red Dark', rgb: [178, 0, 0], brand: 'Anchor' },
-  { code: '20', name: 'Christmas Red Very Dark', rgb: [143, 0, 0], brand: 'Anchor' },
-  { code: '22', name: 'Burgundy', rgb: [115, 0, 0], brand: 'Anchor' },
-  { code: '44', name: 'Carmine', rgb: [200, 45, 45], brand: 'Anchor' },
-  { code: '35', name: 'Terra Cotta Light', rgb: [224, 112, 95], brand: 'Anchor' },
-  { code: '5975', name: 'Terra Cotta', rgb: [190, 85, 70], brand: 'Anchor' },
-  { code: '339', name: 'Terra Cotta Dark', rgb: [165, 60, 45], brand: 'Anchor' },
-  { code: '341', name: 'Terra Cotta Very Dark', rgb: [135, 40, 30], brand: 'Anchor' },
-
-  // Pinks
-  { code: '23', name: 'Rose Pink Light', rgb: [255, 200, 200], brand: 'Anchor' },
-  { code: '24', name: 'Rose Pink', rgb: [255, 175, 175], brand: 'Anchor' },
-  { code: '25', name: 'Rose Pink Medium', rgb: [245, 145, 155], brand: 'Anchor' },
-  { code: '27', name: 'Rose Pink Dark', rgb: [225, 100, 120], brand: 'Anchor' },
-  { code: '28', name: 'Rose Pink Very Dark', rgb: [200, 65, 90], brand: 'Anchor' },
-  { code: '29', name: 'Raspberry Light', rgb: [220, 90, 120], brand: 'Anchor' },
-  { code: '31', name: 'Raspberry', rgb: [200, 60, 100], brand: 'Anchor' },
-  { code: '33', name: 'Raspberry Dark', rgb: [175, 35, 75], brand: 'Anchor' },
-  { code: '36', name: 'Dusty Rose Light', rgb: [225, 175, 180], brand: 'Anchor' },
-  { code: '38', name: 'Dusty Rose', rgb: [200, 145, 155], brand: 'Anchor' },
-  { code: '39', name: 'Dusty Rose Medium', rgb: [180, 120, 135], brand: 'Anchor' },
-  { code: '40', name: 'Dusty Rose Dark', rgb: [155, 90, 105], brand: 'Anchor' },
-  { code: '42', name: 'Dusty Rose Very Dark', rgb: [130, 65, 80], brand: 'Anchor' },
-  { code: '48', name: 'Baby Pink', rgb: [255, 215, 220], brand: 'Anchor' },
-  { code: '49', name: 'Baby Pink Medium', rgb: [255, 190, 200], brand: 'Anchor' },
-  { code: '50', name: 'Baby Pink Dark', rgb: [250, 165, 180], brand: 'Anchor' },
-  { code: '52', name: 'Wild Rose Light', rgb: [240, 185, 190], brand: 'Anchor' },
-  { code: '54', name: 'Wild Rose', rgb: [225, 155, 165], brand: 'Anchor' },
-  { code: '55', name: 'Wild Rose Medium', rgb: [205, 125, 140], brand: 'Anchor' },
-  { code: '57', name: 'Wild Rose Dark', rgb: [180, 95, 110], brand: 'Anchor' },
-  { code: '59', name: 'Wild Rose Very Dark', rgb: [155, 65, 80], brand: 'Anchor' },
-
-  // Oranges
-  { code: '316', name: 'Burnt Orange Light', rgb: [255, 175, 100], brand: 'Anchor' },
-  { code: '314', name: 'Burnt Orange', rgb: [245, 145, 65], brand: 'Anchor' },
-  { code: '329', name: 'Burnt Orange Medium', rgb: [225, 120, 45], brand: 'Anchor' },
-  { code: '330', name: 'Burnt Orange Dark', rgb: [200, 95, 25], brand: 'Anchor' },
-  { code: '332', name: 'Burnt Orange Very Dark', rgb: [170, 70, 15], brand: 'Anchor' },
-  { code: '303', name: 'Pumpkin Light', rgb: [255, 165, 75], brand: 'Anchor' },
-  { code: '304', name: 'Pumpkin', rgb: [250, 140, 45], brand: 'Anchor' },
-  { code: '323', name: 'Pumpkin Medium', rgb: [235, 115, 25], brand: 'Anchor' },
-  { code: '324', name: 'Pumpkin Dark', rgb: [210, 90, 10], brand: 'Anchor' },
-  { code: '326', name: 'Pumpkin Very Dark', rgb: [180, 70, 5], brand: 'Anchor' },
-  { code: '1001', name: 'Melon Light', rgb: [255, 190, 150], brand: 'Anchor' },
-  { code: '8', name: 'Melon', rgb: [255, 165, 120], brand: 'Anchor' },
-  { code: '1002', name: 'Peach', rgb: [255, 185, 140], brand: 'Anchor' },
-  { code: '4146', name: 'Apricot Light', rgb: [255, 200, 165], brand: 'Anchor' },
-  { code: '313', name: 'Apricot', rgb: [255, 175, 130], brand: 'Anchor' },
-
-  // Yellows
-  { code: '293', name: 'Canary Light', rgb: [255, 255, 170], brand: 'Anchor' },
-  { code: '289', name: 'Canary', rgb: [255, 255, 130], brand: 'Anchor' },
-  { code: '290', name: 'Canary Medium', rgb: [255, 250, 90], brand: 'Anchor' },
-  { code: '291', name: 'Canary Dark', rgb: [255, 240, 50], brand: 'Anchor' },
-  { code: '295', name: 'Lemon Light', rgb: [255, 255, 180], brand: 'Anchor' },
-  { code: '288', name: 'Lemon', rgb: [255, 255, 140], brand: 'Anchor' },
-  { code: '286', name: 'Lemon Medium', rgb: [255, 250, 100], brand: 'Anchor' },
-  { code: '298', name: 'Lemon Dark', rgb: [255, 235, 60], brand: 'Anchor' },
-  { code: '300', name: 'Topaz Light', rgb: [255, 230, 160], brand: 'Anchor' },
-  { code: '301', name: 'Topaz', rgb: [255, 220, 120], brand: 'Anchor' },
-  { code: '302', name: 'Topaz Medium', rgb: [255, 205, 80], brand: 'Anchor' },
-  { code: '305', name: 'Topaz Dark', rgb: [245, 185, 50], brand: 'Anchor' },
-  { code: '306', name: 'Topaz Very Dark', rgb: [230, 165, 30], brand: 'Anchor' },
-  { code: '307', name: 'Golden Yellow Light', rgb: [255, 215, 100], brand: 'Anchor' },
-  { code: '308', name: 'Golden Yellow', rgb: [255, 200, 60], brand: 'Anchor' },
-  { code: '309', name: 'Golden Yellow Medium', rgb: [245, 180, 35], brand: 'Anchor' },
-  { code: '310', name: 'Golden Yellow Dark', rgb: [230, 160, 20], brand: 'Anchor' },
-
-  // Greens
-  { code: '238', name: 'Christmas Green Light', rgb: [150, 210, 150], brand: 'Anchor' },
-  { code: '239', name: 'Christmas Green', rgb: [110, 185, 110], brand: 'Anchor' },
-  { code: '226', name: 'Christmas Green Medium', rgb: [75, 160, 75], brand: 'Anchor' },
-  { code: '227', name: 'Christmas Green Dark', rgb: [45, 135, 45], brand: 'Anchor' },
-  { code: '228', name: 'Christmas Green Very Dark', rgb: [20, 110, 20], brand: 'Anchor' },
-  { code: '203', name: 'Parrot Green Light', rgb: [130, 200, 100], brand: 'Anchor' },
-  { code: '204', name: 'Parrot Green', rgb: [95, 175, 65], brand: 'Anchor' },
-  { code: '205', name: 'Parrot Green Medium', rgb: [60, 150, 35], brand: 'Anchor' },
-  { code: '206', name: 'Parrot Green Dark', rgb: [35, 125, 15], brand: 'Anchor' },
-  { code: '208', name: 'Parrot Green Very Dark', rgb: [15, 100, 5], brand: 'Anchor' },
-  { code: '240', name: 'Spring Green Light', rgb: [180, 225, 155], brand: 'Anchor' },
-  { code: '241', name: 'Spring Green', rgb: [145, 205, 120], brand: 'Anchor' },
-  { code: '242', name: 'Spring Green Medium', rgb: [110, 180, 85], brand: 'Anchor' },
-  { code: '243', name: 'Spring Green Dark', rgb: [75, 155, 55], brand: 'Anchor' },
-  { code: '244', name: 'Spring Green Very Dark', rgb: [45, 130, 30], brand: 'Anchor' },
-  { code: '253', name: 'Fern Green Light', rgb: [145, 175, 125], brand: 'Anchor' },
-  { code: '254', name: 'Fern Green', rgb: [115, 150, 95], brand: 'Anchor' },
-  { code: '255', name: 'Fern Green Medium', rgb: [85, 125, 65], brand: 'Anchor' },
-  { code: '256', name: 'Fern Green Dark', rgb: [55, 100, 40], brand: 'Anchor' },
-  { code: '257', name: 'Fern Green Very Dark', rgb: [35, 80, 25], brand: 'Anchor' },
-  { code: '259', name: 'Avocado Light', rgb: [165, 175, 130], brand: 'Anchor' },
-  { code: '260', name: 'Avocado', rgb: [140, 155, 105], brand: 'Anchor' },
-  { code: '261', name: 'Avocado Medium', rgb: [115, 135, 80], brand: 'Anchor' },
-  { code: '262', name: 'Avocado Dark', rgb: [90, 110, 55], brand: 'Anchor' },
-  { code: '263', name: 'Avocado Very Dark', rgb: [65, 85, 35], brand: 'Anchor' },
-  { code: '264', name: 'Moss Green Light', rgb: [185, 195, 130], brand: 'Anchor' },
-  { code: '265', name: 'Moss Green', rgb: [160, 175, 105], brand: 'Anchor' },
-  { code: '266', name: 'Moss Green Medium', rgb: [135, 155, 80], brand: 'Anchor' },
-  { code: '267', name: 'Moss Green Dark', rgb: [110, 130, 55], brand: 'Anchor' },
-  { code: '268', name: 'Moss Green Very Dark', rgb: [85, 105, 35], brand: 'Anchor' },
-  { code: '269', name: 'Olive Light', rgb: [150, 145, 95], brand: 'Anchor' },
-  { code: '280', name: 'Olive', rgb: [130, 125, 75], brand: 'Anchor' },
-  { code: '281', name: 'Olive Medium', rgb: [110, 105, 55], brand: 'Anchor' },
-  { code: '268', name: 'Olive Dark', rgb: [90, 85, 40], brand: 'Anchor' },
-
-  // Blues
-  { code: '128', name: 'Delft Blue Light', rgb: [200, 215, 235], brand: 'Anchor' },
-  { code: '129', name: 'Delft Blue', rgb: [175, 195, 225], brand: 'Anchor' },
-  { code: '130', name: 'Delft Blue Medium', rgb: [145, 170, 210], brand: 'Anchor' },
-  { code: '131', name: 'Delft Blue Dark', rgb: [115, 145, 190], brand: 'Anchor' },
-  { code: '132', name: 'Delft Blue Very Dark', rgb: [85, 120, 170], brand: 'Anchor' },
-  { code: '117', name: 'Wedgwood Light', rgb: [175, 195, 215], brand: 'Anchor' },
-  { code: '118', name: 'Wedgwood', rgb: [145, 170, 195], brand: 'Anchor' },
-  { code: '119', name: 'Wedgwood Medium', rgb: [115, 145, 175], brand: 'Anchor' },
-  { code: '120', name: 'Wedgwood Dark', rgb: [85, 120, 150], brand: 'Anchor' },
-  { code: '121', name: 'Wedgwood Very Dark', rgb: [60, 95, 125], brand: 'Anchor' },
-  { code: '140', name: 'Royal Blue Light', rgb: [130, 160, 210], brand: 'Anchor' },
-  { code: '141', name: 'Royal Blue', rgb: [100, 130, 190], brand: 'Anchor' },
-  { code: '142', name: 'Royal Blue Medium', rgb: [70, 100, 170], brand: 'Anchor' },
-  { code: '143', name: 'Royal Blue Dark', rgb: [45, 75, 145], brand: 'Anchor' },
-  { code: '144', name: 'Royal Blue Very Dark', rgb: [25, 50, 120], brand: 'Anchor' },
-  { code: '145', name: 'Sky Blue Light', rgb: [185, 220, 245], brand: 'Anchor' },
-  { code: '146', name: 'Sky Blue', rgb: [155, 200, 235], brand: 'Anchor' },
-  { code: '147', name: 'Sky Blue Medium', rgb: [125, 175, 220], brand: 'Anchor' },
-  { code: '148', name: 'Sky Blue Dark', rgb: [95, 150, 200], brand: 'Anchor' },
-  { code: '149', name: 'Sky Blue Very Dark', rgb: [65, 125, 180], brand: 'Anchor' },
-  { code: '150', name: 'Cornflower Light', rgb: [170, 190, 225], brand: 'Anchor' },
-  { code: '152', name: 'Cornflower', rgb: [135, 160, 205], brand: 'Anchor' },
-  { code: '153', name: 'Cornflower Medium', rgb: [100, 130, 185], brand: 'Anchor' },
-  { code: '154', name: 'Cornflower Dark', rgb: [70, 100, 165], brand: 'Anchor' },
-  { code: '155', name: 'Peacock Blue Light', rgb: [125, 180, 200], brand: 'Anchor' },
-  { code: '156', name: 'Peacock Blue', rgb: [90, 155, 180], brand: 'Anchor' },
-  { code: '158', name: 'Peacock Blue Medium', rgb: [55, 130, 160], brand: 'Anchor' },
-  { code: '159', name: 'Peacock Blue Dark', rgb: [30, 105, 140], brand: 'Anchor' },
-  { code: '160', name: 'Peacock Blue Very Dark', rgb: [10, 80, 115], brand: 'Anchor' },
-  { code: '167', name: 'Navy Blue Light', rgb: [100, 115, 145], brand: 'Anchor' },
-  { code: '168', name: 'Navy Blue', rgb: [70, 85, 120], brand: 'Anchor' },
-  { code: '169', name: 'Navy Blue Medium', rgb: [45, 60, 95], brand: 'Anchor' },
-  { code: '170', name: 'Navy Blue Dark', rgb: [25, 40, 70], brand: 'Anchor' },
-  { code: '176', name: 'Turquoise Light', rgb: [145, 215, 215], brand: 'Anchor' },
-  { code: '177', name: 'Turquoise', rgb: [110, 195, 200], brand: 'Anchor' },
-  { code: '178', name: 'Turquoise Medium', rgb: [75, 175, 185], brand: 'Anchor' },
-  { code: '179', name: 'Turquoise Dark', rgb: [45, 150, 165], brand: 'Anchor' },
-
-  // Purples and Violets
-  { code: '85', name: 'Lavender Light', rgb: [220, 200, 225], brand: 'Anchor' },
-  { code: '86', name: 'Lavender', rgb: [200, 175, 210], brand: 'Anchor' },
-  { code: '87', name: 'Lavender Medium', rgb: [175, 150, 190], brand: 'Anchor' },
-  { code: '88', name: 'Lavender Dark', rgb: [150, 125, 170], brand: 'Anchor' },
-  { code: '89', name: 'Lavender Very Dark', rgb: [125, 100, 150], brand: 'Anchor' },
-  { code: '95', name: 'Violet Light', rgb: [195, 175, 210], brand: 'Anchor' },
-  { code: '96', name: 'Violet', rgb: [170, 145, 195], brand: 'Anchor' },
-  { code: '97', name: 'Violet Medium', rgb: [145, 115, 175], brand: 'Anchor' },
-  { code: '98', name: 'Violet Dark', rgb: [120, 85, 155], brand: 'Anchor' },
-  { code: '99', name: 'Violet Very Dark', rgb: [95, 55, 135], brand: 'Anchor' },
-  { code: '100', name: 'Purple Light', rgb: [180, 145, 195], brand: 'Anchor' },
-  { code: '101', name: 'Purple', rgb: [155, 110, 175], brand: 'Anchor' },
-  { code: '102', name: 'Purple Medium', rgb: [130, 75, 155], brand: 'Anchor' },
-  { code: '103', name: 'Purple Dark', rgb: [105, 45, 135], brand: 'Anchor' },
-  { code: '104', name: 'Purple Very Dark', rgb: [80, 20, 110], brand: 'Anchor' },
-  { code: '108', name: 'Grape Light', rgb: [165, 130, 165], brand: 'Anchor' },
-  { code: '109', name: 'Grape', rgb: [140, 100, 145], brand: 'Anchor' },
-  { code: '110', name: 'Grape Medium', rgb: [115, 70, 120], brand: 'Anchor' },
-  { code: '111', name: 'Grape Dark', rgb: [90, 45, 95], brand: 'Anchor' },
-  { code: '112', name: 'Grape Very Dark', rgb: [65, 25, 70], brand: 'Anchor' },
-
-  // Browns
-  { code: '358', name: 'Tan Light', rgb: [225, 195, 160], brand: 'Anchor' },
-  { code: '359', name: 'Tan', rgb: [205, 170, 130], brand: 'Anchor' },
-  { code: '360', name: 'Tan Medium', rgb: [185, 145, 100], brand: 'Anchor' },
-  { code: '361', name: 'Tan Dark', rgb: [160, 120, 75], brand: 'Anchor' },
-  { code: '362', name: 'Tan Very Dark', rgb: [135, 95, 55], brand: 'Anchor' },
-  { code: '363', name: 'Golden Brown Light', rgb: [215, 175, 115], brand: 'Anchor' },
-  { code: '365', name: 'Golden Brown', rgb: [190, 145, 85], brand: 'Anchor' },
-  { code: '367', name: 'Golden Brown Medium', rgb: [165, 115, 55], brand: 'Anchor' },
-  { code: '368', name: 'Golden Brown Dark', rgb: [140, 90, 35], brand: 'Anchor' },
-  { code: '369', name: 'Golden Brown Very Dark', rgb: [115, 70, 20], brand: 'Anchor' },
-  { code: '370', name: 'Pecan Light', rgb: [200, 160, 120], brand: 'Anchor' },
-  { code: '371', name: 'Pecan', rgb: [175, 135, 95], brand: 'Anchor' },
-  { code: '372', name: 'Pecan Medium', rgb: [150, 110, 70], brand: 'Anchor' },
-  { code: '373', name: 'Pecan Dark', rgb: [125, 85, 50], brand: 'Anchor' },
-  { code: '374', name: 'Pecan Very Dark', rgb: [100, 65, 35], brand: 'Anchor' },
-  { code: '375', name: 'Mocha Light', rgb: [185, 145, 115], brand: 'Anchor' },
-  { code: '376', name: 'Mocha', rgb: [160, 120, 90], brand: 'Anchor' },
-  { code: '379', name: 'Mocha Medium', rgb: [135, 95, 65], brand: 'Anchor' },
-  { code: '380', name: 'Mocha Dark', rgb: [110, 70, 45], brand: 'Anchor' },
-  { code: '381', name: 'Mocha Very Dark', rgb: [85, 50, 30], brand: 'Anchor' },
-  { code: '382', name: 'Coffee Light', rgb: [170, 130, 100], brand: 'Anchor' },
-  { code: '387', name: 'Coffee', rgb: [145, 105, 75], brand: 'Anchor' },
-  { code: '388', name: 'Coffee Medium', rgb: [120, 80, 50], brand: 'Anchor' },
-  { code: '389', name: 'Coffee Dark', rgb: [95, 55, 30], brand: 'Anchor' },
-  { code: '390', name: 'Coffee Very Dark', rgb: [70, 35, 15], brand: 'Anchor' },
-  { code: '391', name: 'Beige Light', rgb: [230, 215, 195], brand: 'Anchor' },
-  { code: '392', name: 'Beige', rgb: [210, 190, 165], brand: 'Anchor' },
-  { code: '393', name: 'Beige Medium', rgb: [190, 165, 140], brand: 'Anchor' },
-  { code: '394', name: 'Beige Dark', rgb: [170, 140, 115], brand: 'Anchor' },
-  { code: '395', name: 'Beige Very Dark', rgb: [145, 115, 90], brand: 'Anchor' },
-  { code: '896', name: 'Sandalwood Light', rgb: [220, 200, 175], brand: 'Anchor' },
-  { code: '897', name: 'Sandalwood', rgb: [195, 170, 145], brand: 'Anchor' },
-  { code: '898', name: 'Sandalwood Medium', rgb: [170, 140, 115], brand: 'Anchor' },
-  { code: '899', name: 'Sandalwood Dark', rgb: [145, 115, 90], brand: 'Anchor' },
-  { code: '900', name: 'Sandalwood Very Dark', rgb: [120, 90, 65], brand: 'Anchor' },
-  { code: '901', name: 'Mahogany Light', rgb: [175, 115, 80], brand: 'Anchor' },
-  { code: '902', name: 'Mahogany', rgb: [150, 90, 55], brand: 'Anchor' },
-  { code: '903', name: 'Mahogany Medium', rgb: [125, 65, 35], brand: 'Anchor' },
-  { code: '904', name: 'Mahogany Dark', rgb: [100, 45, 20], brand: 'Anchor' },
-  { code: '905', name: 'Mahogany Very Dark', rgb: [75, 30, 10], brand: 'Anchor' },
-  { code: '914', name: 'Chocolate Light', rgb: [160, 110, 85], brand: 'Anchor' },
-  { code: '929', name: 'Chocolate', rgb: [135, 85, 60], brand: 'Anchor' },
-  { code: '936', name: 'Chocolate Medium', rgb: [110, 60, 40], brand: 'Anchor' },
-  { code: '938', name: 'Chocolate Dark', rgb: [85, 40, 25], brand: 'Anchor' },
-  { code: '381', name: 'Chocolate Very Dark', rgb: [60, 25, 15], brand: 'Anchor' },
-
-  // Metallics and Specialty (common conversions)
-  { code: '278', name: 'Metallic Gold Light', rgb: [255, 220, 130], brand: 'Anchor' },
-  { code: '303', name: 'Metallic Gold', rgb: [245, 195, 75], brand: 'Anchor' },
-  { code: '306', name: 'Metallic Gold Dark', rgb: [220, 165, 35], brand: 'Anchor' },
-];
@@ -1,741 +0,0 @@
-// DMC Cotton Embroidery Floss Color Library
-// Complete collection including all DMC thread lines
-
-export type ThreadCategory = 'solid' | 'variegated' | 'light-effects' | 'satin' | 'etoile';
-
-export interface ThreadColor {
-  code: string;
-  name: string;
-  rgb: [number, number, number];
-  brand: 'DMC';
-  category: ThreadCategory;
-}
-
-export const categoryNames: Record<ThreadCategory, string> = {
-  'solid': 'Solid Colors',
-  'variegated': 'Color Variations',
-  'light-effects': 'Light Effects',
-  'satin': 'Satin Floss',
-  'etoile': 'Étoile (Sparkle)',
-};
-
-// ============================================
-// SOLID COLORS (Standard Mouliné Floss)
-// ============================================
-const solidColors: ThreadColor[] = [
-  // Whites and Ecrus
-  { code: 'B5200', name: 'Snow White', rgb: [255, 255, 255], brand: 'DMC', category: 'solid' },
-  { code: 'White', name: 'White', rgb: [252, 251, 248], brand: 'DMC', category: 'solid' },
-  { code: 'Ecru', name: 'Ecru', rgb: [240, 234, 218], brand: 'DMC', category: 'solid' },
-
-  // 100s
-  { code: '150', name: 'Dusty Rose Ultra Vy Dk', rgb: [171, 2, 73], brand: 'DMC', category: 'solid' },
-  { code: '151', name: 'Dusty Rose Vry Lt', rgb: [240, 206, 212], brand: 'DMC', category: 'solid' },
-  { code: '152', name: 'Shell Pink Med Light', rgb: [226, 160, 153], brand: 'DMC', category: 'solid' },
-  { code: '153', name: 'Violet Very Light', rgb: [230, 204, 217], brand: 'DMC', category: 'solid' },
-  { code: '154', name: 'Grape Very Dark', rgb: [87, 36, 51], brand: 'DMC', category: 'solid' },
-  { code: '155', name: 'Blue Violet Med Dk', rgb: [152, 145, 182], brand: 'DMC', category: 'solid' },
-  { code: '156', name: 'Blue Violet Med Lt', rgb: [163, 174, 209], brand: 'DMC', category: 'solid' },
-  { code: '157', name: 'Cornflower Blue Vy Lt', rgb: [187, 195, 217], brand: 'DMC', category: 'solid' },
-  { code: '158', name: 'Cornflower Blu M V D', rgb: [76, 82, 110], brand: 'DMC', category: 'solid' },
-  { code: '159', name: 'Blue Gray Light', rgb: [199, 202, 215], brand: 'DMC', category: 'solid' },
-  { code: '160', name: 'Blue Gray Medium', rgb: [153, 159, 183], brand: 'DMC', category: 'solid' },
-  { code: '161', name: 'Blue Gray', rgb: [120, 128, 164], brand: 'DMC', category: 'solid' },
-  { code: '162', name: 'Blue Ultra Very Light', rgb: [219, 236, 245], brand: 'DMC', category: 'solid' },
-  { code: '163', name: 'Celadon Green Md', rgb: [77, 131, 97], brand: 'DMC', category: 'solid' },
-  { code: '164', name: 'Forest Green Lt', rgb: [200, 216, 184], brand: 'DMC', category: 'solid' },
-  { code: '165', name: 'Moss Green Vy Lt', rgb: [239, 244, 164], brand: 'DMC', category: 'solid' },
-  { code: '166', name: 'Moss Green Md Lt', rgb: [192, 200, 64], brand: 'DMC', category: 'solid' },
-  { code: '167', name: 'Yellow Beige V Dk', rgb: [167, 124, 73], brand: 'DMC', category: 'solid' },
-  { code: '168', name: 'Pewter Very Light', rgb: [209, 209, 209], brand: 'DMC', category: 'solid' },
-  { code: '169', name: 'Pewter Light', rgb: [132, 132, 132], brand: 'DMC', category: 'solid' },
-
-  // 200s
-  { code: '208', name: 'Lavender Very Dark', rgb: [131, 91, 139], brand: 'DMC', category: 'solid' },
-  { code: '209', name: 'Lavender Dark', rgb: [163, 123, 167], brand: 'DMC', category: 'solid' },
-  { code: '210', name: 'Lavender Medium', rgb: [195, 159, 195], brand: 'DMC', category: 'solid' },
-  { code: '211', name: 'Lavender Light', rgb: [227, 203, 227], brand: 'DMC', category: 'solid' },
-  { code: '221', name: 'Shell Pink Vy Dk', rgb: [136, 62, 67], brand: 'DMC', category: 'solid' },
-  { code: '223', name: 'Shell Pink Light', rgb: [204, 132, 124], brand: 'DMC', category: 'solid' },
-  { code: '224', name: 'Shell Pink Very Light', rgb: [235, 183, 175], brand: 'DMC', category: 'solid' },
-  { code: '225', name: 'Shell Pink Ult Vy Lt', rgb: [255, 223, 213], brand: 'DMC', category: 'solid' },
-
-  // 300s - Browns, Tans, Reds, Blues
-  { code: '300', name: 'Mahogany Very Dark', rgb: [111, 47, 0], brand: 'DMC', category: 'solid' },
-  { code: '301', name: 'Mahogany Medium', rgb: [179, 95, 43], brand: 'DMC', category: 'solid' },
-  { code: '304', name: 'Christmas Red Medium', rgb: [183, 31, 51], brand: 'DMC', category: 'solid' },
-  { code: '307', name: 'Lemon', rgb: [253, 237, 84], brand: 'DMC', category: 'solid' },
-  { code: '309', name: 'Rose Dark', rgb: [186, 74, 74], brand: 'DMC', category: 'solid' },
-  { code: '310', name: 'Black', rgb: [0, 0, 0], brand: 'DMC', category: 'solid' },
-  { code: '311', name: 'Navy Blue Medium', rgb: [28, 80, 102], brand: 'DMC', category: 'solid' },
-  { code: '312', name: 'Navy Blue Light', rgb: [53, 102, 139], brand: 'DMC', category: 'solid' },
-  { code: '315', name: 'Antique Mauve Md Dk', rgb: [129, 73, 82], brand: 'DMC', category: 'solid' },
-  { code: '316', name: 'Antique Mauve Med', rgb: [183, 115, 127], brand: 'DMC', category: 'solid' },
-  { code: '317', name: 'Pewter Gray', rgb: [108, 108, 108], brand: 'DMC', category: 'solid' },
-  { code: '318', name: 'Steel Gray Light', rgb: [171, 171, 171], brand: 'DMC', category: 'solid' },
-  { code: '319', name: 'Pistachio Grn Vy Dk', rgb: [32, 95, 46], brand: 'DMC', category: 'solid' },
-  { code: '320', name: 'Pistachio Green Med', rgb: [105, 136, 90], brand: 'DMC', category: 'solid' },
-  { code: '321', name: 'Christmas Red', rgb: [199, 43, 59], brand: 'DMC', category: 'solid' },
-  { code: '322', name: 'Navy Blue Very Light', rgb: [90, 143, 184], brand: 'DMC', category: 'solid' },
-  { code: '326', name: 'Rose Very Dark', rgb: [179, 59, 75], brand: 'DMC', category: 'solid' },
-  { code: '327', name: 'Violet Dark', rgb: [99, 54, 102], brand: 'DMC', category: 'solid' },
-  { code: '333', name: 'Blue Violet Very Dark', rgb: [92, 84, 120], brand: 'DMC', category: 'solid' },
-  { code: '334', name: 'Baby Blue Medium', rgb: [115, 159, 193], brand: 'DMC', category: 'solid' },
-  { code: '335', name: 'Rose', rgb: [238, 84, 110], brand: 'DMC', category: 'solid' },
-  { code: '336', name: 'Navy Blue', rgb: [37, 59, 115], brand: 'DMC', category: 'solid' },
-  { code: '340', name: 'Blue Violet Medium', rgb: [173, 167, 199], brand: 'DMC', category: 'solid' },
-  { code: '341', name: 'Blue Violet Light', rgb: [183, 191, 221], brand: 'DMC', category: 'solid' },
-  { code: '347', name: 'Salmon Very Dark', rgb: [191, 45, 45], brand: 'DMC', category: 'solid' },
-  { code: '349', name: 'Coral Dark', rgb: [210, 16, 53], brand: 'DMC', category: 'solid' },
-  { code: '350', name: 'Coral Medium', rgb: [224, 72, 72], brand: 'DMC', category: 'solid' },
-  { code: '351', name: 'Coral', rgb: [233, 106, 103], brand: 'DMC', category: 'solid' },
-  { code: '352', name: 'Coral Light', rgb: [253, 156, 151], brand: 'DMC', category: 'solid' },
-  { code: '353', name: 'Peach', rgb: [254, 215, 204], brand: 'DMC', category: 'solid' },
-  { code: '355', name: 'Terra Cotta Dark', rgb: [152, 68, 54], brand: 'DMC', category: 'solid' },
-  { code: '356', name: 'Terra Cotta Medium', rgb: [197, 106, 91], brand: 'DMC', category: 'solid' },
-  { code: '367', name: 'Pistachio Green Dk', rgb: [97, 122, 82], brand: 'DMC', category: 'solid' },
-  { code: '368', name: 'Pistachio Green Lt', rgb: [166, 194, 152], brand: 'DMC', category: 'solid' },
-  { code: '369', name: 'Pistachio Green Vy Lt', rgb: [215, 237, 204], brand: 'DMC', category: 'solid' },
-  { code: '370', name: 'Mustard Medium', rgb: [184, 157, 100], brand: 'DMC', category: 'solid' },
-  { code: '371', name: 'Mustard', rgb: [191, 166, 113], brand: 'DMC', category: 'solid' },
-  { code: '372', name: 'Mustard Light', rgb: [204, 183, 132], brand: 'DMC', category: 'solid' },
-
-  // 400s
-  { code: '400', name: 'Mahogany Dark', rgb: [143, 67, 15], brand: 'DMC', category: 'solid' },
-  { code: '402', name: 'Mahogany Very Light', rgb: [247, 167, 119], brand: 'DMC', category: 'solid' },
-  { code: '407', name: 'Desert Sand Med', rgb: [187, 129, 97], brand: 'DMC', category: 'solid' },
-  { code: '413', name: 'Pewter Gray Dark', rgb: [86, 86, 86], brand: 'DMC', category: 'solid' },
-  { code: '414', name: 'Steel Gray Dark', rgb: [140, 140, 140], brand: 'DMC', category: 'solid' },
-  { code: '415', name: 'Pearl Gray', rgb: [211, 211, 214], brand: 'DMC', category: 'solid' },
-  { code: '420', name: 'Hazelnut Brown Dk', rgb: [160, 112, 66], brand: 'DMC', category: 'solid' },
-  { code: '422', name: 'Hazelnut Brown Lt', rgb: [198, 159, 123], brand: 'DMC', category: 'solid' },
-  { code: '433', name: 'Brown Medium', rgb: [122, 69, 31], brand: 'DMC', category: 'solid' },
-  { code: '434', name: 'Brown Light', rgb: [152, 94, 51], brand: 'DMC', category: 'solid' },
-  { code: '435', name: 'Brown Very Light', rgb: [184, 119, 72], brand: 'DMC', category: 'solid' },
-  { code: '436', name: 'Tan', rgb: [203, 144, 81], brand: 'DMC', category: 'solid' },
-  { code: '437', name: 'Tan Light', rgb: [228, 187, 142], brand: 'DMC', category: 'solid' },
-  { code: '444', name: 'Lemon Dark', rgb: [255, 214, 0], brand: 'DMC', category: 'solid' },
-  { code: '445', name: 'Lemon Light', rgb: [255, 251, 139], brand: 'DMC', category: 'solid' },
-  { code: '451', name: 'Shell Gray Dark', rgb: [145, 123, 115], brand: 'DMC', category: 'solid' },
-  { code: '452', name: 'Shell Gray Medium', rgb: [192, 179, 174], brand: 'DMC', category: 'solid' },
-  { code: '453', name: 'Shell Gray Light', rgb: [215, 206, 203], brand: 'DMC', category: 'solid' },
-  { code: '469', name: 'Avocado Green', rgb: [114, 132, 60], brand: 'DMC', category: 'solid' },
-  { code: '470', name: 'Avocado Green Light', rgb: [148, 171, 79], brand: 'DMC', category: 'solid' },
-  { code: '471', name: 'Avocado Grn Vy Lt', rgb: [174, 191, 121], brand: 'DMC', category: 'solid' },
-  { code: '472', name: 'Avocado Grn Ult Lt', rgb: [216, 228, 152], brand: 'DMC', category: 'solid' },
-  { code: '498', name: 'Christmas Red Dark', rgb: [167, 19, 43], brand: 'DMC', category: 'solid' },
-
-  // 500s - Greens
-  { code: '500', name: 'Blue Green Vy Dk', rgb: [4, 77, 51], brand: 'DMC', category: 'solid' },
-  { code: '501', name: 'Blue Green Dark', rgb: [57, 111, 82], brand: 'DMC', category: 'solid' },
-  { code: '502', name: 'Blue Green', rgb: [91, 144, 113], brand: 'DMC', category: 'solid' },
-  { code: '503', name: 'Blue Green Medium', rgb: [123, 172, 148], brand: 'DMC', category: 'solid' },
-  { code: '504', name: 'Blue Green Very Light', rgb: [196, 222, 204], brand: 'DMC', category: 'solid' },
-  { code: '505', name: 'Jade Green', rgb: [51, 131, 98], brand: 'DMC', category: 'solid' },
-  { code: '517', name: 'Wedgwood Dark', rgb: [59, 118, 143], brand: 'DMC', category: 'solid' },
-  { code: '518', name: 'Wedgwood Light', rgb: [79, 147, 167], brand: 'DMC', category: 'solid' },
-  { code: '519', name: 'Sky Blue', rgb: [126, 177, 200], brand: 'DMC', category: 'solid' },
-  { code: '520', name: 'Fern Green Dark', rgb: [102, 109, 79], brand: 'DMC', category: 'solid' },
-  { code: '522', name: 'Fern Green', rgb: [150, 158, 126], brand: 'DMC', category: 'solid' },
-  { code: '523', name: 'Fern Green Light', rgb: [171, 177, 151], brand: 'DMC', category: 'solid' },
-  { code: '524', name: 'Fern Green Very Light', rgb: [196, 205, 172], brand: 'DMC', category: 'solid' },
-  { code: '535', name: 'Ash Gray Very Light', rgb: [99, 100, 88], brand: 'DMC', category: 'solid' },
-  { code: '543', name: 'Beige Brown Ult Vy Lt', rgb: [242, 227, 206], brand: 'DMC', category: 'solid' },
-  { code: '550', name: 'Violet Very Dark', rgb: [92, 24, 78], brand: 'DMC', category: 'solid' },
-  { code: '552', name: 'Violet Medium', rgb: [128, 58, 107], brand: 'DMC', category: 'solid' },
-  { code: '553', name: 'Violet', rgb: [163, 99, 139], brand: 'DMC', category: 'solid' },
-  { code: '554', name: 'Violet Light', rgb: [219, 179, 203], brand: 'DMC', category: 'solid' },
-  { code: '561', name: 'Jade Very Dark', rgb: [44, 106, 69], brand: 'DMC', category: 'solid' },
-  { code: '562', name: 'Jade Medium', rgb: [83, 151, 106], brand: 'DMC', category: 'solid' },
-  { code: '563', name: 'Jade Light', rgb: [143, 192, 152], brand: 'DMC', category: 'solid' },
-  { code: '564', name: 'Jade Very Light', rgb: [167, 205, 175], brand: 'DMC', category: 'solid' },
-  { code: '580', name: 'Moss Green Dk', rgb: [136, 141, 51], brand: 'DMC', category: 'solid' },
-  { code: '581', name: 'Moss Green', rgb: [167, 174, 56], brand: 'DMC', category: 'solid' },
-  { code: '597', name: 'Turquoise', rgb: [91, 163, 179], brand: 'DMC', category: 'solid' },
-  { code: '598', name: 'Turquoise Light', rgb: [144, 195, 204], brand: 'DMC', category: 'solid' },
-
-  // 600s - Pinks and Reds
-  { code: '600', name: 'Cranberry Very Dark', rgb: [205, 47, 99], brand: 'DMC', category: 'solid' },
-  { code: '601', name: 'Cranberry Dark', rgb: [209, 40, 106], brand: 'DMC', category: 'solid' },
-  { code: '602', name: 'Cranberry Medium', rgb: [226, 72, 116], brand: 'DMC', category: 'solid' },
-  { code: '603', name: 'Cranberry', rgb: [255, 115, 140], brand: 'DMC', category: 'solid' },
-  { code: '604', name: 'Cranberry Light', rgb: [255, 176, 190], brand: 'DMC', category: 'solid' },
-  { code: '605', name: 'Cranberry Very Light', rgb: [255, 192, 205], brand: 'DMC', category: 'solid' },
-  { code: '606', name: 'Bright Orange-Red', rgb: [250, 50, 3], brand: 'DMC', category: 'solid' },
-  { code: '608', name: 'Bright Orange', rgb: [253, 93, 53], brand: 'DMC', category: 'solid' },
-  { code: '610', name: 'Drab Brown Dk', rgb: [121, 96, 71], brand: 'DMC', category: 'solid' },
-  { code: '611', name: 'Drab Brown', rgb: [150, 118, 86], brand: 'DMC', category: 'solid' },
-  { code: '612', name: 'Drab Brown Light', rgb: [188, 154, 120], brand: 'DMC', category: 'solid' },
-  { code: '613', name: 'Drab Brown Vy Lt', rgb: [220, 196, 170], brand: 'DMC', category: 'solid' },
-  { code: '632', name: 'Desert Sand Ult Vy Dk', rgb: [135, 85, 57], brand: 'DMC', category: 'solid' },
-  { code: '640', name: 'Beige Gray Vy Dk', rgb: [133, 123, 108], brand: 'DMC', category: 'solid' },
-  { code: '642', name: 'Beige Gray Dark', rgb: [164, 152, 134], brand: 'DMC', category: 'solid' },
-  { code: '644', name: 'Beige Gray Medium', rgb: [221, 216, 203], brand: 'DMC', category: 'solid' },
-  { code: '645', name: 'Beaver Gray Vy Dk', rgb: [110, 101, 92], brand: 'DMC', category: 'solid' },
-  { code: '646', name: 'Beaver Gray Dk', rgb: [135, 125, 115], brand: 'DMC', category: 'solid' },
-  { code: '647', name: 'Beaver Gray Med', rgb: [176, 166, 156], brand: 'DMC', category: 'solid' },
-  { code: '648', name: 'Beaver Gray Light', rgb: [188, 180, 172], brand: 'DMC', category: 'solid' },
-  { code: '666', name: 'Christmas Red Bright', rgb: [227, 29, 66], brand: 'DMC', category: 'solid' },
-  { code: '676', name: 'Old Gold Light', rgb: [229, 206, 151], brand: 'DMC', category: 'solid' },
-  { code: '677', name: 'Old Gold Vy Lt', rgb: [245, 236, 203], brand: 'DMC', category: 'solid' },
-  { code: '680', name: 'Old Gold Dark', rgb: [188, 141, 14], brand: 'DMC', category: 'solid' },
-  { code: '699', name: 'Christmas Green', rgb: [5, 101, 23], brand: 'DMC', category: 'solid' },
-
-  // 700s - Greens and Blues
-  { code: '700', name: 'Christmas Green Bright', rgb: [7, 115, 27], brand: 'DMC', category: 'solid' },
-  { code: '701', name: 'Christmas Green Light', rgb: [63, 143, 41], brand: 'DMC', category: 'solid' },
-  { code: '702', name: 'Kelly Green', rgb: [71, 167, 47], brand: 'DMC', category: 'solid' },
-  { code: '703', name: 'Chartreuse', rgb: [123, 181, 71], brand: 'DMC', category: 'solid' },
-  { code: '704', name: 'Chartreuse Bright', rgb: [158, 207, 52], brand: 'DMC', category: 'solid' },
-  { code: '712', name: 'Cream', rgb: [255, 251, 239], brand: 'DMC', category: 'solid' },
-  { code: '718', name: 'Plum', rgb: [156, 36, 98], brand: 'DMC', category: 'solid' },
-  { code: '720', name: 'Orange Spice Dark', rgb: [229, 92, 31], brand: 'DMC', category: 'solid' },
-  { code: '721', name: 'Orange Spice Medium', rgb: [242, 120, 66], brand: 'DMC', category: 'solid' },
-  { code: '722', name: 'Orange Spice Light', rgb: [247, 151, 111], brand: 'DMC', category: 'solid' },
-  { code: '725', name: 'Topaz', rgb: [255, 200, 64], brand: 'DMC', category: 'solid' },
-  { code: '726', name: 'Topaz Light', rgb: [253, 215, 85], brand: 'DMC', category: 'solid' },
-  { code: '727', name: 'Topaz Very Light', rgb: [255, 241, 175], brand: 'DMC', category: 'solid' },
-  { code: '728', name: 'Topaz Golden', rgb: [228, 180, 104], brand: 'DMC', category: 'solid' },
-  { code: '729', name: 'Old Gold Medium', rgb: [208, 165, 62], brand: 'DMC', category: 'solid' },
-  { code: '730', name: 'Olive Green Vy Dk', rgb: [130, 123, 48], brand: 'DMC', category: 'solid' },
-  { code: '731', name: 'Olive Green Dark', rgb: [147, 139, 55], brand: 'DMC', category: 'solid' },
-  { code: '732', name: 'Olive Green', rgb: [148, 140, 54], brand: 'DMC', category: 'solid' },
-  { code: '733', name: 'Olive Green Med', rgb: [188, 179, 76], brand: 'DMC', category: 'solid' },
-  { code: '734', name: 'Olive Green Light', rgb: [199, 192, 119], brand: 'DMC', category: 'solid' },
-  { code: '738', name: 'Tan Very Light', rgb: [236, 204, 158], brand: 'DMC', category: 'solid' },
-  { code: '739', name: 'Tan Ultra Vy Lt', rgb: [248, 228, 200], brand: 'DMC', category: 'solid' },
-  { code: '740', name: 'Tangerine', rgb: [255, 139, 0], brand: 'DMC', category: 'solid' },
-  { code: '741', name: 'Tangerine Medium', rgb: [255, 163, 43], brand: 'DMC', category: 'solid' },
-  { code: '742', name: 'Tangerine Light', rgb: [255, 191, 87], brand: 'DMC', category: 'solid' },
-  { code: '743', name: 'Yellow Medium', rgb: [254, 211, 118], brand: 'DMC', category: 'solid' },
-  { code: '744', name: 'Yellow Pale', rgb: [255, 231, 147], brand: 'DMC', category: 'solid' },
-  { code: '745', name: 'Yellow Pale Light', rgb: [255, 233, 173], brand: 'DMC', category: 'solid' },
-  { code: '746', name: 'Off White', rgb: [252, 252, 238], brand: 'DMC', category: 'solid' },
-  { code: '747', name: 'Sky Blue Very Light', rgb: [229, 252, 253], brand: 'DMC', category: 'solid' },
-  { code: '754', name: 'Peach Light', rgb: [247, 203, 191], brand: 'DMC', category: 'solid' },
-  { code: '758', name: 'Terra Cotta Vy Lt', rgb: [238, 170, 155], brand: 'DMC', category: 'solid' },
-  { code: '760', name: 'Salmon', rgb: [245, 173, 173], brand: 'DMC', category: 'solid' },
-  { code: '761', name: 'Salmon Light', rgb: [255, 201, 201], brand: 'DMC', category: 'solid' },
-  { code: '762', name: 'Pearl Gray Vy Lt', rgb: [236, 236, 236], brand: 'DMC', category: 'solid' },
-  { code: '772', name: 'Yellow Green Vy Lt', rgb: [228, 236, 212], brand: 'DMC', category: 'solid' },
-  { code: '775', name: 'Baby Blue Very Light', rgb: [217, 235, 241], brand: 'DMC', category: 'solid' },
-  { code: '776', name: 'Pink Medium', rgb: [252, 176, 185], brand: 'DMC', category: 'solid' },
-  { code: '777', name: 'Raspberry Very Dark', rgb: [145, 53, 70], brand: 'DMC', category: 'solid' },
-  { code: '778', name: 'Antique Mauve Vy Lt', rgb: [223, 179, 187], brand: 'DMC', category: 'solid' },
-  { code: '779', name: 'Cocoa Dark', rgb: [98, 75, 69], brand: 'DMC', category: 'solid' },
-  { code: '780', name: 'Topaz Ultra Vy Dk', rgb: [148, 99, 26], brand: 'DMC', category: 'solid' },
-  { code: '781', name: 'Topaz Very Dark', rgb: [162, 109, 32], brand: 'DMC', category: 'solid' },
-  { code: '782', name: 'Topaz Dark', rgb: [174, 119, 32], brand: 'DMC', category: 'solid' },
-  { code: '783', name: 'Topaz Medium', rgb: [206, 145, 36], brand: 'DMC', category: 'solid' },
-  { code: '791', name: 'Cornflower Blue V D', rgb: [70, 69, 99], brand: 'DMC', category: 'solid' },
-  { code: '792', name: 'Cornflower Blue Dark', rgb: [85, 91, 123], brand: 'DMC', category: 'solid' },
-  { code: '793', name: 'Cornflower Blue Med', rgb: [112, 125, 162], brand: 'DMC', category: 'solid' },
-  { code: '794', name: 'Cornflower Blue Light', rgb: [143, 156, 193], brand: 'DMC', category: 'solid' },
-  { code: '796', name: 'Royal Blue Dark', rgb: [17, 65, 109], brand: 'DMC', category: 'solid' },
-  { code: '797', name: 'Royal Blue', rgb: [19, 71, 125], brand: 'DMC', category: 'solid' },
-  { code: '798', name: 'Delft Blue Dark', rgb: [70, 106, 142], brand: 'DMC', category: 'solid' },
-  { code: '799', name: 'Delft Blue Medium', rgb: [116, 142, 182], brand: 'DMC', category: 'solid' },
-
-  // 800s - Blues
-  { code: '800', name: 'Delft Blue Pale', rgb: [192, 204, 222], brand: 'DMC', category: 'solid' },
-  { code: '801', name: 'Coffee Brown Dk', rgb: [101, 57, 25], brand: 'DMC', category: 'solid' },
-  { code: '803', name: 'Baby Blue Ult Vy Dk', rgb: [44, 89, 124], brand: 'DMC', category: 'solid' },
-  { code: '806', name: 'Peacock Blue Dark', rgb: [61, 149, 165], brand: 'DMC', category: 'solid' },
-  { code: '807', name: 'Peacock Blue', rgb: [100, 171, 186], brand: 'DMC', category: 'solid' },
-  { code: '809', name: 'Delft Blue', rgb: [148, 168, 198], brand: 'DMC', category: 'solid' },
-  { code: '813', name: 'Blue Light', rgb: [161, 194, 215], brand: 'DMC', category: 'solid' },
-  { code: '814', name: 'Garnet Dark', rgb: [123, 0, 27], brand: 'DMC', category: 'solid' },
-  { code: '815', name: 'Garnet Medium', rgb: [135, 7, 31], brand: 'DMC', category: 'solid' },
-  { code: '816', name: 'Garnet', rgb: [151, 11, 35], brand: 'DMC', category: 'solid' },
-  { code: '817', name: 'Coral Red Very Dark', rgb: [187, 5, 31], brand: 'DMC', category: 'solid' },
-  { code: '818', name: 'Baby Pink', rgb: [255, 223, 217], brand: 'DMC', category: 'solid' },
-  { code: '819', name: 'Baby Pink Light', rgb: [255, 238, 235], brand: 'DMC', category: 'solid' },
-  { code: '820', name: 'Royal Blue Very Dark', rgb: [14, 54, 92], brand: 'DMC', category: 'solid' },
-  { code: '822', name: 'Beige Gray Light', rgb: [231, 226, 211], brand: 'DMC', category: 'solid' },
-  { code: '823', name: 'Navy Blue Dark', rgb: [33, 48, 99], brand: 'DMC', category: 'solid' },
-  { code: '824', name: 'Blue Very Dark', rgb: [57, 105, 135], brand: 'DMC', category: 'solid' },
-  { code: '825', name: 'Blue Dark', rgb: [71, 129, 165], brand: 'DMC', category: 'solid' },
-  { code: '826', name: 'Blue Medium', rgb: [107, 158, 191], brand: 'DMC', category: 'solid' },
-  { code: '827', name: 'Blue Very Light', rgb: [189, 221, 237], brand: 'DMC', category: 'solid' },
-  { code: '828', name: 'Blue Ultra Vy Lt', rgb: [197, 232, 237], brand: 'DMC', category: 'solid' },
-  { code: '829', name: 'Golden Olive Vy Dk', rgb: [126, 107, 66], brand: 'DMC', category: 'solid' },
-  { code: '830', name: 'Golden Olive Dk', rgb: [141, 120, 75], brand: 'DMC', category: 'solid' },
-  { code: '831', name: 'Golden Olive Med', rgb: [170, 143, 86], brand: 'DMC', category: 'solid' },
-  { code: '832', name: 'Golden Olive', rgb: [189, 155, 81], brand: 'DMC', category: 'solid' },
-  { code: '833', name: 'Golden Olive Light', rgb: [200, 171, 108], brand: 'DMC', category: 'solid' },
-  { code: '834', name: 'Golden Olive Vy Lt', rgb: [219, 190, 127], brand: 'DMC', category: 'solid' },
-  { code: '838', name: 'Beige Brown Vy Dk', rgb: [89, 73, 55], brand: 'DMC', category: 'solid' },
-  { code: '839', name: 'Beige Brown Dark', rgb: [103, 85, 65], brand: 'DMC', category: 'solid' },
-  { code: '840', name: 'Beige Brown Med', rgb: [154, 124, 92], brand: 'DMC', category: 'solid' },
-  { code: '841', name: 'Beige Brown Light', rgb: [182, 155, 126], brand: 'DMC', category: 'solid' },
-  { code: '842', name: 'Beige Brown Vy Lt', rgb: [209, 186, 161], brand: 'DMC', category: 'solid' },
-  { code: '844', name: 'Beaver Gray Ult Dk', rgb: [72, 72, 72], brand: 'DMC', category: 'solid' },
-  { code: '869', name: 'Hazelnut Brn Vy Dk', rgb: [131, 94, 57], brand: 'DMC', category: 'solid' },
-
-  // 890s - Greens
-  { code: '890', name: 'Pistachio Grn Ult Dk', rgb: [23, 73, 35], brand: 'DMC', category: 'solid' },
-  { code: '891', name: 'Carnation Dark', rgb: [255, 87, 115], brand: 'DMC', category: 'solid' },
-  { code: '892', name: 'Carnation Medium', rgb: [255, 121, 140], brand: 'DMC', category: 'solid' },
-  { code: '893', name: 'Carnation Light', rgb: [252, 144, 162], brand: 'DMC', category: 'solid' },
-  { code: '894', name: 'Carnation Very Light', rgb: [255, 178, 187], brand: 'DMC', category: 'solid' },
-  { code: '895', name: 'Hunter Green Vy Dk', rgb: [27, 83, 0], brand: 'DMC', category: 'solid' },
-  { code: '898', name: 'Coffee Brown Vy Dk', rgb: [73, 42, 19], brand: 'DMC', category: 'solid' },
-
-  // 900s - Oranges and Yellows
-  { code: '899', name: 'Rose Medium', rgb: [242, 118, 136], brand: 'DMC', category: 'solid' },
-  { code: '900', name: 'Burnt Orange Dark', rgb: [209, 88, 7], brand: 'DMC', category: 'solid' },
-  { code: '902', name: 'Garnet Very Dark', rgb: [130, 38, 55], brand: 'DMC', category: 'solid' },
-  { code: '904', name: 'Parrot Green Vy Dk', rgb: [85, 120, 34], brand: 'DMC', category: 'solid' },
-  { code: '905', name: 'Parrot Green Dark', rgb: [98, 138, 40], brand: 'DMC', category: 'solid' },
-  { code: '906', name: 'Parrot Green Med', rgb: [127, 179, 53], brand: 'DMC', category: 'solid' },
-  { code: '907', name: 'Parrot Green Light', rgb: [199, 230, 102], brand: 'DMC', category: 'solid' },
-  { code: '909', name: 'Emerald Green Vy Dk', rgb: [21, 111, 73], brand: 'DMC', category: 'solid' },
-  { code: '910', name: 'Emerald Green Dark', rgb: [24, 126, 86], brand: 'DMC', category: 'solid' },
-  { code: '911', name: 'Emerald Green Med', rgb: [24, 144, 101], brand: 'DMC', category: 'solid' },
-  { code: '912', name: 'Emerald Green Light', rgb: [27, 157, 107], brand: 'DMC', category: 'solid' },
-  { code: '913', name: 'Nile Green Medium', rgb: [109, 171, 119], brand: 'DMC', category: 'solid' },
-  { code: '915', name: 'Plum Dark', rgb: [130, 0, 67], brand: 'DMC', category: 'solid' },
-  { code: '917', name: 'Plum Medium', rgb: [155, 19, 89], brand: 'DMC', category: 'solid' },
-  { code: '918', name: 'Red Copper Dark', rgb: [130, 52, 10], brand: 'DMC', category: 'solid' },
-  { code: '919', name: 'Red Copper', rgb: [166, 69, 16], brand: 'DMC', category: 'solid' },
-  { code: '920', name: 'Copper Medium', rgb: [172, 84, 20], brand: 'DMC', category: 'solid' },
-  { code: '921', name: 'Copper', rgb: [198, 98, 24], brand: 'DMC', category: 'solid' },
-  { code: '922', name: 'Copper Light', rgb: [226, 115, 35], brand: 'DMC', category: 'solid' },
-  { code: '924', name: 'Gray Green Vy Dark', rgb: [86, 106, 106], brand: 'DMC', category: 'solid' },
-  { code: '926', name: 'Gray Green Med', rgb: [152, 174, 174], brand: 'DMC', category: 'solid' },
-  { code: '927', name: 'Gray Green Light', rgb: [189, 203, 203], brand: 'DMC', category: 'solid' },
-  { code: '928', name: 'Gray Green Vy Lt', rgb: [221, 227, 227], brand: 'DMC', category: 'solid' },
-  { code: '930', name: 'Antique Blue Dark', rgb: [69, 92, 113], brand: 'DMC', category: 'solid' },
-  { code: '931', name: 'Antique Blue Medium', rgb: [106, 133, 158], brand: 'DMC', category: 'solid' },
-  { code: '932', name: 'Antique Blue Light', rgb: [162, 181, 198], brand: 'DMC', category: 'solid' },
-  { code: '934', name: 'Avocado Grn Black', rgb: [49, 57, 25], brand: 'DMC', category: 'solid' },
-  { code: '935', name: 'Avocado Green Dk', rgb: [66, 77, 33], brand: 'DMC', category: 'solid' },
-  { code: '936', name: 'Avocado Grn V Dk', rgb: [76, 88, 38], brand: 'DMC', category: 'solid' },
-  { code: '937', name: 'Avocado Green Med', rgb: [98, 113, 51], brand: 'DMC', category: 'solid' },
-  { code: '938', name: 'Coffee Brown Ult Dk', rgb: [54, 31, 14], brand: 'DMC', category: 'solid' },
-  { code: '939', name: 'Navy Blue Very Dark', rgb: [27, 40, 83], brand: 'DMC', category: 'solid' },
-  { code: '943', name: 'Aquamarine Med', rgb: [61, 147, 132], brand: 'DMC', category: 'solid' },
-  { code: '945', name: 'Tawny', rgb: [251, 213, 187], brand: 'DMC', category: 'solid' },
-  { code: '946', name: 'Burnt Orange Med', rgb: [235, 99, 7], brand: 'DMC', category: 'solid' },
-  { code: '947', name: 'Burnt Orange', rgb: [255, 123, 77], brand: 'DMC', category: 'solid' },
-  { code: '948', name: 'Peach Very Light', rgb: [254, 231, 218], brand: 'DMC', category: 'solid' },
-  { code: '950', name: 'Desert Sand Light', rgb: [238, 211, 196], brand: 'DMC', category: 'solid' },
-  { code: '951', name: 'Tawny Light', rgb: [255, 226, 207], brand: 'DMC', category: 'solid' },
-  { code: '954', name: 'Nile Green', rgb: [136, 186, 145], brand: 'DMC', category: 'solid' },
-  { code: '955', name: 'Nile Green Light', rgb: [162, 214, 173], brand: 'DMC', category: 'solid' },
-  { code: '956', name: 'Geranium', rgb: [255, 145, 145], brand: 'DMC', category: 'solid' },
-  { code: '957', name: 'Geranium Pale', rgb: [253, 181, 181], brand: 'DMC', category: 'solid' },
-  { code: '958', name: 'Sea Green Dark', rgb: [62, 182, 161], brand: 'DMC', category: 'solid' },
-  { code: '959', name: 'Sea Green Medium', rgb: [89, 199, 180], brand: 'DMC', category: 'solid' },
-  { code: '961', name: 'Dusty Rose Dark', rgb: [207, 115, 115], brand: 'DMC', category: 'solid' },
-  { code: '962', name: 'Dusty Rose Medium', rgb: [230, 138, 138], brand: 'DMC', category: 'solid' },
-  { code: '963', name: 'Dusty Rose Ult Vy Lt', rgb: [255, 215, 215], brand: 'DMC', category: 'solid' },
-  { code: '964', name: 'Sea Green Light', rgb: [169, 226, 216], brand: 'DMC', category: 'solid' },
-  { code: '966', name: 'Baby Green Medium', rgb: [185, 215, 192], brand: 'DMC', category: 'solid' },
-  { code: '967', name: 'Apricot Very Light', rgb: [255, 222, 213], brand: 'DMC', category: 'solid' },
-  { code: '970', name: 'Pumpkin Light', rgb: [247, 139, 19], brand: 'DMC', category: 'solid' },
-  { code: '971', name: 'Pumpkin', rgb: [246, 127, 0], brand: 'DMC', category: 'solid' },
-  { code: '972', name: 'Canary Deep', rgb: [255, 181, 21], brand: 'DMC', category: 'solid' },
-  { code: '973', name: 'Canary Bright', rgb: [255, 227, 0], brand: 'DMC', category: 'solid' },
-  { code: '975', name: 'Golden Brown Dk', rgb: [145, 79, 18], brand: 'DMC', category: 'solid' },
-  { code: '976', name: 'Golden Brown Med', rgb: [194, 129, 66], brand: 'DMC', category: 'solid' },
-  { code: '977', name: 'Golden Brown Light', rgb: [220, 156, 86], brand: 'DMC', category: 'solid' },
-  { code: '986', name: 'Forest Green Vy Dk', rgb: [64, 82, 48], brand: 'DMC', category: 'solid' },
-  { code: '987', name: 'Forest Green Dark', rgb: [88, 113, 65], brand: 'DMC', category: 'solid' },
-  { code: '988', name: 'Forest Green Med', rgb: [115, 139, 91], brand: 'DMC', category: 'solid' },
-  { code: '989', name: 'Forest Green', rgb: [141, 166, 117], brand: 'DMC', category: 'solid' },
-  { code: '991', name: 'Aquamarine Dark', rgb: [71, 123, 110], brand: 'DMC', category: 'solid' },
-  { code: '992', name: 'Aquamarine Light', rgb: [111, 174, 159], brand: 'DMC', category: 'solid' },
-  { code: '993', name: 'Aquamarine Vy Lt', rgb: [144, 192, 180], brand: 'DMC', category: 'solid' },
-  { code: '995', name: 'Electric Blue Dark', rgb: [38, 150, 182], brand: 'DMC', category: 'solid' },
-  { code: '996', name: 'Electric Blue Med', rgb: [48, 194, 236], brand: 'DMC', category: 'solid' },
-
-  // 3000s
-  { code: '3011', name: 'Khaki Green Dark', rgb: [137, 138, 88], brand: 'DMC', category: 'solid' },
-  { code: '3012', name: 'Khaki Green Med', rgb: [166, 167, 93], brand: 'DMC', category: 'solid' },
-  { code: '3013', name: 'Khaki Green Light', rgb: [185, 185, 130], brand: 'DMC', category: 'solid' },
-  { code: '3021', name: 'Brown Gray Vy Dk', rgb: [79, 75, 65], brand: 'DMC', category: 'solid' },
-  { code: '3022', name: 'Brown Gray Med', rgb: [142, 144, 120], brand: 'DMC', category: 'solid' },
-  { code: '3023', name: 'Brown Gray Light', rgb: [177, 170, 151], brand: 'DMC', category: 'solid' },
-  { code: '3024', name: 'Brown Gray Vy Lt', rgb: [235, 234, 231], brand: 'DMC', category: 'solid' },
-  { code: '3031', name: 'Mocha Brown Vy Dk', rgb: [75, 60, 42], brand: 'DMC', category: 'solid' },
-  { code: '3032', name: 'Mocha Brown Med', rgb: [179, 159, 139], brand: 'DMC', category: 'solid' },
-  { code: '3033', name: 'Mocha Brown Vy Lt', rgb: [227, 216, 204], brand: 'DMC', category: 'solid' },
-  { code: '3041', name: 'Antique Violet Med', rgb: [149, 111, 124], brand: 'DMC', category: 'solid' },
-  { code: '3042', name: 'Antique Violet Light', rgb: [183, 157, 167], brand: 'DMC', category: 'solid' },
-  { code: '3045', name: 'Yellow Beige Dk', rgb: [188, 150, 106], brand: 'DMC', category: 'solid' },
-  { code: '3046', name: 'Yellow Beige Med', rgb: [216, 188, 154], brand: 'DMC', category: 'solid' },
-  { code: '3047', name: 'Yellow Beige Light', rgb: [231, 214, 193], brand: 'DMC', category: 'solid' },
-  { code: '3051', name: 'Green Gray Dark', rgb: [95, 102, 72], brand: 'DMC', category: 'solid' },
-  { code: '3052', name: 'Green Gray Med', rgb: [136, 146, 104], brand: 'DMC', category: 'solid' },
-  { code: '3053', name: 'Green Gray', rgb: [156, 164, 130], brand: 'DMC', category: 'solid' },
-  { code: '3064', name: 'Desert Sand', rgb: [196, 142, 112], brand: 'DMC', category: 'solid' },
-  { code: '3072', name: 'Beaver Gray Vy Lt', rgb: [230, 232, 232], brand: 'DMC', category: 'solid' },
-  { code: '3078', name: 'Golden Yellow Vy Lt', rgb: [253, 249, 205], brand: 'DMC', category: 'solid' },
-
-  // 3300s
-  { code: '3325', name: 'Baby Blue Light', rgb: [184, 210, 230], brand: 'DMC', category: 'solid' },
-  { code: '3326', name: 'Rose Light', rgb: [251, 173, 180], brand: 'DMC', category: 'solid' },
-  { code: '3328', name: 'Salmon Dark', rgb: [227, 109, 109], brand: 'DMC', category: 'solid' },
-  { code: '3340', name: 'Apricot Medium', rgb: [255, 131, 111], brand: 'DMC', category: 'solid' },
-  { code: '3341', name: 'Apricot', rgb: [252, 171, 152], brand: 'DMC', category: 'solid' },
-  { code: '3345', name: 'Hunter Green Dk', rgb: [27, 89, 21], brand: 'DMC', category: 'solid' },
-  { code: '3346', name: 'Hunter Green', rgb: [64, 106, 58], brand: 'DMC', category: 'solid' },
-  { code: '3347', name: 'Yellow Green Med', rgb: [113, 147, 92], brand: 'DMC', category: 'solid' },
-  { code: '3348', name: 'Yellow Green Light', rgb: [204, 217, 177], brand: 'DMC', category: 'solid' },
-  { code: '3350', name: 'Dusty Rose Ultra Dk', rgb: [188, 67, 101], brand: 'DMC', category: 'solid' },
-  { code: '3354', name: 'Dusty Rose Light', rgb: [228, 166, 172], brand: 'DMC', category: 'solid' },
-  { code: '3362', name: 'Pine Green Dark', rgb: [94, 107, 71], brand: 'DMC', category: 'solid' },
-  { code: '3363', name: 'Pine Green Med', rgb: [114, 130, 86], brand: 'DMC', category: 'solid' },
-  { code: '3364', name: 'Pine Green', rgb: [131, 151, 95], brand: 'DMC', category: 'solid' },
-
-  // 3600s
-  { code: '3607', name: 'Plum Light', rgb: [197, 73, 137], brand: 'DMC', category: 'solid' },
-  { code: '3608', name: 'Plum Very Light', rgb: [234, 156, 196], brand: 'DMC', category: 'solid' },
-  { code: '3609', name: 'Plum Ultra Light', rgb: [244, 174, 213], brand: 'DMC', category: 'solid' },
-  { code: '3685', name: 'Mauve Very Dark', rgb: [136, 21, 49], brand: 'DMC', category: 'solid' },
-  { code: '3687', name: 'Mauve', rgb: [201, 107, 112], brand: 'DMC', category: 'solid' },
-  { code: '3688', name: 'Mauve Medium', rgb: [231, 169, 172], brand: 'DMC', category: 'solid' },
-  { code: '3689', name: 'Mauve Light', rgb: [251, 191, 194], brand: 'DMC', category: 'solid' },
-
-  // 3700s
-  { code: '3705', name: 'Melon Dark', rgb: [255, 121, 146], brand: 'DMC', category: 'solid' },
-  { code: '3706', name: 'Melon Medium', rgb: [255, 173, 188], brand: 'DMC', category: 'solid' },
-  { code: '3708', name: 'Melon Light', rgb: [255, 203, 213], brand: 'DMC', category: 'solid' },
-  { code: '3712', name: 'Salmon Medium', rgb: [241, 135, 135], brand: 'DMC', category: 'solid' },
-  { code: '3713', name: 'Salmon Very Light', rgb: [255, 226, 226], brand: 'DMC', category: 'solid' },
-  { code: '3716', name: 'Dusty Rose Med Vy Lt', rgb: [255, 189, 189], brand: 'DMC', category: 'solid' },
-  { code: '3721', name: 'Shell Pink Dark', rgb: [161, 75, 81], brand: 'DMC', category: 'solid' },
-  { code: '3722', name: 'Shell Pink Med', rgb: [188, 108, 100], brand: 'DMC', category: 'solid' },
-  { code: '3726', name: 'Antique Mauve Dark', rgb: [155, 91, 102], brand: 'DMC', category: 'solid' },
-  { code: '3727', name: 'Antique Mauve Light', rgb: [219, 169, 178], brand: 'DMC', category: 'solid' },
-  { code: '3731', name: 'Dusty Rose Very Dark', rgb: [218, 103, 131], brand: 'DMC', category: 'solid' },
-  { code: '3733', name: 'Dusty Rose', rgb: [232, 135, 155], brand: 'DMC', category: 'solid' },
-  { code: '3740', name: 'Antique Violet Dark', rgb: [120, 87, 98], brand: 'DMC', category: 'solid' },
-  { code: '3743', name: 'Antique Violet Vy Lt', rgb: [215, 203, 211], brand: 'DMC', category: 'solid' },
-  { code: '3746', name: 'Blue Violet Dark', rgb: [119, 107, 152], brand: 'DMC', category: 'solid' },
-  { code: '3747', name: 'Blue Violet Vy Lt', rgb: [211, 215, 237], brand: 'DMC', category: 'solid' },
-  { code: '3750', name: 'Antique Blue Vy Dk', rgb: [56, 76, 94], brand: 'DMC', category: 'solid' },
-  { code: '3752', name: 'Antique Blue Vy Lt', rgb: [199, 209, 219], brand: 'DMC', category: 'solid' },
-  { code: '3753', name: 'Antique Blue Ult Vy Lt', rgb: [219, 226, 233], brand: 'DMC', category: 'solid' },
-  { code: '3755', name: 'Baby Blue', rgb: [147, 180, 206], brand: 'DMC', category: 'solid' },
-  { code: '3756', name: 'Baby Blue Ult Vy Lt', rgb: [238, 252, 252], brand: 'DMC', category: 'solid' },
-  { code: '3760', name: 'Wedgwood Med', rgb: [62, 133, 162], brand: 'DMC', category: 'solid' },
-  { code: '3761', name: 'Sky Blue Light', rgb: [172, 216, 226], brand: 'DMC', category: 'solid' },
-  { code: '3765', name: 'Peacock Blue Vy Dk', rgb: [52, 127, 140], brand: 'DMC', category: 'solid' },
-  { code: '3766', name: 'Peacock Blue Light', rgb: [153, 207, 217], brand: 'DMC', category: 'solid' },
-  { code: '3768', name: 'Gray Green Dark', rgb: [101, 127, 127], brand: 'DMC', category: 'solid' },
-  { code: '3770', name: 'Tawny Vy Light', rgb: [255, 238, 227], brand: 'DMC', category: 'solid' },
-  { code: '3771', name: 'Terra Cotta Ult Vy Lt', rgb: [244, 187, 169], brand: 'DMC', category: 'solid' },
-  { code: '3772', name: 'Desert Sand Vy Dk', rgb: [160, 108, 80], brand: 'DMC', category: 'solid' },
-  { code: '3773', name: 'Desert Sand Med', rgb: [182, 117, 82], brand: 'DMC', category: 'solid' },
-  { code: '3774', name: 'Desert Sand Vy Lt', rgb: [243, 225, 215], brand: 'DMC', category: 'solid' },
-  { code: '3776', name: 'Mahogany Light', rgb: [207, 121, 57], brand: 'DMC', category: 'solid' },
-  { code: '3777', name: 'Terra Cotta Vy Dk', rgb: [134, 48, 34], brand: 'DMC', category: 'solid' },
-  { code: '3778', name: 'Terra Cotta Light', rgb: [217, 137, 120], brand: 'DMC', category: 'solid' },
-  { code: '3779', name: 'Ter Cotta Ult Vy Lt', rgb: [248, 202, 200], brand: 'DMC', category: 'solid' },
-  { code: '3781', name: 'Mocha Brown Dark', rgb: [107, 87, 67], brand: 'DMC', category: 'solid' },
-  { code: '3782', name: 'Mocha Brown Light', rgb: [210, 188, 166], brand: 'DMC', category: 'solid' },
-  { code: '3787', name: 'Brown Gray Dark', rgb: [98, 93, 80], brand: 'DMC', category: 'solid' },
-  { code: '3790', name: 'Beige Gray Ult Dk', rgb: [127, 106, 85], brand: 'DMC', category: 'solid' },
-  { code: '3799', name: 'Pewter Gray Vy Dk', rgb: [66, 66, 66], brand: 'DMC', category: 'solid' },
-
-  // 3800s
-  { code: '3801', name: 'Christmas Red Lt', rgb: [231, 73, 103], brand: 'DMC', category: 'solid' },
-  { code: '3802', name: 'Antique Mauve Vy Dk', rgb: [113, 65, 73], brand: 'DMC', category: 'solid' },
-  { code: '3803', name: 'Mauve Dark', rgb: [171, 51, 87], brand: 'DMC', category: 'solid' },
-  { code: '3804', name: 'Cyclamen Pink Dark', rgb: [224, 40, 118], brand: 'DMC', category: 'solid' },
-  { code: '3805', name: 'Cyclamen Pink', rgb: [243, 71, 139], brand: 'DMC', category: 'solid' },
-  { code: '3806', name: 'Cyclamen Pink Light', rgb: [255, 140, 174], brand: 'DMC', category: 'solid' },
-  { code: '3807', name: 'Cornflower Blue', rgb: [96, 103, 140], brand: 'DMC', category: 'solid' },
-  { code: '3808', name: 'Turquoise Ult Vy Dk', rgb: [54, 105, 112], brand: 'DMC', category: 'solid' },
-  { code: '3809', name: 'Turquoise Vy Dark', rgb: [63, 124, 133], brand: 'DMC', category: 'solid' },
-  { code: '3810', name: 'Turquoise Dark', rgb: [72, 142, 154], brand: 'DMC', category: 'solid' },
-  { code: '3811', name: 'Turquoise Very Light', rgb: [188, 227, 230], brand: 'DMC', category: 'solid' },
-  { code: '3812', name: 'Sea Green Vy Dk', rgb: [47, 140, 132], brand: 'DMC', category: 'solid' },
-  { code: '3813', name: 'Blue Green Lt', rgb: [178, 212, 189], brand: 'DMC', category: 'solid' },
-  { code: '3814', name: 'Aquamarine', rgb: [80, 139, 125], brand: 'DMC', category: 'solid' },
-  { code: '3815', name: 'Celadon Green Dk', rgb: [71, 119, 89], brand: 'DMC', category: 'solid' },
-  { code: '3816', name: 'Celadon Green', rgb: [101, 165, 125], brand: 'DMC', category: 'solid' },
-  { code: '3817', name: 'Celadon Green Lt', rgb: [153, 195, 170], brand: 'DMC', category: 'solid' },
-  { code: '3818', name: 'Emerald Grn Ult V Dk', rgb: [17, 90, 59], brand: 'DMC', category: 'solid' },
-  { code: '3819', name: 'Moss Green Lt', rgb: [224, 232, 104], brand: 'DMC', category: 'solid' },
-  { code: '3820', name: 'Straw Dark', rgb: [223, 182, 95], brand: 'DMC', category: 'solid' },
-  { code: '3821', name: 'Straw', rgb: [243, 206, 117], brand: 'DMC', category: 'solid' },
-  { code: '3822', name: 'Straw Light', rgb: [246, 220, 152], brand: 'DMC', category: 'solid' },
-  { code: '3823', name: 'Yellow Ultra Pale', rgb: [255, 253, 227], brand: 'DMC', category: 'solid' },
-  { code: '3824', name: 'Apricot Light', rgb: [254, 205, 194], brand: 'DMC', category: 'solid' },
-  { code: '3825', name: 'Pumpkin Pale', rgb: [253, 189, 150], brand: 'DMC', category: 'solid' },
-  { code: '3826', name: 'Golden Brown', rgb: [173, 114, 57], brand: 'DMC', category: 'solid' },
-  { code: '3827', name: 'Golden Brown Pale', rgb: [247, 187, 119], brand: 'DMC', category: 'solid' },
-  { code: '3828', name: 'Hazelnut Brown', rgb: [183, 139, 97], brand: 'DMC', category: 'solid' },
-  { code: '3829', name: 'Old Gold Very Dark', rgb: [169, 130, 4], brand: 'DMC', category: 'solid' },
-  { code: '3830', name: 'Terra Cotta', rgb: [185, 85, 68], brand: 'DMC', category: 'solid' },
-  { code: '3831', name: 'Raspberry Dark', rgb: [179, 47, 72], brand: 'DMC', category: 'solid' },
-  { code: '3832', name: 'Raspberry Medium', rgb: [219, 85, 110], brand: 'DMC', category: 'solid' },
-  { code: '3833', name: 'Raspberry Light', rgb: [234, 134, 153], brand: 'DMC', category: 'solid' },
-  { code: '3834', name: 'Grape Dark', rgb: [114, 55, 93], brand: 'DMC', category: 'solid' },
-  { code: '3835', name: 'Grape Medium', rgb: [148, 96, 131], brand: 'DMC', category: 'solid' },
-  { code: '3836', name: 'Grape Light', rgb: [186, 145, 170], brand: 'DMC', category: 'solid' },
-  { code: '3837', name: 'Lavender Ultra Dark', rgb: [108, 58, 110], brand: 'DMC', category: 'solid' },
-  { code: '3838', name: 'Lavender Blue Dark', rgb: [92, 114, 148], brand: 'DMC', category: 'solid' },
-  { code: '3839', name: 'Lavender Blue Med', rgb: [123, 142, 171], brand: 'DMC', category: 'solid' },
-  { code: '3840', name: 'Lavender Blue Light', rgb: [176, 192, 218], brand: 'DMC', category: 'solid' },
-  { code: '3841', name: 'Baby Blue Pale', rgb: [205, 223, 237], brand: 'DMC', category: 'solid' },
-  { code: '3842', name: 'Wedgwood Vry Dk', rgb: [50, 102, 124], brand: 'DMC', category: 'solid' },
-  { code: '3843', name: 'Electric Blue', rgb: [20, 170, 208], brand: 'DMC', category: 'solid' },
-  { code: '3844', name: 'Turquoise Bright Dark', rgb: [18, 174, 186], brand: 'DMC', category: 'solid' },
-  { code: '3845', name: 'Turquoise Bright Med', rgb: [4, 196, 202], brand: 'DMC', category: 'solid' },
-  { code: '3846', name: 'Turquoise Bright Lt', rgb: [6, 227, 230], brand: 'DMC', category: 'solid' },
-  { code: '3847', name: 'Teal Green Dark', rgb: [52, 125, 117], brand: 'DMC', category: 'solid' },
-  { code: '3848', name: 'Teal Green Med', rgb: [85, 147, 146], brand: 'DMC', category: 'solid' },
-  { code: '3849', name: 'Teal Green Light', rgb: [82, 179, 164], brand: 'DMC', category: 'solid' },
-  { code: '3850', name: 'Bright Green Dk', rgb: [55, 132, 119], brand: 'DMC', category: 'solid' },
-  { code: '3851', name: 'Bright Green Lt', rgb: [73, 179, 161], brand: 'DMC', category: 'solid' },
-  { code: '3852', name: 'Straw Very Dark', rgb: [205, 157, 55], brand: 'DMC', category: 'solid' },
-  { code: '3853', name: 'Autumn Gold Dk', rgb: [242, 151, 70], brand: 'DMC', category: 'solid' },
-  { code: '3854', name: 'Autumn Gold Med', rgb: [242, 175, 104], brand: 'DMC', category: 'solid' },
-  { code: '3855', name: 'Autumn Gold Lt', rgb: [250, 211, 150], brand: 'DMC', category: 'solid' },
-  { code: '3856', name: 'Mahogany Ult Vy Lt', rgb: [255, 211, 181], brand: 'DMC', category: 'solid' },
-  { code: '3857', name: 'Rosewood Dark', rgb: [104, 37, 26], brand: 'DMC', category: 'solid' },
-  { code: '3858', name: 'Rosewood Med', rgb: [150, 74, 63], brand: 'DMC', category: 'solid' },
-  { code: '3859', name: 'Rosewood Light', rgb: [186, 139, 124], brand: 'DMC', category: 'solid' },
-  { code: '3860', name: 'Cocoa', rgb: [125, 93, 87], brand: 'DMC', category: 'solid' },
-  { code: '3861', name: 'Cocoa Light', rgb: [166, 136, 129], brand: 'DMC', category: 'solid' },
-  { code: '3862', name: 'Mocha Beige Dark', rgb: [138, 110, 78], brand: 'DMC', category: 'solid' },
-  { code: '3863', name: 'Mocha Beige Med', rgb: [164, 131, 92], brand: 'DMC', category: 'solid' },
-  { code: '3864', name: 'Mocha Beige Light', rgb: [203, 182, 156], brand: 'DMC', category: 'solid' },
-  { code: '3865', name: 'Winter White', rgb: [249, 247, 241], brand: 'DMC', category: 'solid' },
-  { code: '3866', name: 'Mocha Brn Ult Vy Lt', rgb: [250, 246, 240], brand: 'DMC', category: 'solid' },
-];
-
-// ============================================
-// COLOR VARIATIONS (Variegated)
-// ============================================
-const variegatedColors: ThreadColor[] = [
-  { code: '48', name: 'Variegated Baby Pink', rgb: [255, 218, 233], brand: 'DMC', category: 'variegated' },
-  { code: '51', name: 'Variegated Burnt Orange', rgb: [235, 139, 102], brand: 'DMC', category: 'variegated' },
-  { code: '52', name: 'Variegated Violet', rgb: [149, 103, 170], brand: 'DMC', category: 'variegated' },
-  { code: '53', name: 'Variegated Steel Gray', rgb: [148, 162, 172], brand: 'DMC', category: 'variegated' },
-  { code: '57', name: 'Variegated Fuchsia', rgb: [200, 93, 143], brand: 'DMC', category: 'variegated' },
-  { code: '61', name: 'Variegated Pale Blue', rgb: [165, 200, 218], brand: 'DMC', category: 'variegated' },
-  { code: '62', name: 'Variegated Light Brown', rgb: [178, 149, 119], brand: 'DMC', category: 'variegated' },
-  { code: '67', name: 'Variegated Baby Yellow', rgb: [255, 246, 191], brand: 'DMC', category: 'variegated' },
-  { code: '69', name: 'Variegated Terra Cotta', rgb: [191, 117, 98], brand: 'DMC', category: 'variegated' },
-  { code: '75', name: 'Variegated Baby Pink', rgb: [239, 183, 200], brand: 'DMC', category: 'variegated' },
-  { code: '90', name: 'Variegated Yellow', rgb: [252, 219, 129], brand: 'DMC', category: 'variegated' },
-  { code: '91', name: 'Variegated Seafoam', rgb: [146, 205, 197], brand: 'DMC', category: 'variegated' },
-  { code: '92', name: 'Variegated Avocado', rgb: [161, 181, 130], brand: 'DMC', category: 'variegated' },
-  { code: '93', name: 'Variegated Cornflower Blue', rgb: [126, 153, 191], brand: 'DMC', category: 'variegated' },
-  { code: '94', name: 'Variegated Khaki', rgb: [162, 158, 122], brand: 'DMC', category: 'variegated' },
-  { code: '95', name: 'Variegated Fuchsia', rgb: [202, 123, 156], brand: 'DMC', category: 'variegated' },
-  { code: '99', name: 'Variegated Mauve', rgb: [177, 130, 148], brand: 'DMC', category: 'variegated' },
-  { code: '101', name: 'Variegated Blueberry', rgb: [94, 128, 168], brand: 'DMC', category: 'variegated' },
-  { code: '102', name: 'Variegated Grape', rgb: [129, 103, 139], brand: 'DMC', category: 'variegated' },
-  { code: '103', name: 'Variegated Plum', rgb: [155, 98, 130], brand: 'DMC', category: 'variegated' },
-  { code: '104', name: 'Variegated Rose', rgb: [194, 131, 142], brand: 'DMC', category: 'variegated' },
-  { code: '105', name: 'Variegated Tan Brown', rgb: [172, 143, 109], brand: 'DMC', category: 'variegated' },
-  { code: '106', name: 'Variegated Coral', rgb: [232, 159, 149], brand: 'DMC', category: 'variegated' },
-  { code: '107', name: 'Variegated Carnation', rgb: [234, 145, 162], brand: 'DMC', category: 'variegated' },
-  { code: '108', name: 'Variegated Lavender', rgb: [180, 159, 191], brand: 'DMC', category: 'variegated' },
-  { code: '109', name: 'Variegated Royal Blue', rgb: [79, 107, 163], brand: 'DMC', category: 'variegated' },
-  { code: '110', name: 'Variegated Black', rgb: [74, 74, 74], brand: 'DMC', category: 'variegated' },
-  { code: '111', name: 'Variegated Mustard', rgb: [201, 175, 107], brand: 'DMC', category: 'variegated' },
-  { code: '112', name: 'Variegated Tangerine', rgb: [244, 170, 108], brand: 'DMC', category: 'variegated' },
-  { code: '113', name: 'Variegated Purple', rgb: [145, 109, 152], brand: 'DMC', category: 'variegated' },
-  { code: '114', name: 'Variegated Lemon', rgb: [253, 233, 153], brand: 'DMC', category: 'variegated' },
-  { code: '115', name: 'Variegated Garnet', rgb: [161, 72, 86], brand: 'DMC', category: 'variegated' },
-  { code: '116', name: 'Variegated Mocha', rgb: [151, 123, 95], brand: 'DMC', category: 'variegated' },
-  { code: '117', name: 'Variegated Caribbean', rgb: [97, 165, 173], brand: 'DMC', category: 'variegated' },
-  { code: '118', name: 'Variegated Delft Blue', rgb: [121, 148, 184], brand: 'DMC', category: 'variegated' },
-  { code: '119', name: 'Variegated Wedgwood', rgb: [91, 138, 162], brand: 'DMC', category: 'variegated' },
-  { code: '120', name: 'Variegated Blue', rgb: [116, 161, 195], brand: 'DMC', category: 'variegated' },
-  { code: '121', name: 'Variegated Teal', rgb: [83, 148, 145], brand: 'DMC', category: 'variegated' },
-  { code: '122', name: 'Variegated Jade', rgb: [100, 164, 137], brand: 'DMC', category: 'variegated' },
-  { code: '123', name: 'Variegated Celery', rgb: [160, 188, 147], brand: 'DMC', category: 'variegated' },
-  { code: '124', name: 'Variegated Spruce', rgb: [95, 133, 114], brand: 'DMC', category: 'variegated' },
-  { code: '125', name: 'Variegated Seafoam', rgb: [124, 176, 169], brand: 'DMC', category: 'variegated' },
-  { code: '126', name: 'Variegated Coral Red', rgb: [212, 107, 107], brand: 'DMC', category: 'variegated' },
-  { code: '127', name: 'Variegated Antique Mauve', rgb: [173, 122, 133], brand: 'DMC', category: 'variegated' },
-];
-
-// ============================================
-// LIGHT EFFECTS (Metallic/Glow)
-// ============================================
-const lightEffectsColors: ThreadColor[] = [
-  { code: 'E130', name: 'Light Effects Pumpkin', rgb: [245, 139, 55], brand: 'DMC', category: 'light-effects' },
-  { code: 'E135', name: 'Light Effects Golden Yellow', rgb: [255, 210, 65], brand: 'DMC', category: 'light-effects' },
-  { code: 'E168', name: 'Light Effects Silver', rgb: [200, 200, 200], brand: 'DMC', category: 'light-effects' },
-  { code: 'E211', name: 'Light Effects Lilac', rgb: [205, 180, 219], brand: 'DMC', category: 'light-effects' },
-  { code: 'E301', name: 'Light Effects Copper', rgb: [200, 120, 60], brand: 'DMC', category: 'light-effects' },
-  { code: 'E310', name: 'Light Effects Black', rgb: [45, 45, 45], brand: 'DMC', category: 'light-effects' },
-  { code: 'E316', name: 'Light Effects Pink Garnet', rgb: [200, 100, 130], brand: 'DMC', category: 'light-effects' },
-  { code: 'E317', name: 'Light Effects Pewter', rgb: [140, 140, 145], brand: 'DMC', category: 'light-effects' },
-  { code: 'E321', name: 'Light Effects Red Ruby', rgb: [200, 50, 70], brand: 'DMC', category: 'light-effects' },
-  { code: 'E334', name: 'Light Effects Blue Topaz', rgb: [100, 160, 200], brand: 'DMC', category: 'light-effects' },
-  { code: 'E436', name: 'Light Effects Tan', rgb: [210, 165, 115], brand: 'DMC', category: 'light-effects' },
-  { code: 'E677', name: 'Light Effects White Gold', rgb: [245, 235, 200], brand: 'DMC', category: 'light-effects' },
-  { code: 'E699', name: 'Light Effects Green Emerald', rgb: [45, 140, 80], brand: 'DMC', category: 'light-effects' },
-  { code: 'E703', name: 'Light Effects Chartreuse', rgb: [140, 195, 80], brand: 'DMC', category: 'light-effects' },
-  { code: 'E718', name: 'Light Effects Magenta', rgb: [170, 60, 120], brand: 'DMC', category: 'light-effects' },
-  { code: 'E746', name: 'Light Effects Off White', rgb: [250, 245, 230], brand: 'DMC', category: 'light-effects' },
-  { code: 'E747', name: 'Light Effects Baby Blue', rgb: [200, 230, 245], brand: 'DMC', category: 'light-effects' },
-  { code: 'E815', name: 'Light Effects Garnet', rgb: [150, 35, 55], brand: 'DMC', category: 'light-effects' },
-  { code: 'E818', name: 'Light Effects Baby Pink', rgb: [255, 220, 220], brand: 'DMC', category: 'light-effects' },
-  { code: 'E825', name: 'Light Effects Blue', rgb: [75, 140, 175], brand: 'DMC', category: 'light-effects' },
-  { code: 'E898', name: 'Light Effects Coffee Brown', rgb: [95, 60, 40], brand: 'DMC', category: 'light-effects' },
-  { code: 'E940', name: 'Light Effects Glow in Dark', rgb: [230, 255, 230], brand: 'DMC', category: 'light-effects' },
-  { code: 'E966', name: 'Light Effects Baby Green', rgb: [180, 220, 190], brand: 'DMC', category: 'light-effects' },
-  { code: 'E980', name: 'Light Effects Neon Yellow', rgb: [255, 255, 100], brand: 'DMC', category: 'light-effects' },
-  { code: 'E990', name: 'Light Effects Neon Green', rgb: [100, 255, 120], brand: 'DMC', category: 'light-effects' },
-  { code: 'E3821', name: 'Light Effects Straw Gold', rgb: [240, 210, 130], brand: 'DMC', category: 'light-effects' },
-  { code: 'E3837', name: 'Light Effects Lavender', rgb: [140, 100, 160], brand: 'DMC', category: 'light-effects' },
-  { code: 'E3843', name: 'Light Effects Turquoise', rgb: [60, 180, 210], brand: 'DMC', category: 'light-effects' },
-  { code: 'E3849', name: 'Light Effects Teal', rgb: [90, 180, 165], brand: 'DMC', category: 'light-effects' },
-  { code: 'E3852', name: 'Light Effects Antique Gold', rgb: [195, 155, 65], brand: 'DMC', category: 'light-effects' },
-  { code: 'E5200', name: 'Light Effects Pure White', rgb: [255, 255, 255], brand: 'DMC', category: 'light-effects' },
-];
-
-// ============================================
-// SATIN FLOSS
-// ============================================
-const satinColors: ThreadColor[] = [
-  { code: 'S211', name: 'Satin Lavender Light', rgb: [220, 200, 230], brand: 'DMC', category: 'satin' },
-  { code: 'S310', name: 'Satin Black', rgb: [30, 30, 30], brand: 'DMC', category: 'satin' },
-  { code: 'S321', name: 'Satin Red', rgb: [200, 50, 65], brand: 'DMC', category: 'satin' },
-  { code: 'S326', name: 'Satin Rose Very Dark', rgb: [175, 60, 75], brand: 'DMC', category: 'satin' },
-  { code: 'S336', name: 'Satin Navy Blue', rgb: [45, 65, 115], brand: 'DMC', category: 'satin' },
-  { code: 'S355', name: 'Satin Terra Cotta Dark', rgb: [155, 75, 60], brand: 'DMC', category: 'satin' },
-  { code: 'S415', name: 'Satin Pearl Gray', rgb: [205, 205, 210], brand: 'DMC', category: 'satin' },
-  { code: 'S434', name: 'Satin Brown Light', rgb: [160, 105, 65], brand: 'DMC', category: 'satin' },
-  { code: 'S436', name: 'Satin Tan', rgb: [205, 155, 95], brand: 'DMC', category: 'satin' },
-  { code: 'S469', name: 'Satin Avocado Green', rgb: [125, 145, 75], brand: 'DMC', category: 'satin' },
-  { code: 'S471', name: 'Satin Avocado Very Light', rgb: [180, 200, 135], brand: 'DMC', category: 'satin' },
-  { code: 'S498', name: 'Satin Christmas Red', rgb: [175, 35, 55], brand: 'DMC', category: 'satin' },
-  { code: 'S501', name: 'Satin Blue Green Dark', rgb: [65, 120, 95], brand: 'DMC', category: 'satin' },
-  { code: 'S519', name: 'Satin Sky Blue', rgb: [135, 185, 210], brand: 'DMC', category: 'satin' },
-  { code: 'S550', name: 'Satin Violet Very Dark', rgb: [100, 40, 90], brand: 'DMC', category: 'satin' },
-  { code: 'S602', name: 'Satin Cranberry Medium', rgb: [220, 80, 125], brand: 'DMC', category: 'satin' },
-  { code: 'S666', name: 'Satin Christmas Red Bright', rgb: [225, 40, 75], brand: 'DMC', category: 'satin' },
-  { code: 'S699', name: 'Satin Christmas Green', rgb: [25, 110, 45], brand: 'DMC', category: 'satin' },
-  { code: 'S700', name: 'Satin Christmas Green Bright', rgb: [35, 125, 50], brand: 'DMC', category: 'satin' },
-  { code: 'S725', name: 'Satin Topaz', rgb: [255, 205, 75], brand: 'DMC', category: 'satin' },
-  { code: 'S738', name: 'Satin Tan Very Light', rgb: [235, 210, 170], brand: 'DMC', category: 'satin' },
-  { code: 'S741', name: 'Satin Tangerine Medium', rgb: [255, 175, 65], brand: 'DMC', category: 'satin' },
-  { code: 'S762', name: 'Satin Pearl Gray Very Light', rgb: [235, 235, 235], brand: 'DMC', category: 'satin' },
-  { code: 'S775', name: 'Satin Baby Blue Very Light', rgb: [215, 235, 245], brand: 'DMC', category: 'satin' },
-  { code: 'S798', name: 'Satin Delft Blue Dark', rgb: [85, 120, 160], brand: 'DMC', category: 'satin' },
-  { code: 'S799', name: 'Satin Delft Blue Medium', rgb: [125, 155, 195], brand: 'DMC', category: 'satin' },
-  { code: 'S800', name: 'Satin Delft Blue Pale', rgb: [195, 210, 230], brand: 'DMC', category: 'satin' },
-  { code: 'S806', name: 'Satin Peacock Blue Dark', rgb: [75, 160, 175], brand: 'DMC', category: 'satin' },
-  { code: 'S809', name: 'Satin Delft Blue', rgb: [160, 180, 210], brand: 'DMC', category: 'satin' },
-  { code: 'S817', name: 'Satin Coral Red Very Dark', rgb: [190, 25, 50], brand: 'DMC', category: 'satin' },
-  { code: 'S818', name: 'Satin Baby Pink', rgb: [255, 225, 220], brand: 'DMC', category: 'satin' },
-  { code: 'S838', name: 'Satin Beige Brown Very Dark', rgb: [100, 85, 70], brand: 'DMC', category: 'satin' },
-  { code: 'S869', name: 'Satin Hazelnut Brown Very Dark', rgb: [145, 110, 75], brand: 'DMC', category: 'satin' },
-  { code: 'S891', name: 'Satin Carnation Dark', rgb: [255, 100, 130], brand: 'DMC', category: 'satin' },
-  { code: 'S892', name: 'Satin Carnation Medium', rgb: [255, 135, 155], brand: 'DMC', category: 'satin' },
-  { code: 'S898', name: 'Satin Coffee Brown Very Dark', rgb: [90, 60, 40], brand: 'DMC', category: 'satin' },
-  { code: 'S899', name: 'Satin Rose Medium', rgb: [245, 135, 155], brand: 'DMC', category: 'satin' },
-  { code: 'S911', name: 'Satin Emerald Green Medium', rgb: [45, 160, 120], brand: 'DMC', category: 'satin' },
-  { code: 'S912', name: 'Satin Emerald Green Light', rgb: [55, 175, 130], brand: 'DMC', category: 'satin' },
-  { code: 'S943', name: 'Satin Aquamarine Medium', rgb: [80, 165, 155], brand: 'DMC', category: 'satin' },
-  { code: 'S945', name: 'Satin Tawny', rgb: [250, 220, 200], brand: 'DMC', category: 'satin' },
-  { code: 'S963', name: 'Satin Dusty Rose Ultra Very Lt', rgb: [255, 220, 220], brand: 'DMC', category: 'satin' },
-  { code: 'S964', name: 'Satin Sea Green Light', rgb: [180, 230, 225], brand: 'DMC', category: 'satin' },
-  { code: 'S966', name: 'Satin Baby Green Medium', rgb: [195, 225, 205], brand: 'DMC', category: 'satin' },
-  { code: 'S973', name: 'Satin Canary Bright', rgb: [255, 235, 55], brand: 'DMC', category: 'satin' },
-  { code: 'S3607', name: 'Satin Plum Light', rgb: [210, 95, 160], brand: 'DMC', category: 'satin' },
-  { code: 'S3608', name: 'Satin Plum Very Light', rgb: [240, 175, 215], brand: 'DMC', category: 'satin' },
-  { code: 'S3609', name: 'Satin Plum Ultra Light', rgb: [250, 195, 230], brand: 'DMC', category: 'satin' },
-  { code: 'S3756', name: 'Satin Baby Blue Ultra Very Lt', rgb: [245, 255, 255], brand: 'DMC', category: 'satin' },
-  { code: 'S3821', name: 'Satin Straw', rgb: [250, 220, 140], brand: 'DMC', category: 'satin' },
-  { code: 'S3823', name: 'Satin Yellow Ultra Pale', rgb: [255, 255, 235], brand: 'DMC', category: 'satin' },
-  { code: 'S5200', name: 'Satin Snow White', rgb: [255, 255, 255], brand: 'DMC', category: 'satin' },
-];
-
-// ============================================
-// ÉTOILE (Sparkle)
-// ============================================
-const etoileColors: ThreadColor[] = [
-  { code: 'C155', name: 'Étoile Blue Violet Med Dark', rgb: [160, 155, 195], brand: 'DMC', category: 'etoile' },
-  { code: 'C310', name: 'Étoile Black', rgb: [35, 35, 35], brand: 'DMC', category: 'etoile' },
-  { code: 'C321', name: 'Étoile Red', rgb: [205, 60, 80], brand: 'DMC', category: 'etoile' },
-  { code: 'C326', name: 'Étoile Rose Very Dark', rgb: [185, 75, 95], brand: 'DMC', category: 'etoile' },
-  { code: 'C349', name: 'Étoile Coral Dark', rgb: [215, 40, 75], brand: 'DMC', category: 'etoile' },
-  { code: 'C415', name: 'Étoile Pearl Gray', rgb: [215, 215, 220], brand: 'DMC', category: 'etoile' },
-  { code: 'C435', name: 'Étoile Brown Very Light', rgb: [195, 140, 95], brand: 'DMC', category: 'etoile' },
-  { code: 'C498', name: 'Étoile Christmas Red Dark', rgb: [180, 40, 65], brand: 'DMC', category: 'etoile' },
-  { code: 'C517', name: 'Étoile Wedgwood Dark', rgb: [80, 135, 165], brand: 'DMC', category: 'etoile' },
-  { code: 'C550', name: 'Étoile Violet Very Dark', rgb: [110, 50, 100], brand: 'DMC', category: 'etoile' },
-  { code: 'C597', name: 'Étoile Turquoise', rgb: [110, 180, 200], brand: 'DMC', category: 'etoile' },
-  { code: 'C598', name: 'Étoile Turquoise Light', rgb: [160, 210, 220], brand: 'DMC', category: 'etoile' },
-  { code: 'C666', name: 'Étoile Christmas Red Bright', rgb: [235, 55, 90], brand: 'DMC', category: 'etoile' },
-  { code: 'C699', name: 'Étoile Christmas Green', rgb: [35, 120, 55], brand: 'DMC', category: 'etoile' },
-  { code: 'C700', name: 'Étoile Christmas Green Bright', rgb: [45, 135, 60], brand: 'DMC', category: 'etoile' },
-  { code: 'C703', name: 'Étoile Chartreuse', rgb: [140, 200, 95], brand: 'DMC', category: 'etoile' },
-  { code: 'C718', name: 'Étoile Plum', rgb: [175, 60, 125], brand: 'DMC', category: 'etoile' },
-  { code: 'C740', name: 'Étoile Tangerine', rgb: [255, 160, 55], brand: 'DMC', category: 'etoile' },
-  { code: 'C762', name: 'Étoile Pearl Gray Very Light', rgb: [240, 240, 240], brand: 'DMC', category: 'etoile' },
-  { code: 'C796', name: 'Étoile Royal Blue Dark', rgb: [40, 85, 135], brand: 'DMC', category: 'etoile' },
-  { code: 'C798', name: 'Étoile Delft Blue Dark', rgb: [95, 130, 170], brand: 'DMC', category: 'etoile' },
-  { code: 'C820', name: 'Étoile Royal Blue Very Dark', rgb: [35, 75, 120], brand: 'DMC', category: 'etoile' },
-  { code: 'C825', name: 'Étoile Blue Dark', rgb: [90, 150, 185], brand: 'DMC', category: 'etoile' },
-  { code: 'C828', name: 'Étoile Blue Ultra Very Light', rgb: [210, 240, 245], brand: 'DMC', category: 'etoile' },
-  { code: 'C890', name: 'Étoile Pistachio Green Ult Dk', rgb: [45, 95, 60], brand: 'DMC', category: 'etoile' },
-  { code: 'C891', name: 'Étoile Carnation Dark', rgb: [255, 110, 140], brand: 'DMC', category: 'etoile' },
-  { code: 'C894', name: 'Étoile Carnation Very Light', rgb: [255, 195, 205], brand: 'DMC', category: 'etoile' },
-  { code: 'C906', name: 'Étoile Parrot Green Medium', rgb: [145, 200, 85], brand: 'DMC', category: 'etoile' },
-  { code: 'C912', name: 'Étoile Emerald Green Light', rgb: [60, 180, 135], brand: 'DMC', category: 'etoile' },
-  { code: 'C943', name: 'Étoile Aquamarine Medium', rgb: [85, 170, 160], brand: 'DMC', category: 'etoile' },
-  { code: 'C3607', name: 'Étoile Plum Light', rgb: [220, 105, 170], brand: 'DMC', category: 'etoile' },
-  { code: 'C3756', name: 'Étoile Baby Blue Ultra Very Lt', rgb: [250, 255, 255], brand: 'DMC', category: 'etoile' },
-  { code: 'C5200', name: 'Étoile Snow White', rgb: [255, 255, 255], brand: 'DMC', category: 'etoile' },
-];
-
-// Combine all threads
-export const dmcThreads: ThreadColor[] = [
-  ...solidColors,
-  ...variegatedColors,
-  ...lightEffectsColors,
-  ...satinColors,
-  ...etoileColors,
-];
-
-// Get threads by category
-export function getThreadsByCategory(category: ThreadCategory): ThreadColor[] {
-  return dmcThreads.filter(t => t.category === category);
-}
-
-// Helper function to find closest DMC color to an RGB value
-export function findClosestDMC(r: number, g: number, b: number, category?: ThreadCategory): ThreadColor {
-  const threads = category ? getThreadsByCategory(category) : solidColors; // Default to solid colors
-  let closestColor = threads[0];
-  let closestDistance = Infinity;
-
-  for (const thread of threads) {
-    const dr = r - thread.rgb[0];
-    const dg = g - thread.rgb[1];
-    const db = b - thread.rgb[2];
-    // Weighted distance (human eye is more sensitive to green)
-    const distance = dr * dr * 0.299 + dg * dg * 0.587 + db * db * 0.114;
-
-    if (distance < closestDistance) {
-      closestDistance = distance;
-      closestColor = thread;
-    }
-  }
-
-  return closestColor;
-}
-
-// Search threads by name or code
-export function searchDMCThreads(query: string, category?: ThreadCategory): ThreadColor[] {
-  const threads = category ? getThreadsByCategory(category) : dmcThreads;
-  const lowerQuery = query.toLowerCase();
-  return threads.filter(
-    (thread) =>
-      thread.code.toLowerCase().includes(lowerQuery) ||
-      thread.name.toLowerCase().includes(lowerQuery)
-  );
-}
@@ -1,14 +1,29 @@
 // Unified Thread Library Interface
-// Provides a common interface for all thread brands
+// Thread data comes from the Rust backend (get_thread_libraries, search_threads, ...)
 
-import { dmcThreads, ThreadColor as DMCThreadColor } from './dmcThreads';
-import { anchorThreads, AnchorThreadColor } from './anchorThreads';
-import { kreinikThreads, KreinikThreadColor, KreinikType } from './kreinikThreads';
+import { invoke } from '@tauri-apps/api/core';
 import { PATTERN_SYMBOLS } from '../utils/symbolAssignment';
+import { ColorMatchAlgorithm } from '../utils/colorMatching';
 
 // Thread brands available in the application
 export type ThreadBrand = 'DMC' | 'Anchor' | 'Kreinik';
 
+const THREAD_BRANDS: ThreadBrand[] = ['DMC', 'Anchor', 'Kreinik'];
+
+// DMC product lines
+export type ThreadCategory = 'solid' | 'variegated' | 'light-effects' | 'satin' | 'etoile';
+
+export const categoryNames: Record<ThreadCategory, string> = {
+  'solid': 'Solid Colors',
+  'variegated': 'Color Variations',
+  'light-effects': 'Light Effects',
+  'satin': 'Satin Floss',
+  'etoile': 'Étoile (Sparkle)',
+};
+
+// Kreinik product lines
+export type KreinikType = 'blending-filament' | 'braid' | 'ribbon' | 'cord' | 'japan' | 'silk';
+
 // Unified thread color interface
 export interface UnifiedThreadColor {
   code: string;
@@ -27,129 +42,136 @@ export interface ThreadLibraryInfo {
   colorCount: number;
 }
 
-// Get all available thread libraries
-export function getThreadLibraries(): ThreadLibraryInfo[] {
-  return [
-    {
-      brand: 'DMC',
-      name: 'DMC',
-      description: 'DMC Cotton Embroidery Floss - Industry standard for cross-stitch and embroidery',
-      colorCount: dmcThreads.length,
-    },
-    {
-      brand: 'Anchor',
-      name: 'Anchor Stranded',
-      description: 'Anchor Stranded Cotton - Popular alternative with excellent color range',
-      colorCount: anchorThreads.length,
-    },
-    {
-      brand: 'Kreinik',
-      name: 'Kreinik Metallics',
-      description: 'Kreinik Metallic Threads - Premium metallic and specialty threads',
-      colorCount: kreinikThreads.length,
-    },
-  ];
+// A thread ranked by closeness to a target color
+export interface NearestThread {
+  thread: UnifiedThreadColor;
+  distance: number; // Under the requested algorithm
+  deltaE: number; // CIEDE2000
 }
 
-// Get symbol for a thread based on its index
-function getSymbolForIndex(index: number): string {
-  return PATTERN_SYMBOLS.all[index % PATTERN_SYMBOLS.all.length];
+// Shapes returned by the backend commands
+interface BackendThread {
+  code: string;
+  name: string;
+  rgb: [number, number, number];
+  brand: string;
+  category?: string;
 }
 
-// Convert DMC thread to unified format (with symbol)
-function dmcToUnified(thread: DMCThreadColor, index: number): UnifiedThreadColor {
-  return {
-    code: thread.code,
-    name: thread.name,
-    rgb: thread.rgb,
-    brand: 'DMC',
-    category: thread.category,
-    symbol: getSymbolForIndex(index),
-  };
+interface BackendLibraryInfo {
+  brand: string;
+  name: string;
+  description: string;
+  color_count: number;
 }
 
-// Convert Anchor thread to unified format (with symbol)
-function anchorToUnified(thread: AnchorThreadColor, index: number): UnifiedThreadColor {
-  return {
-    code: thread.code,
-    name: thread.name,
-    rgb: thread.rgb,
-    brand: 'Anchor',
-    symbol: getSymbolForIndex(index),
-  };
+interface BackendSearchPage {
+  threads: Array<{ thread: BackendThread; distance: number | null }>;
+  total: number;
+  offset: number;
 }
 
-// Convert Kreinik thread to unified format (with symbol)
-function kreinikToUnified(thread: KreinikThreadColor, index: number): UnifiedThreadColor {
+interface BackendNearestThread {
+  thread: BackendThread;
+  distance: number;
+  delta_e: number;
+}
+
+// Threads fetched per search_threads call when loading a whole library
+const PAGE_SIZE = 500;
+
+function isThreadBrand(brand: string): brand is ThreadBrand {
+  return (THREAD_BRANDS as string[]).includes(brand);
+}
+
+// Get symbol for a thread based on its index
+function getSymbolForIndex(index: number): string {
+  return PATTERN_SYMBOLS.all[index % PATTERN_SYMBOLS.all.length];
+}
+
+// Convert a backend thread to unified format (with symbol)
+function toUnified(thread: BackendThread, index: number): UnifiedThreadColor {
   return {
     code: thread.code,
     name: thread.name,
     rgb: thread.rgb,
-    brand: 'Kreinik',
-    category: thread.type,
+    brand: thread.brand as ThreadBrand,
+    category: thread.category,
     symbol: getSymbolForIndex(index),
   };
 }
 
-// Get threads for a specific brand
-export function getThreadsByBrand(brand: ThreadBrand): UnifiedThreadColor[] {
-  switch (brand) {
-    case 'DMC':
-      return dmcThreads.map((t, i) => dmcToUnified(t, i));
-    case 'Anchor':
-      return anchorThreads.map((t, i) => anchorToUnified(t, i));
-    case 'Kreinik':
-      return kreinikThreads.map((t, i) => kreinikToUnified(t, i));
-    default:
-      return [];
-  }
+// Get the built-in thread libraries (custom libraries have no picker yet)
+export async function getThreadLibraries(): Promise<ThreadLibraryInfo[]> {
+  const libraries = await invoke<BackendLibraryInfo[]>('get_thread_libraries');
+  return libraries
+    .filter(lib => isThreadBrand(lib.brand))
+    .map(lib => ({
+      brand: lib.brand as ThreadBrand,
+      name: lib.name,
+      description: lib.description,
+      colorCount: lib.color_count,
+    }));
 }
 
-// Get all threads from all libraries
-export function getAllThreads(): UnifiedThreadColor[] {
-  let index = 0;
-  const result: UnifiedThreadColor[] = [];
-
-  for (const t of dmcThreads) {
-    result.push(dmcToUnified(t, index++));
-  }
-  for (const t of anchorThreads) {
-    result.push(anchorToUnified(t, index++));
-  }
-  for (const t of kreinikThreads) {
-    result.push(kreinikToUnified(t, index++));
+// Get every thread of a brand, in library order
+export async function getThreadsByBrand(brand: ThreadBrand): Promise<UnifiedThreadColor[]> {
+  const threads: UnifiedThreadColor[] = [];
+  for (;;) {
+    const page = await invoke<BackendSearchPage>('search_threads', {
+      query: { brands: [brand], offset: threads.length, limit: PAGE_SIZE },
+    });
+    for (const hit of page.threads) {
+      threads.push(toUnified(hit.thread, threads.length));
+    }
+    if (page.threads.length === 0 || threads.length >= page.total) {
+      return threads;
+    }
   }
-
-  return result;
-}
-
-// Get threads for multiple brands
-export function getThreadsForBrands(brands: ThreadBrand[]): UnifiedThreadColor[] {
-  return brands.flatMap(brand => getThreadsByBrand(brand));
 }
 
-// Search threads by name or code
-export function searchThreads(
+// Search threads by name or code (first page of matches)
+export async function searchThreads(
   query: string,
   brands?: ThreadBrand[]
-): UnifiedThreadColor[] {
-  const threads = brands ? getThreadsForBrands(brands) : getAllThreads();
-  const lowerQuery = query.toLowerCase();
-
-  return threads.filter(
-    thread =>
-      thread.code.toLowerCase().includes(lowerQuery) ||
-      thread.name.toLowerCase().includes(lowerQuery)
-  );
+): Promise<UnifiedThreadColor[]> {
+  const page = await invoke<BackendSearchPage>('search_threads', {
+    query: { brands: brands ?? [], text: query },
+  });
+  return page.threads.map((hit, i) => toUnified(hit.thread, i));
 }
 
-// Get thread by exact code and brand
-export function getThreadByCode(
+// Get thread by code (case-insensitive) and brand
+export async function getThreadByCode(
   code: string,
   brand: ThreadBrand
-): UnifiedThreadColor | undefined {
-  const threads = getThreadsByBrand(brand);
-  return threads.find(t => t.code === code);
+): Promise<UnifiedThreadColor | undefined> {
+  try {
+    const thread = await invoke<BackendThread>('get_thread', { brand, code });
+    return toUnified(thread, 0);
+  } catch {
+    return undefined;
+  }
+}
+
+// Rank the threads closest to a color
+export async function findNearestThreads(
+  rgb: [number, number, number],
+  brands: ThreadBrand[],
+  algorithm: ColorMatchAlgorithm,
+  k: number
+): Promise<NearestThread[]> {
+  const nearest = await invoke<BackendNearestThread[]>('find_nearest_threads', {
+    rgb,
+    brands,
+    algorithm,
+    k,
+  });
+  return nearest.map((m, i) => ({
+    thread: toUnified(m.thread, i),
+    distance: m.distance,
+    deltaE: m.delta_e,
+  }));
 }
 
 // Convert threads to palette format for color matching
@@ -185,6 +207,3 @@ export function getBrandDisplayName(brand: ThreadBrand): string {
   };
   return brandNames[brand];
 }
-
-// Export raw thread arrays for backward compatibility
-export { dmcThreads, anchorThreads, kreinikThreads };
@@ -19,6 +19,7 @@ export interface Color {
   rgb: [number, number, number];
   threadBrand?: string;
   threadCode?: string;
+  blendThreadCode?: string; // Second thread in the needle for blended colors
   symbol?: string;
 }
 
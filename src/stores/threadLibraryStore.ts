@@ -0,0 +1,46 @@
+import { create } from 'zustand';
+import {
+  ThreadBrand,
+  ThreadLibraryInfo,
+  UnifiedThreadColor,
+  getThreadLibraries,
+  getThreadsByBrand,
+} from '../data/threadLibrary';
+
+// Thread libraries loaded from the backend, cached for the session
+interface ThreadLibraryState {
+  libraries: ThreadLibraryInfo[];
+  threadsByBrand: Partial<Record<ThreadBrand, UnifiedThreadColor[]>>;
+
+  // Actions
+  loadLibraries: () => Promise<void>;
+  loadThreads: (brand: ThreadBrand) => Promise<UnifiedThreadColor[]>;
+}
+
+export const useThreadLibraryStore = create<ThreadLibraryState>((set, get) => ({
+  libraries: [],
+  threadsByBrand: {},
+
+  loadLibraries: async () => {
+    if (get().libraries.length > 0) return;
+    try {
+      const libraries = await getThreadLibraries();
+      set({ libraries });
+    } catch (error) {
+      console.error('Failed to load thread libraries:', error);
+    }
+  },
+
+  loadThreads: async (brand: ThreadBrand) => {
+    const cached = get().threadsByBrand[brand];
+    if (cached) return cached;
+    try {
+      const threads = await getThreadsByBrand(brand);
+      set(state => ({ threadsByBrand: { ...state.threadsByBrand, [brand]: threads } }));
+      return threads;
+    } catch (error) {
+      console.error(`Failed to load ${brand} threads:`, error);
+      return [];
+    }
+  },
+}));